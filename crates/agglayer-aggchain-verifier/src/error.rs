@@ -0,0 +1,33 @@
+use agglayer_primitives::{vkey_hash::VKeyHash, Digest};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The proof was generated with a SP1 version we do not support.
+    #[error("unsupported SP1 proof version {0:?}")]
+    UnsupportedVersion(String),
+
+    /// The proof declares a different SP1 version than the one it carries.
+    #[error("proof declares SP1 version {declared:?} but was generated with {actual:?}")]
+    VersionMismatch { declared: String, actual: String },
+
+    /// The proof was generated for a different program.
+    #[error("verifying key hash mismatch: expected {expected:?}, got {actual:?}")]
+    VKeyHashMismatch {
+        expected: VKeyHash,
+        actual: VKeyHash,
+    },
+
+    /// The proof commits to different public values.
+    #[error("public values hash mismatch: expected {expected}, got {actual}")]
+    PublicValuesHashMismatch { expected: Digest, actual: Digest },
+
+    #[error("failed to deserialize proof")]
+    DeserializingProof(#[source] agglayer_bincode::Error),
+
+    #[error("failed to deserialize vkey")]
+    DeserializingVKey(#[source] agglayer_bincode::Error),
+
+    /// The STARK proof does not verify.
+    #[error("invalid SP1 proof: {0}")]
+    InvalidProof(String),
+}
@@ -0,0 +1,133 @@
+//! Host-side verification of SP1 aggchain proofs.
+//!
+//! The checks that do not require the SP1 prover stack (proof version,
+//! verifying key hash and committed public values) are always available. The
+//! STARK verification itself is provided by [`Sp1AggchainProofVerifier`],
+//! which is gated behind the `sp1` feature.
+
+use agglayer_interop_types::aggchain_proof::AggchainProofPublicValues;
+use agglayer_primitives::{vkey_hash::VKeyHash, Digest};
+use sha2::{Digest as _, Sha256};
+
+mod error;
+#[cfg(feature = "sp1")]
+mod sp1;
+
+pub use error::Error;
+#[cfg(feature = "sp1")]
+pub use sp1::Sp1AggchainProofVerifier;
+
+/// SP1 versions for which aggchain proofs are accepted.
+pub const SUPPORTED_SP1_VERSIONS: &[&str] = &["v6.0.0", "v6.1.0"];
+
+/// Check that the proof was generated with a supported SP1 version.
+pub fn check_version(version: &str) -> Result<(), Error> {
+    if SUPPORTED_SP1_VERSIONS.contains(&version) {
+        Ok(())
+    } else {
+        Err(Error::UnsupportedVersion(version.to_owned()))
+    }
+}
+
+/// Check that the verifying key of the proof is the expected one.
+pub fn check_vkey_hash(expected: VKeyHash, actual: VKeyHash) -> Result<(), Error> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Error::VKeyHashMismatch { expected, actual })
+    }
+}
+
+/// Check that the public values committed by the proof hash to the expected
+/// aggchain proof public values.
+pub fn check_public_values(
+    committed: &[u8],
+    expected: &AggchainProofPublicValues,
+) -> Result<(), Error> {
+    let expected = Digest(expected.hash());
+    let actual = Digest(Sha256::digest(committed).into());
+
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Error::PublicValuesHashMismatch { expected, actual })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_interop_types::NetworkId;
+
+    use super::*;
+
+    fn sample_public_values() -> AggchainProofPublicValues {
+        AggchainProofPublicValues {
+            prev_local_exit_root: Digest([0x11; 32]),
+            new_local_exit_root: Digest([0x22; 32]),
+            l1_info_root: Digest([0x33; 32]),
+            origin_network: NetworkId::new(0x00112233),
+            commit_imported_bridge_exits: Digest([0x44; 32]),
+            aggchain_params: Digest([0x55; 32]),
+        }
+    }
+
+    /// Public values committed by the aggchain proof program for
+    /// [`sample_public_values`], the origin network being little-endian.
+    const COMMITTED_PUBLIC_VALUES: [&[u8]; 6] = [
+        &[0x11; 32],
+        &[0x22; 32],
+        &[0x33; 32],
+        &[0x33, 0x22, 0x11, 0x00],
+        &[0x44; 32],
+        &[0x55; 32],
+    ];
+
+    #[test]
+    fn accepts_supported_versions() {
+        for version in SUPPORTED_SP1_VERSIONS {
+            check_version(version).unwrap();
+        }
+    }
+
+    #[test]
+    fn rejects_unsupported_versions() {
+        for version in ["v4.0.0-rc.3", "v5.0.0", "6.0.0", ""] {
+            assert!(matches!(
+                check_version(version),
+                Err(Error::UnsupportedVersion(v)) if v == version
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_vkey_hash_mismatch() {
+        let expected = VKeyHash::from_hash_u32([1; 8]);
+        let actual = VKeyHash::from_hash_u32([2; 8]);
+
+        check_vkey_hash(expected, expected).unwrap();
+        assert!(matches!(
+            check_vkey_hash(expected, actual),
+            Err(Error::VKeyHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn matches_committed_public_values() {
+        let public_values = sample_public_values();
+
+        check_public_values(&COMMITTED_PUBLIC_VALUES.concat(), &public_values).unwrap();
+    }
+
+    #[test]
+    fn rejects_public_values_mismatch() {
+        let public_values = sample_public_values();
+        let mut committed = COMMITTED_PUBLIC_VALUES.concat();
+        committed[0] ^= 1;
+
+        assert!(matches!(
+            check_public_values(&committed, &public_values),
+            Err(Error::PublicValuesHashMismatch { expected, .. })
+                if expected == Digest(public_values.hash())
+        ));
+    }
+}
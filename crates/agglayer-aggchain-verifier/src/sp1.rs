@@ -0,0 +1,248 @@
+use agglayer_interop_types::aggchain_proof::{AggchainProofPublicValues, SP1StarkWithContext};
+use agglayer_primitives::vkey_hash::VKeyHash;
+use sp1_sdk::{
+    blocking::{EnvProver, Prover as _, ProverClient},
+    HashableKey as _, SP1ProofWithPublicValues, SP1VerifyingKey,
+};
+
+use crate::{check_public_values, check_version, check_vkey_hash, Error};
+
+/// Verifier for SP1 aggchain proofs backed by the SP1 SDK.
+///
+/// The proof and verifying key of a [`SP1StarkWithContext`] are expected to be
+/// the SP1-compatible bincode encodings of [`SP1ProofWithPublicValues`] and
+/// [`SP1VerifyingKey`] respectively.
+pub struct Sp1AggchainProofVerifier {
+    client: EnvProver,
+}
+
+impl Default for Sp1AggchainProofVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sp1AggchainProofVerifier {
+    /// Create a verifier using the prover configured from the environment.
+    pub fn new() -> Self {
+        Self {
+            client: ProverClient::from_env(),
+        }
+    }
+
+    /// Verify the STARK proof against the expected verifying key hash and
+    /// return the public values it commits to.
+    pub fn verify_stark(
+        &self,
+        proof: &SP1StarkWithContext,
+        expected_vkey: VKeyHash,
+    ) -> Result<Vec<u8>, Error> {
+        check_version(&proof.version)?;
+
+        let vkey: SP1VerifyingKey = agglayer_bincode::sp1_compatible()
+            .deserialize(&proof.vkey)
+            .map_err(Error::DeserializingVKey)?;
+        check_vkey_hash(expected_vkey, VKeyHash::from_hash_u32(vkey.hash_u32()))?;
+
+        let stark: SP1ProofWithPublicValues = agglayer_bincode::sp1_compatible()
+            .deserialize(&proof.proof)
+            .map_err(Error::DeserializingProof)?;
+        if stark.sp1_version != proof.version {
+            return Err(Error::VersionMismatch {
+                declared: proof.version.clone(),
+                actual: stark.sp1_version,
+            });
+        }
+
+        self.client
+            .verify(&stark, &vkey, None)
+            .map_err(|error| Error::InvalidProof(error.to_string()))?;
+
+        Ok(stark.public_values.to_vec())
+    }
+
+    /// Verify the aggchain proof against the expected verifying key hash and
+    /// aggchain proof public values.
+    pub fn verify(
+        &self,
+        proof: &SP1StarkWithContext,
+        expected_vkey: VKeyHash,
+        public_values: &AggchainProofPublicValues,
+    ) -> Result<(), Error> {
+        let committed = self.verify_stark(proof, expected_vkey)?;
+
+        check_public_values(&committed, public_values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use agglayer_elf_build_sample_program_host::ELF;
+    use agglayer_interop_types::{Digest, NetworkId};
+    use sp1_sdk::{
+        blocking::ProveRequest as _, ProvingKey as _, SP1PublicValues, SP1Stdin,
+        SP1_CIRCUIT_VERSION,
+    };
+
+    use super::*;
+    use crate::check_public_values;
+
+    const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+    /// Verifier shared by the tests, as setting up the prover is slow.
+    static VERIFIER: LazyLock<Sp1AggchainProofVerifier> =
+        LazyLock::new(Sp1AggchainProofVerifier::new);
+
+    /// Prove the sample program, returning the proof and its vkey hash.
+    fn sample_proof() -> (SP1StarkWithContext, VKeyHash) {
+        let client = ProverClient::from_env();
+        let pk = client.setup(ELF.into()).unwrap();
+        let vk = pk.verifying_key().clone();
+        let proof = client
+            .prove(&pk, SP1Stdin::new())
+            .compressed()
+            .run()
+            .unwrap();
+
+        let proof = SP1StarkWithContext {
+            proof: agglayer_bincode::sp1_compatible()
+                .serialize(&proof)
+                .unwrap(),
+            vkey: agglayer_bincode::sp1_compatible().serialize(&vk).unwrap(),
+            version: SP1_CIRCUIT_VERSION.to_owned(),
+        };
+
+        (proof, VKeyHash::from_hash_u32(vk.hash_u32()))
+    }
+
+    /// Proof of the sample program written by `write_sample_program_fixtures`,
+    /// returning the proof and its vkey hash.
+    fn pregenerated_sample_proof() -> (SP1StarkWithContext, VKeyHash) {
+        let proof = SP1StarkWithContext {
+            proof: include_bytes!("../tests/fixtures/sample_program_proof.bin").to_vec(),
+            vkey: include_bytes!("../tests/fixtures/sample_program_vkey.bin").to_vec(),
+            version: SP1_CIRCUIT_VERSION.to_owned(),
+        };
+        let vkey: SP1VerifyingKey = agglayer_bincode::sp1_compatible()
+            .deserialize(&proof.vkey)
+            .unwrap();
+
+        (proof, VKeyHash::from_hash_u32(vkey.hash_u32()))
+    }
+
+    #[test]
+    fn sdk_version_is_supported() {
+        check_version(SP1_CIRCUIT_VERSION).unwrap();
+    }
+
+    #[test]
+    #[ignore = "proves the sample program, which takes minutes on a CPU"]
+    fn sample_program_proof() {
+        let verifier = Sp1AggchainProofVerifier::new();
+        let (proof, vkey_hash) = sample_proof();
+
+        // The sample program does not commit anything.
+        let committed = verifier.verify_stark(&proof, vkey_hash).unwrap();
+        assert!(committed.is_empty());
+
+        let wrong_vkey = VKeyHash::from_hash_u32([0; 8]);
+        assert!(matches!(
+            verifier.verify_stark(&proof, wrong_vkey),
+            Err(Error::VKeyHashMismatch { .. })
+        ));
+
+        let public_values = AggchainProofPublicValues {
+            prev_local_exit_root: Digest::ZERO,
+            new_local_exit_root: Digest::ZERO,
+            l1_info_root: Digest::ZERO,
+            origin_network: NetworkId::new(0),
+            commit_imported_bridge_exits: Digest::ZERO,
+            aggchain_params: Digest::ZERO,
+        };
+        assert!(matches!(
+            verifier.verify(&proof, vkey_hash, &public_values),
+            Err(Error::PublicValuesHashMismatch { .. })
+        ));
+
+        let unsupported = SP1StarkWithContext {
+            version: "v4.0.0-rc.3".to_owned(),
+            ..proof.clone()
+        };
+        assert!(matches!(
+            verifier.verify_stark(&unsupported, vkey_hash),
+            Err(Error::UnsupportedVersion(_))
+        ));
+
+        let mut tampered = proof;
+        let last = tampered.proof.len() - 1;
+        tampered.proof[last] ^= 1;
+        assert!(verifier.verify_stark(&tampered, vkey_hash).is_err());
+    }
+
+    #[test]
+    fn pregenerated_sample_proof_is_verified() {
+        let verifier = &*VERIFIER;
+        let (proof, vkey_hash) = pregenerated_sample_proof();
+
+        // The sample program does not commit anything.
+        let committed = verifier.verify_stark(&proof, vkey_hash).unwrap();
+        assert!(committed.is_empty());
+    }
+
+    #[test]
+    fn tampered_public_values_are_rejected() {
+        let verifier = &*VERIFIER;
+        let (mut proof, vkey_hash) = pregenerated_sample_proof();
+
+        // Zeroed public values are committed as zeroes, so that only the
+        // STARK verification can tell them apart from the committed ones.
+        let public_values = AggchainProofPublicValues {
+            prev_local_exit_root: Digest::ZERO,
+            new_local_exit_root: Digest::ZERO,
+            l1_info_root: Digest::ZERO,
+            origin_network: NetworkId::new(0),
+            commit_imported_bridge_exits: Digest::ZERO,
+            aggchain_params: Digest::ZERO,
+        };
+        let committed = [0; 5 * 32 + 4];
+        check_public_values(&committed, &public_values).unwrap();
+
+        let mut stark: SP1ProofWithPublicValues = agglayer_bincode::sp1_compatible()
+            .deserialize(&proof.proof)
+            .unwrap();
+        stark.public_values = SP1PublicValues::from(&committed);
+        proof.proof = agglayer_bincode::sp1_compatible()
+            .serialize(&stark)
+            .unwrap();
+
+        assert!(matches!(
+            verifier.verify(&proof, vkey_hash, &public_values),
+            Err(Error::InvalidProof(_))
+        ));
+    }
+
+    /// Regenerates the proof of `pregenerated_sample_proof`, e.g. after an SP1
+    /// upgrade, with `cargo test --release -p agglayer-aggchain-verifier
+    /// --features sp1 -- --ignored write_sample_program_fixtures`.
+    ///
+    /// Proving needs about 20 GB of memory.
+    #[test]
+    #[ignore = "proves the sample program, which takes minutes on a CPU"]
+    fn write_sample_program_fixtures() {
+        let (proof, _) = sample_proof();
+
+        std::fs::create_dir_all(FIXTURES_DIR).unwrap();
+        std::fs::write(
+            format!("{FIXTURES_DIR}/sample_program_proof.bin"),
+            &proof.proof,
+        )
+        .unwrap();
+        std::fs::write(
+            format!("{FIXTURES_DIR}/sample_program_vkey.bin"),
+            &proof.vkey,
+        )
+        .unwrap();
+    }
+}
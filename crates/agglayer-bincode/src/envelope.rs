@@ -0,0 +1,387 @@
+//! Schema-versioned envelopes for bincode payloads.
+//!
+//! An envelope prefixes the encoded payload with a header made of a magic
+//! number, the schema id of the payload type and its schema version. Decoding
+//! checks the header before touching the payload, so data written with a
+//! different layout is rejected (or migrated) instead of being misdecoded.
+//!
+//! The header has a fixed layout, independent of the codec: the magic number
+//! followed by the big-endian schema id and version. Only the payload is
+//! encoded with the settings of the codec. When encoding with a codec limited
+//! by [`Codec::with_limit`], the header counts towards the limit.
+
+use bincode::{ErrorKind, Options};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{options, Codec};
+
+/// Magic number starting every envelope.
+pub const MAGIC: [u8; 4] = *b"AGLE";
+
+/// Length of the envelope header.
+pub const HEADER_LEN: usize = 10;
+
+/// Type with a versioned wire schema.
+pub trait Versioned: Serialize + DeserializeOwned {
+    /// Identifier of the schema, unique among the enveloped types.
+    const SCHEMA_ID: u32;
+
+    /// Current version of the schema.
+    const VERSION: u16;
+
+    /// Declare how to decode payloads written with older schema versions.
+    ///
+    /// By default, only the current version is accepted.
+    fn migrations<Opts: Options + Clone>(
+        migrations: Migrations<'_, Opts, Self>,
+    ) -> Migrations<'_, Opts, Self> {
+        migrations
+    }
+}
+
+/// Errors that can occur when decoding an envelope.
+#[derive(Debug, thiserror::Error)]
+pub enum EnvelopeError {
+    #[error("invalid envelope magic number {0:02x?}")]
+    BadMagic([u8; 4]),
+
+    #[error("envelope schema id mismatch: expected {expected}, got {actual}")]
+    SchemaMismatch { expected: u32, actual: u32 },
+
+    #[error("unsupported version {version} of schema {schema_id} (current is {current})")]
+    UnsupportedVersion {
+        schema_id: u32,
+        version: u16,
+        current: u16,
+    },
+
+    #[error("envelope too short for its header: {0} bytes")]
+    TruncatedHeader(usize),
+
+    #[error("failed to decode envelope")]
+    Decode(#[source] bincode::Error),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Header {
+    magic: [u8; 4],
+    schema_id: u32,
+    version: u16,
+}
+
+impl Header {
+    fn of<T: Versioned>() -> Self {
+        Self {
+            magic: MAGIC,
+            schema_id: T::SCHEMA_ID,
+            version: T::VERSION,
+        }
+    }
+
+    fn to_bytes(self) -> [u8; HEADER_LEN] {
+        let mut bytes = [0; HEADER_LEN];
+        bytes[..4].copy_from_slice(&self.magic);
+        bytes[4..8].copy_from_slice(&self.schema_id.to_be_bytes());
+        bytes[8..].copy_from_slice(&self.version.to_be_bytes());
+        bytes
+    }
+
+    /// Splits an envelope into its header and its payload.
+    fn split(bytes: &[u8]) -> Result<(Self, &[u8]), EnvelopeError> {
+        let Some((header, payload)) = bytes.split_first_chunk::<HEADER_LEN>() else {
+            return Err(EnvelopeError::TruncatedHeader(bytes.len()));
+        };
+        let [m0, m1, m2, m3, s0, s1, s2, s3, v0, v1] = *header;
+        let header = Self {
+            magic: [m0, m1, m2, m3],
+            schema_id: u32::from_be_bytes([s0, s1, s2, s3]),
+            version: u16::from_be_bytes([v0, v1]),
+        };
+        Ok((header, payload))
+    }
+}
+
+/// Registry of the older schema versions a [`Versioned`] type can be decoded
+/// from.
+///
+/// Each older version is registered with the type its payload decodes to,
+/// which is then converted into the current type:
+///
+/// ```ignore
+/// fn migrations<Opts: Options + Clone>(
+///     migrations: Migrations<'_, Opts, Self>,
+/// ) -> Migrations<'_, Opts, Self> {
+///     migrations.from::<FooV1>(1).from::<FooV2>(2)
+/// }
+/// ```
+pub struct Migrations<'a, Opts, T> {
+    codec: Codec<Opts>,
+    payload: &'a [u8],
+    header: Header,
+    decoded: Option<Result<T, EnvelopeError>>,
+}
+
+impl<Opts: Options + Clone, T: Versioned> Migrations<'_, Opts, T> {
+    /// Register a migration from the given schema version.
+    pub fn from<Old>(mut self, version: u16) -> Self
+    where
+        Old: DeserializeOwned + Into<T>,
+    {
+        if self.decoded.is_none() && self.header.version == version {
+            self.decoded = Some(
+                self.codec
+                    .clone()
+                    .deserialize::<Old>(self.payload)
+                    .map(Into::into)
+                    .map_err(EnvelopeError::Decode),
+            );
+        }
+        self
+    }
+
+    fn finish(self) -> Result<T, EnvelopeError> {
+        self.decoded
+            .unwrap_or(Err(EnvelopeError::UnsupportedVersion {
+                schema_id: T::SCHEMA_ID,
+                version: self.header.version,
+                current: T::VERSION,
+            }))
+    }
+}
+
+impl<Opts: Options + Clone> Codec<Opts> {
+    /// Codec of the payload, limited to what is left of the limit once the
+    /// header is accounted for.
+    fn payload_codec(self) -> bincode::Result<Codec<options::Limited<Opts>>> {
+        let limit = self.limit.unwrap_or(u64::MAX);
+        let payload_limit = limit
+            .checked_sub(HEADER_LEN as u64)
+            .ok_or(ErrorKind::SizeLimit)?;
+        Ok(self.with_limit(payload_limit))
+    }
+
+    /// Encode an object into a byte vector, wrapped in a versioned envelope.
+    pub fn serialize_envelope<T: Versioned>(self, item: &T) -> bincode::Result<Vec<u8>> {
+        let mut bytes = Header::of::<T>().to_bytes().to_vec();
+        self.payload_codec()?.serialize_into(&mut bytes, item)?;
+        Ok(bytes)
+    }
+
+    /// Decode an object wrapped in a versioned envelope, migrating it from an
+    /// older schema version if needed.
+    pub fn deserialize_envelope<T: Versioned>(self, bytes: &[u8]) -> Result<T, EnvelopeError> {
+        let (header, payload) = Header::split(bytes)?;
+
+        if header.magic != MAGIC {
+            return Err(EnvelopeError::BadMagic(header.magic));
+        }
+
+        if header.schema_id != T::SCHEMA_ID {
+            return Err(EnvelopeError::SchemaMismatch {
+                expected: T::SCHEMA_ID,
+                actual: header.schema_id,
+            });
+        }
+
+        let codec = self.payload_codec().map_err(EnvelopeError::Decode)?;
+        if header.version == T::VERSION {
+            return codec.deserialize(payload).map_err(EnvelopeError::Decode);
+        }
+
+        T::migrations(Migrations {
+            codec,
+            payload,
+            header,
+            decoded: None,
+        })
+        .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct PointV1 {
+        x: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct PointV2 {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Point {
+        x: u64,
+        y: u64,
+    }
+
+    impl From<PointV1> for Point {
+        fn from(PointV1 { x }: PointV1) -> Self {
+            Point { x: x.into(), y: 0 }
+        }
+    }
+
+    impl From<PointV2> for Point {
+        fn from(PointV2 { x, y }: PointV2) -> Self {
+            Point {
+                x: x.into(),
+                y: y.into(),
+            }
+        }
+    }
+
+    impl Versioned for PointV1 {
+        const SCHEMA_ID: u32 = 0x706f6e74;
+        const VERSION: u16 = 1;
+    }
+
+    impl Versioned for PointV2 {
+        const SCHEMA_ID: u32 = 0x706f6e74;
+        const VERSION: u16 = 2;
+    }
+
+    impl Versioned for Point {
+        const SCHEMA_ID: u32 = 0x706f6e74;
+        const VERSION: u16 = 3;
+
+        fn migrations<Opts: Options + Clone>(
+            migrations: Migrations<'_, Opts, Self>,
+        ) -> Migrations<'_, Opts, Self> {
+            migrations.from::<PointV1>(1).from::<PointV2>(2)
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Other(u64);
+
+    impl Versioned for Other {
+        const SCHEMA_ID: u32 = 0x6f746872;
+        const VERSION: u16 = 1;
+    }
+
+    #[test]
+    fn round_trip() {
+        let point = Point { x: 1, y: 2 };
+
+        let bytes = crate::default().serialize_envelope(&point).unwrap();
+
+        assert_eq!(&bytes[..4], &MAGIC);
+        assert_eq!(&bytes[4..8], &Point::SCHEMA_ID.to_be_bytes());
+        assert_eq!(&bytes[8..10], &Point::VERSION.to_be_bytes());
+        assert_eq!(
+            crate::default()
+                .deserialize_envelope::<Point>(&bytes)
+                .unwrap(),
+            point
+        );
+    }
+
+    #[test]
+    fn migrates_older_versions() {
+        let v1 = crate::default()
+            .serialize_envelope(&PointV1 { x: 7 })
+            .unwrap();
+        let v2 = crate::default()
+            .serialize_envelope(&PointV2 { x: 7, y: 9 })
+            .unwrap();
+
+        assert_eq!(
+            crate::default().deserialize_envelope::<Point>(&v1).unwrap(),
+            Point { x: 7, y: 0 }
+        );
+        assert_eq!(
+            crate::default().deserialize_envelope::<Point>(&v2).unwrap(),
+            Point { x: 7, y: 9 }
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        let bytes = crate::default()
+            .serialize_envelope(&Point { x: 1, y: 2 })
+            .unwrap();
+
+        assert!(matches!(
+            crate::default().deserialize_envelope::<PointV2>(&bytes),
+            Err(EnvelopeError::UnsupportedVersion {
+                schema_id: 0x706f6e74,
+                version: 3,
+                current: 2,
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_schema_mismatch() {
+        let bytes = crate::default().serialize_envelope(&Other(1)).unwrap();
+
+        assert!(matches!(
+            crate::default().deserialize_envelope::<Point>(&bytes),
+            Err(EnvelopeError::SchemaMismatch {
+                expected: 0x706f6e74,
+                actual: 0x6f746872,
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_magic() {
+        let bytes = crate::default().serialize(&Point { x: 1, y: 2 }).unwrap();
+
+        assert!(matches!(
+            crate::default().deserialize_envelope::<Point>(&bytes),
+            Err(EnvelopeError::BadMagic([0, 0, 0, 0]))
+        ));
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        assert!(matches!(
+            crate::default().deserialize_envelope::<Point>(&MAGIC),
+            Err(EnvelopeError::TruncatedHeader(4))
+        ));
+    }
+
+    #[test]
+    fn header_layout_does_not_depend_on_the_codec() {
+        let point = Point { x: 1, y: 2 };
+
+        let big_endian = crate::default().serialize_envelope(&point).unwrap();
+        let little_endian = crate::sp1_compatible().serialize_envelope(&point).unwrap();
+
+        assert_eq!(big_endian[..HEADER_LEN], little_endian[..HEADER_LEN]);
+        assert_ne!(big_endian[HEADER_LEN..], little_endian[HEADER_LEN..]);
+        assert_eq!(
+            crate::sp1_compatible()
+                .deserialize_envelope::<Point>(&little_endian)
+                .unwrap(),
+            point
+        );
+    }
+
+    #[test]
+    fn limit_covers_the_header() {
+        let point = Point { x: 1, y: 2 };
+        // 10 bytes of header and 16 bytes of payload.
+        let bytes = crate::default().serialize_envelope(&point).unwrap();
+        assert_eq!(bytes.len(), 26);
+
+        assert!(crate::default()
+            .with_limit(25)
+            .serialize_envelope(&point)
+            .is_err());
+        assert_eq!(
+            crate::default()
+                .with_limit(26)
+                .deserialize_envelope::<Point>(&bytes)
+                .unwrap(),
+            point
+        );
+    }
+}
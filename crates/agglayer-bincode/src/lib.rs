@@ -1,5 +1,13 @@
 pub use bincode::{Error, ErrorKind, Options, Result};
 
+pub use crate::{
+    envelope::{EnvelopeError, Migrations, Versioned},
+    seq::SeqDecoder,
+};
+
+pub mod envelope;
+mod seq;
+
 /// Bincode configuration. Deliberately inaccessible from the outside.
 mod options {
     use bincode::config::{
@@ -32,18 +40,33 @@ mod options {
 
 /// Bincode codec with opinionated settings.
 #[derive(Clone, Debug)]
-pub struct Codec<Opts>(Opts);
+pub struct Codec<Opts> {
+    options: Opts,
+    /// Limit set with [`Codec::with_limit`], shared by the elements of a
+    /// sequence decoded with [`Codec::deserialize_seq_from`].
+    limit: Option<u64>,
+}
+
+impl<Opts> Codec<Opts> {
+    #[inline]
+    fn new(options: Opts) -> Self {
+        Self {
+            options,
+            limit: None,
+        }
+    }
+}
 
 /// Create a bincode codec with default agglayer settings.
 #[inline]
 pub fn default() -> Codec<options::Default> {
-    Codec(options::default())
+    Codec::new(options::default())
 }
 
 /// Create a bincode codec with settings compatible with SP1 payload encoding.
 #[inline]
 pub fn sp1_compatible() -> Codec<options::SP1Compatible> {
-    Codec(options::sp1_compatible())
+    Codec::new(options::sp1_compatible())
 }
 
 /// Create a bincode codec with settings used by `sp1`.
@@ -65,7 +88,10 @@ impl<Opts: Options> Codec<Opts> {
     /// Impose a limit on encoding / decoding size.
     #[inline]
     pub fn with_limit(self, max: u64) -> Codec<options::Limited<Opts>> {
-        Codec(self.0.with_limit(max))
+        Codec {
+            options: self.options.with_limit(max),
+            limit: Some(max),
+        }
     }
 
     /// Encode an object into a byte vector.
@@ -74,7 +100,7 @@ impl<Opts: Options> Codec<Opts> {
     where
         T: ?Sized + serde::Serialize,
     {
-        self.0.serialize(item)
+        self.options.serialize(item)
     }
 
     /// Encode an object into a writer.
@@ -84,16 +110,30 @@ impl<Opts: Options> Codec<Opts> {
         W: std::io::Write,
         T: ?Sized + serde::Serialize,
     {
-        self.0.serialize_into(writer, item)
+        self.options.serialize_into(writer, item)
+    }
+
+    /// Compute the size of the encoding of an object without encoding it.
+    ///
+    /// Fails if the size exceeds the limit set with [`Codec::with_limit`].
+    #[inline]
+    pub fn serialized_size<T>(self, item: &T) -> Result<u64>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        self.options.serialized_size(item)
     }
 
     /// Decode an object from a slice.
+    ///
+    /// Types with borrowed fields (such as `&'a [u8]` or `&'a str`) borrow
+    /// them from the slice instead of copying.
     #[inline]
     pub fn deserialize<'a, T>(self, bytes: &'a [u8]) -> Result<T>
     where
         T: serde::Deserialize<'a>,
     {
-        self.0.deserialize(bytes)
+        self.options.deserialize(bytes)
     }
 
     /// Decode an object from a reader.
@@ -103,7 +143,7 @@ impl<Opts: Options> Codec<Opts> {
         T: serde::de::DeserializeOwned,
         R: std::io::Read,
     {
-        self.0.deserialize_from(reader)
+        self.options.deserialize_from(reader)
     }
 }
 
@@ -127,6 +167,58 @@ mod test {
         assert_eq!(stdin0.read::<NetworkId>(), stdin1.read::<NetworkId>());
     }
 
+    #[test]
+    fn deserialize_borrows_from_input() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Blob<'a> {
+            version: u32,
+            payload: &'a [u8],
+        }
+
+        let blob = Blob {
+            version: 6,
+            payload: &[0xde, 0xad, 0xbe, 0xef],
+        };
+        let bytes = super::default().serialize(&blob).unwrap();
+
+        let decoded: Blob = super::default().deserialize(&bytes).unwrap();
+
+        assert_eq!(decoded.version, 6);
+        assert_eq!(decoded.payload, blob.payload);
+        assert!(bytes.as_ptr_range().contains(&decoded.payload.as_ptr()));
+    }
+
+    #[rstest::rstest]
+    #[case::u32(&0x00112233_u32, 4)]
+    #[case::bytes(&vec![0_u8; 3], 11)]
+    #[case::string(&"agglayer", 16)]
+    #[case::tuple(&(1_u8, Some(2_u64)), 10)]
+    fn serialized_size_matches_encoding<T: serde::Serialize + ?Sized>(
+        #[case] item: &T,
+        #[case] size: u64,
+    ) {
+        assert_eq!(super::default().serialized_size(item).unwrap(), size);
+        assert_eq!(super::default().serialize(item).unwrap().len() as u64, size);
+        assert_eq!(super::sp1_compatible().serialized_size(item).unwrap(), size);
+    }
+
+    #[test]
+    fn serialized_size_enforces_limit() {
+        let item = vec![0_u8; 32];
+
+        assert_eq!(
+            super::default()
+                .with_limit(40)
+                .serialized_size(&item)
+                .unwrap(),
+            40
+        );
+        assert!(super::default()
+            .with_limit(39)
+            .serialized_size(&item)
+            .is_err());
+    }
+
     #[test]
     fn sp1_compatible_round_trips_network_id() {
         type NetworkId = u32;
@@ -0,0 +1,226 @@
+//! Incremental decoding of length-prefixed sequences.
+
+use std::{io::Read, marker::PhantomData};
+
+use bincode::Options;
+use serde::de::DeserializeOwned;
+
+use crate::{Codec, Result};
+
+/// Decoder yielding the elements of an encoded sequence one at a time.
+///
+/// Created by [`Codec::deserialize_seq_from`]. The elements are decoded
+/// lazily from the underlying reader, so the whole sequence never has to be
+/// held in memory. The decoder stops after the first error.
+pub struct SeqDecoder<T, R, Opts> {
+    codec: Codec<Opts>,
+    reader: CountingReader<R>,
+    remaining: u64,
+    _item: PhantomData<fn() -> T>,
+}
+
+/// Reader keeping track of the number of bytes read so far.
+struct CountingReader<R> {
+    inner: R,
+    bytes_read: u64,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.bytes_read += read as u64;
+        Ok(read)
+    }
+}
+
+impl<T, R, Opts> SeqDecoder<T, R, Opts> {
+    /// Number of elements left to decode.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Get back the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader.inner
+    }
+}
+
+impl<T, R, Opts> Iterator for SeqDecoder<T, R, Opts>
+where
+    T: DeserializeOwned,
+    R: Read,
+    Opts: Options + Clone,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let options = self.codec.options.clone();
+        let item = match self.codec.limit {
+            // Whatever is left of the limit once the previous elements are
+            // decoded.
+            Some(limit) => options
+                .with_limit(limit.saturating_sub(self.reader.bytes_read))
+                .deserialize_from(&mut self.reader),
+            None => options.deserialize_from(&mut self.reader),
+        };
+        self.remaining = match item {
+            Ok(_) => self.remaining - 1,
+            Err(_) => 0,
+        };
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, usize::try_from(self.remaining).ok())
+    }
+}
+
+impl<Opts: Options + Clone> Codec<Opts> {
+    /// Decode a length-prefixed sequence, such as a `Vec<T>`, from a reader
+    /// one element at a time.
+    ///
+    /// Only the length prefix is read upfront. When the codec is limited
+    /// using [`Codec::with_limit`], the limit applies to the whole sequence,
+    /// as when decoding it at once.
+    pub fn deserialize_seq_from<T, R>(self, reader: R) -> Result<SeqDecoder<T, R, Opts>>
+    where
+        T: DeserializeOwned,
+        R: Read,
+    {
+        let mut reader = CountingReader {
+            inner: reader,
+            bytes_read: 0,
+        };
+        let remaining = self.clone().deserialize_from(&mut reader)?;
+
+        Ok(SeqDecoder {
+            codec: self,
+            reader,
+            remaining,
+            _item: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Exit {
+        network: u32,
+        amount: u64,
+        metadata: Vec<u8>,
+    }
+
+    fn exits() -> Vec<Exit> {
+        (0..5)
+            .map(|i| Exit {
+                network: i,
+                amount: u64::from(i) * 1000,
+                metadata: vec![i as u8; i as usize],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decodes_sequence_incrementally() {
+        let exits = exits();
+        let bytes = crate::default().serialize(&exits).unwrap();
+
+        let mut decoder = crate::default()
+            .deserialize_seq_from::<Exit, _>(bytes.as_slice())
+            .unwrap();
+        assert_eq!(decoder.remaining(), 5);
+
+        assert_eq!(decoder.next().unwrap().unwrap(), exits[0]);
+        assert_eq!(decoder.remaining(), 4);
+
+        let rest = decoder.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(rest, exits[1..]);
+        assert!(decoder.next().is_none());
+        assert!(decoder.into_inner().is_empty());
+    }
+
+    #[test]
+    fn decodes_sp1_compatible_sequence() {
+        let exits = exits();
+        let bytes = crate::sp1_compatible().serialize(&exits).unwrap();
+
+        let decoded = crate::sp1_compatible()
+            .deserialize_seq_from::<Exit, _>(bytes.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(decoded, exits);
+    }
+
+    #[test]
+    fn stops_after_truncated_element() {
+        let exits = exits();
+        let bytes = crate::default().serialize(&exits).unwrap();
+        let truncated = &bytes[..bytes.len() - 1];
+
+        let mut decoder = crate::default()
+            .deserialize_seq_from::<Exit, _>(truncated)
+            .unwrap();
+
+        for exit in &exits[..4] {
+            assert_eq!(&decoder.next().unwrap().unwrap(), exit);
+        }
+        assert!(decoder.next().unwrap().is_err());
+        assert!(decoder.next().is_none());
+    }
+
+    #[test]
+    fn does_not_trust_length_prefix() {
+        let bytes = crate::default().serialize(&u64::MAX).unwrap();
+
+        let mut decoder = crate::default()
+            .deserialize_seq_from::<Exit, _>(bytes.as_slice())
+            .unwrap();
+
+        assert_eq!(decoder.remaining(), u64::MAX);
+        assert!(decoder.next().unwrap().is_err());
+        assert!(decoder.next().is_none());
+    }
+
+    #[test]
+    fn limit_applies_to_whole_sequence() {
+        let exits = exits();
+        let bytes = crate::default().serialize(&exits).unwrap();
+        let size = bytes.len() as u64;
+
+        let decoded = crate::default()
+            .with_limit(size)
+            .deserialize_seq_from::<Exit, _>(bytes.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(decoded, exits);
+
+        // Each element fits in the limit, but not all of them together.
+        let largest = crate::default().serialized_size(&exits[4]).unwrap();
+        assert!(largest < size - 1);
+        let mut decoder = crate::default()
+            .with_limit(size - 1)
+            .deserialize_seq_from::<Exit, _>(bytes.as_slice())
+            .unwrap();
+        for exit in &exits[..4] {
+            assert_eq!(&decoder.next().unwrap().unwrap(), exit);
+        }
+        assert!(matches!(
+            *decoder.next().unwrap().unwrap_err(),
+            crate::ErrorKind::SizeLimit
+        ));
+        assert!(decoder.next().is_none());
+    }
+}
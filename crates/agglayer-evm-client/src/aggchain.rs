@@ -0,0 +1,16 @@
+use alloy::sol;
+
+sol! {
+    /// Getters of the aggchain contracts, verified through the agglayer
+    /// gateway.
+    #[sol(rpc)]
+    #[derive(Debug, PartialEq, Eq)]
+    contract AggchainBase {
+        /// Returns the verifying key registered for the selector, either owned
+        /// by the aggchain or the default one of the agglayer gateway.
+        function getAggchainVKey(bytes4 aggchainVKeySelector)
+            external
+            view
+            returns (bytes32 aggchainVKey);
+    }
+}
@@ -5,6 +5,7 @@ use alloy::{
         Identity, RootProvider,
     },
 };
+use url::Url;
 
 pub type AlloyFillProvider = FillProvider<
     JoinFill<
@@ -19,3 +20,39 @@ pub type AlloyFillProvider = FillProvider<
 pub trait AlloyRpc: Send + Sync {
     fn alloy_rpc(&self) -> &AlloyFillProvider;
 }
+
+/// Wraps a root provider with the fillers of [`AlloyFillProvider`].
+pub(crate) fn fill_provider(root: RootProvider) -> AlloyFillProvider {
+    FillProvider::new(
+        root,
+        JoinFill::new(
+            Identity,
+            JoinFill::new(
+                GasFiller::default(),
+                JoinFill::new(
+                    BlobGasFiller::default(),
+                    JoinFill::new(NonceFiller::default(), ChainIdFiller::default()),
+                ),
+            ),
+        ),
+    )
+}
+
+/// [`AlloyRpc`] sending its requests to a single HTTP endpoint.
+pub struct HttpRpc {
+    provider: AlloyFillProvider,
+}
+
+impl HttpRpc {
+    pub fn new(url: Url) -> Self {
+        Self {
+            provider: fill_provider(RootProvider::new_http(url)),
+        }
+    }
+}
+
+impl AlloyRpc for HttpRpc {
+    fn alloy_rpc(&self) -> &AlloyFillProvider {
+        &self.provider
+    }
+}
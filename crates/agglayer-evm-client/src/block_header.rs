@@ -0,0 +1,231 @@
+use agglayer_primitives::Digest;
+use alloy::consensus::Header;
+use unified_bridge::L1InfoTreeLeafInner;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum BlockHeaderError {
+    #[error("Header of block {number} hashes to {computed} instead of {claimed}")]
+    HashMismatch {
+        number: u64,
+        claimed: Digest,
+        computed: Digest,
+    },
+
+    #[error("Block {number} follows block {previous}")]
+    NotConsecutive { number: u64, previous: u64 },
+
+    #[error(
+        "Parent hash {parent_hash} of block {number} is not the previous hash {previous_hash}"
+    )]
+    ParentHashMismatch {
+        number: u64,
+        parent_hash: Digest,
+        previous_hash: Digest,
+    },
+
+    #[error("L1 info tree leaf does not match block {number}")]
+    L1InfoTreeLeafMismatch { number: u64 },
+}
+
+/// Header of a block, along with the hash the node reported for it.
+///
+/// The reported hash is only trusted once checked with
+/// [`BlockHeader::verify_hash`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockHeader {
+    hash: Digest,
+    header: Header,
+}
+
+impl BlockHeader {
+    pub fn new(hash: Digest, header: Header) -> Self {
+        Self { hash, header }
+    }
+
+    pub fn hash(&self) -> Digest {
+        self.hash
+    }
+
+    pub fn number(&self) -> u64 {
+        self.header.number
+    }
+
+    pub fn parent_hash(&self) -> Digest {
+        self.header.parent_hash.into()
+    }
+
+    pub fn timestamp(&self) -> u64 {
+        self.header.timestamp
+    }
+
+    pub fn state_root(&self) -> Digest {
+        self.header.state_root.into()
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Checks that the RLP encoding of the header hashes to the reported
+    /// block hash.
+    pub fn verify_hash(&self) -> Result<(), BlockHeaderError> {
+        let computed = Digest::from(self.header.hash_slow());
+        if computed != self.hash {
+            return Err(BlockHeaderError::HashMismatch {
+                number: self.number(),
+                claimed: self.hash,
+                computed,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that the L1 info tree leaf was inserted by this block, which
+    /// records the hash of its parent block and its own timestamp.
+    pub fn verify_l1_info_tree_leaf(
+        &self,
+        leaf: &L1InfoTreeLeafInner,
+    ) -> Result<(), BlockHeaderError> {
+        if leaf.block_hash != self.parent_hash() || leaf.timestamp != self.timestamp() {
+            return Err(BlockHeaderError::L1InfoTreeLeafMismatch {
+                number: self.number(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the headers hash to their reported hashes and form a chain of
+/// consecutive blocks, each one being the parent of the next.
+pub fn verify_header_chain(headers: &[BlockHeader]) -> Result<(), BlockHeaderError> {
+    for header in headers {
+        header.verify_hash()?;
+    }
+    for pair in headers.windows(2) {
+        let [previous, header] = pair else {
+            unreachable!()
+        };
+        if previous.number().checked_add(1) != Some(header.number()) {
+            return Err(BlockHeaderError::NotConsecutive {
+                number: header.number(),
+                previous: previous.number(),
+            });
+        }
+        if header.parent_hash() != previous.hash() {
+            return Err(BlockHeaderError::ParentHashMismatch {
+                number: header.number(),
+                parent_hash: header.parent_hash(),
+                previous_hash: previous.hash(),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloy::primitives::{b256, bytes, Bloom, B64, U256};
+    use hex_literal::hex;
+
+    use super::*;
+
+    /// Header of the Ethereum mainnet genesis block.
+    fn mainnet_genesis() -> BlockHeader {
+        BlockHeader::new(
+            Digest(hex!(
+                "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+            )),
+            Header {
+                ommers_hash: b256!(
+                    "1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+                ),
+                state_root: b256!(
+                    "d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544"
+                ),
+                transactions_root: b256!(
+                    "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+                ),
+                receipts_root: b256!(
+                    "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+                ),
+                logs_bloom: Bloom::ZERO,
+                difficulty: U256::from(0x400000000_u64),
+                gas_limit: 5000,
+                extra_data: bytes!(
+                    "11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa"
+                ),
+                nonce: B64::from(0x42_u64),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Chain of `len` headers whose hashes are consistent.
+    pub(crate) fn chain(len: u64) -> Vec<BlockHeader> {
+        let mut parent_hash = Digest::ZERO;
+        (100..100 + len)
+            .map(|number| {
+                let header = Header {
+                    number,
+                    parent_hash: parent_hash.into(),
+                    timestamp: 1_700_000_000 + 12 * number,
+                    ..Default::default()
+                };
+                parent_hash = header.hash_slow().into();
+                BlockHeader::new(parent_hash, header)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn recorded_header_hashes_to_its_block_hash() {
+        let genesis = mainnet_genesis();
+        assert_eq!(genesis.verify_hash(), Ok(()));
+
+        let mut tampered = genesis.clone();
+        tampered.header.state_root = Default::default();
+        assert!(matches!(
+            tampered.verify_hash(),
+            Err(BlockHeaderError::HashMismatch { number: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn parent_chain_is_checked() {
+        let headers = chain(4);
+        assert_eq!(verify_header_chain(&headers), Ok(()));
+        assert_eq!(verify_header_chain(&[]), Ok(()));
+
+        assert_eq!(
+            verify_header_chain(&[headers[0].clone(), headers[2].clone()]),
+            Err(BlockHeaderError::NotConsecutive {
+                number: 102,
+                previous: 100,
+            })
+        );
+
+        // A consistent header on another fork.
+        let mut fork = headers[1].header().clone();
+        fork.parent_hash = Default::default();
+        let fork = BlockHeader::new(fork.hash_slow().into(), fork);
+        assert!(matches!(
+            verify_header_chain(&[headers[0].clone(), fork]),
+            Err(BlockHeaderError::ParentHashMismatch { number: 101, .. })
+        ));
+    }
+
+    #[test]
+    fn l1_info_tree_leaf_is_checked_against_the_header() {
+        let headers = chain(2);
+        let leaf = L1InfoTreeLeafInner {
+            global_exit_root: Digest([0x42; 32]),
+            block_hash: headers[0].hash(),
+            timestamp: headers[1].timestamp(),
+        };
+        assert_eq!(headers[1].verify_l1_info_tree_leaf(&leaf), Ok(()));
+        assert_eq!(
+            headers[0].verify_l1_info_tree_leaf(&leaf),
+            Err(BlockHeaderError::L1InfoTreeLeafMismatch { number: 100 })
+        );
+    }
+}
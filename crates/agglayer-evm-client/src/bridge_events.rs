@@ -0,0 +1,254 @@
+use agglayer_primitives::{Address, U256};
+use alloy::{
+    primitives::Log,
+    sol,
+    sol_types::{SolEvent as _, SolEventInterface as _},
+};
+use unified_bridge::{BridgeExit, GlobalIndex, LeafType, NetworkId, TokenInfo};
+
+sol! {
+    /// Events of the `PolygonZkEVMBridgeV2` contract.
+    #[derive(Debug, PartialEq, Eq)]
+    #[allow(clippy::too_many_arguments)]
+    contract PolygonZkEVMBridgeV2 {
+        event BridgeEvent(
+            uint8 leafType,
+            uint32 originNetwork,
+            address originAddress,
+            uint32 destinationNetwork,
+            address destinationAddress,
+            uint256 amount,
+            bytes metadata,
+            uint32 depositCount
+        );
+
+        event ClaimEvent(
+            uint256 globalIndex,
+            uint32 originNetwork,
+            address originAddress,
+            address destinationAddress,
+            uint256 amount
+        );
+    }
+}
+
+/// Bridge exit inserted in the local exit tree of the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedBridgeExit {
+    /// Index of the leaf in the local exit tree, i.e. the deposit count.
+    pub leaf_index: u32,
+    pub bridge_exit: BridgeExit,
+}
+
+/// Bridge exit claimed on the network.
+///
+/// The claim event only carries part of the claimed bridge exit, the rest
+/// has to be fetched from the origin network to build an
+/// [`ImportedBridgeExit`](unified_bridge::ImportedBridgeExit).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedClaim {
+    pub global_index: GlobalIndex,
+    pub token_info: TokenInfo,
+    pub dest_address: Address,
+    pub amount: U256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeContractEvent {
+    BridgeExit(DecodedBridgeExit),
+    Claim(DecodedClaim),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DecodeBridgeEventError {
+    #[error("Decoding bridge contract log")]
+    Decoding(#[source] alloy::sol_types::Error),
+
+    #[error("Invalid leaf type {leaf_type}")]
+    InvalidLeafType { leaf_type: u8 },
+
+    #[error("Invalid global index {global_index}")]
+    InvalidGlobalIndex { global_index: U256 },
+}
+
+impl BridgeContractEvent {
+    /// Topics of the decoded events, used to filter the bridge contract logs.
+    pub const SIGNATURE_HASHES: [alloy::primitives::B256; 2] = [
+        PolygonZkEVMBridgeV2::BridgeEvent::SIGNATURE_HASH,
+        PolygonZkEVMBridgeV2::ClaimEvent::SIGNATURE_HASH,
+    ];
+
+    /// Decodes a log of the bridge contract, returning `None` for the events
+    /// other than the bridge and claim ones.
+    pub fn decode(log: &Log) -> Result<Option<Self>, DecodeBridgeEventError> {
+        if !log
+            .topics()
+            .first()
+            .is_some_and(|topic| Self::SIGNATURE_HASHES.contains(topic))
+        {
+            return Ok(None);
+        }
+
+        let event = PolygonZkEVMBridgeV2::PolygonZkEVMBridgeV2Events::decode_log(log)
+            .map_err(DecodeBridgeEventError::Decoding)?
+            .data;
+
+        let event = match event {
+            PolygonZkEVMBridgeV2::PolygonZkEVMBridgeV2Events::BridgeEvent(event) => {
+                let leaf_type = LeafType::try_from(event.leafType).map_err(|_| {
+                    DecodeBridgeEventError::InvalidLeafType {
+                        leaf_type: event.leafType,
+                    }
+                })?;
+                Self::BridgeExit(DecodedBridgeExit {
+                    leaf_index: event.depositCount,
+                    bridge_exit: BridgeExit::new(
+                        leaf_type,
+                        NetworkId::new(event.originNetwork),
+                        event.originAddress.into(),
+                        NetworkId::new(event.destinationNetwork),
+                        event.destinationAddress.into(),
+                        event.amount,
+                        event.metadata.into(),
+                    ),
+                })
+            }
+            PolygonZkEVMBridgeV2::PolygonZkEVMBridgeV2Events::ClaimEvent(event) => {
+                let global_index = GlobalIndex::try_from(event.globalIndex).map_err(|_| {
+                    DecodeBridgeEventError::InvalidGlobalIndex {
+                        global_index: event.globalIndex,
+                    }
+                })?;
+                Self::Claim(DecodedClaim {
+                    global_index,
+                    token_info: TokenInfo {
+                        origin_network: NetworkId::new(event.originNetwork),
+                        origin_token_address: event.originAddress.into(),
+                    },
+                    dest_address: event.destinationAddress.into(),
+                    amount: event.amount,
+                })
+            }
+        };
+
+        Ok(Some(event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::{keccak::keccak256, Digest};
+    use alloy::{
+        rpc::types::Log as RpcLog,
+        sol_types::{sol_data, SolType as _},
+    };
+
+    use super::*;
+
+    /// Logs of the bridge contract as returned by `eth_getLogs`.
+    fn recorded_logs() -> Vec<RpcLog> {
+        serde_json::from_str(include_str!("../tests/fixtures/bridge_logs.json")).unwrap()
+    }
+
+    #[test]
+    fn decode_recorded_logs() {
+        let events = recorded_logs()
+            .iter()
+            .map(|log| BridgeContractEvent::decode(&log.inner).unwrap())
+            .collect::<Vec<_>>();
+
+        // ERC-20 metadata sent along with the first bridging of a token.
+        let metadata = <(sol_data::String, sol_data::String, sol_data::Uint<8>)>::abi_encode_params(
+            &("USD Coin".to_owned(), "USDC".to_owned(), 6),
+        );
+
+        assert_eq!(
+            events,
+            [
+                Some(BridgeContractEvent::BridgeExit(DecodedBridgeExit {
+                    leaf_index: 41,
+                    bridge_exit: BridgeExit {
+                        leaf_type: LeafType::Transfer,
+                        token_info: TokenInfo {
+                            origin_network: NetworkId::new(0),
+                            origin_token_address: Address::ZERO,
+                        },
+                        dest_network: NetworkId::new(1),
+                        dest_address: Address::new(hex_literal::hex!(
+                            "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678"
+                        )),
+                        amount: U256::from(250_000_000_000_000_000_u128),
+                        metadata: Some(keccak256(&[])),
+                    },
+                })),
+                Some(BridgeContractEvent::BridgeExit(DecodedBridgeExit {
+                    leaf_index: 42,
+                    bridge_exit: BridgeExit {
+                        leaf_type: LeafType::Transfer,
+                        token_info: TokenInfo {
+                            origin_network: NetworkId::new(0),
+                            origin_token_address: Address::new(hex_literal::hex!(
+                                "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+                            )),
+                        },
+                        dest_network: NetworkId::new(3),
+                        dest_address: Address::new(hex_literal::hex!(
+                            "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678"
+                        )),
+                        amount: U256::from(1_000_000_u64),
+                        metadata: Some(keccak256(&metadata)),
+                    },
+                })),
+                Some(BridgeContractEvent::Claim(DecodedClaim {
+                    global_index: GlobalIndex::new(NetworkId::new(1), 17),
+                    token_info: TokenInfo {
+                        origin_network: NetworkId::new(0),
+                        origin_token_address: Address::ZERO,
+                    },
+                    dest_address: Address::new(hex_literal::hex!(
+                        "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678"
+                    )),
+                    amount: U256::from(100_000_000_000_000_000_u128),
+                })),
+                // `NewWrappedToken` event.
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn metadata_is_hashed_like_bridge_exit_new() {
+        let Some(BridgeContractEvent::BridgeExit(decoded)) =
+            BridgeContractEvent::decode(&recorded_logs()[0].inner).unwrap()
+        else {
+            panic!("expected a bridge exit");
+        };
+
+        // An empty metadata hashes to the same value as a missing one.
+        assert_eq!(
+            decoded.bridge_exit.metadata,
+            Some(Digest(hex_literal::hex!(
+                "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+            )))
+        );
+    }
+
+    #[test]
+    fn invalid_logs_are_rejected() {
+        let mut log = recorded_logs()[0].inner.clone();
+        let mut data = log.data.data.to_vec();
+        data[31] = 7;
+        log.data.data = data.into();
+        assert!(matches!(
+            BridgeContractEvent::decode(&log),
+            Err(DecodeBridgeEventError::InvalidLeafType { leaf_type: 7 })
+        ));
+
+        let mut log = recorded_logs()[0].inner.clone();
+        log.data.data = log.data.data[..64].to_vec().into();
+        assert!(matches!(
+            BridgeContractEvent::decode(&log),
+            Err(DecodeBridgeEventError::Decoding(_))
+        ));
+    }
+}
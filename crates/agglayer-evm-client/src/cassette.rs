@@ -0,0 +1,503 @@
+//! Records the JSON-RPC requests sent to a node into a cassette file and
+//! replays them offline, so that the tests of the RPC consumers do not
+//! depend on a live endpoint.
+
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use alloy::{
+    providers::RootProvider,
+    rpc::{
+        client::RpcClient,
+        json_rpc::{RequestPacket, Response, ResponsePacket, SerializedRequest},
+    },
+    transports::{
+        http::ReqwestTransport, Transport, TransportError, TransportErrorKind, TransportFut,
+    },
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tower::Service;
+use url::Url;
+
+use crate::{
+    alloy_rpc::{fill_provider, AlloyFillProvider},
+    AlloyRpc,
+};
+
+/// Recorded request along with the response of the node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+    /// Response object without its `jsonrpc` and `id` members.
+    pub response: Value,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CassetteError {
+    #[error("Reading cassette {path:?}")]
+    Reading {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Parsing cassette {path:?}")]
+    Parsing {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+
+    #[error("Writing cassette {path:?}")]
+    Writing {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Serializing cassette {path:?}")]
+    Serializing {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// Error returned by a replayed request missing from the cassette.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "No recorded response left for {method} with params {params} in cassette {path:?} ({replayed} \
+     matching responses already replayed), record the cassette again"
+)]
+pub struct MissingInteraction {
+    pub method: String,
+    pub params: Value,
+    pub path: PathBuf,
+    pub replayed: usize,
+}
+
+fn params(request: &SerializedRequest) -> Value {
+    let mut params = request
+        .params()
+        .and_then(|params| serde_json::from_str(params.get()).ok())
+        .unwrap_or(Value::Null);
+    if request.method() == "eth_getLogs" {
+        sort_topics(&mut params);
+    }
+    params
+}
+
+/// Sorts the alternatives of each topic of the `eth_getLogs` filters, which
+/// alloy keeps in a hash set and thus serializes in no particular order.
+fn sort_topics(params: &mut Value) {
+    for filter in params.as_array_mut().into_iter().flatten() {
+        let Some(topics) = filter.get_mut("topics").and_then(Value::as_array_mut) else {
+            continue;
+        };
+        for alternatives in topics.iter_mut().filter_map(Value::as_array_mut) {
+            alternatives.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+        }
+    }
+}
+
+fn requests(packet: &RequestPacket) -> &[SerializedRequest] {
+    match packet {
+        RequestPacket::Single(request) => std::slice::from_ref(request),
+        RequestPacket::Batch(requests) => requests,
+    }
+}
+
+fn responses(packet: &ResponsePacket) -> &[Response] {
+    match packet {
+        ResponsePacket::Single(response) => std::slice::from_ref(response),
+        ResponsePacket::Batch(responses) => responses,
+    }
+}
+
+/// Transport forwarding the requests to the wrapped one and recording them
+/// along with their responses.
+#[derive(Clone)]
+struct RecordingTransport<T> {
+    inner: T,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl<T> RecordingTransport<T> {
+    fn record(&self, requests: &RequestPacket, responses: &ResponsePacket) {
+        let mut interactions = self.interactions.lock().unwrap();
+        for request in self::requests(requests) {
+            let Some(response) = self::responses(responses)
+                .iter()
+                .find(|response| response.id == *request.id())
+            else {
+                continue;
+            };
+            let Ok(Value::Object(mut response)) = serde_json::to_value(response) else {
+                continue;
+            };
+            response.remove("jsonrpc");
+            response.remove("id");
+            interactions.push(Interaction {
+                method: request.method().to_owned(),
+                params: params(request),
+                response: Value::Object(response),
+            });
+        }
+    }
+}
+
+impl<T: Transport + Clone> Service<RequestPacket> for RecordingTransport<T> {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, requests: RequestPacket) -> Self::Future {
+        let recorder = self.clone();
+        let mut inner = self.inner.clone();
+        Box::pin(async move {
+            let responses = inner.call(requests.clone()).await?;
+            recorder.record(&requests, &responses);
+            Ok(responses)
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Replay {
+    path: PathBuf,
+    interactions: Vec<Interaction>,
+    replayed: Vec<bool>,
+}
+
+impl Replay {
+    fn response(&mut self, request: &SerializedRequest) -> Result<Response, TransportError> {
+        let method = request.method();
+        let params = params(request);
+        let mut matching = self
+            .interactions
+            .iter()
+            .zip(&mut self.replayed)
+            .filter(|(interaction, _)| interaction.method == method && interaction.params == params)
+            .peekable();
+
+        let mut replayed = 0;
+        while let Some((_, true)) = matching.peek() {
+            replayed += 1;
+            matching.next();
+        }
+        let Some((interaction, is_replayed)) = matching.find(|(_, replayed)| !**replayed) else {
+            return Err(TransportErrorKind::custom(MissingInteraction {
+                method: method.to_owned(),
+                params,
+                path: self.path.clone(),
+                replayed,
+            }));
+        };
+        *is_replayed = true;
+
+        let mut response = interaction.response.clone();
+        if let Value::Object(response) = &mut response {
+            response.insert("jsonrpc".to_owned(), "2.0".into());
+            response.insert(
+                "id".to_owned(),
+                serde_json::to_value(request.id()).map_err(TransportError::ser_err)?,
+            );
+        }
+        serde_json::from_value(response).map_err(|error| TransportError::deser_err(error, ""))
+    }
+}
+
+/// Transport answering the requests with the responses of a cassette.
+///
+/// Identical requests are answered with their recorded responses in order,
+/// each response being replayed once.
+#[derive(Clone)]
+struct ReplayTransport {
+    replay: Arc<Mutex<Replay>>,
+}
+
+impl Service<RequestPacket> for ReplayTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, requests: RequestPacket) -> Self::Future {
+        let responses = {
+            let mut replay = self.replay.lock().unwrap();
+            match &requests {
+                RequestPacket::Single(request) => {
+                    replay.response(request).map(ResponsePacket::Single)
+                }
+                RequestPacket::Batch(requests) => requests
+                    .iter()
+                    .map(|request| replay.response(request))
+                    .collect::<Result<_, _>>()
+                    .map(ResponsePacket::Batch),
+            }
+        };
+        Box::pin(async move { responses })
+    }
+}
+
+/// [`AlloyRpc`] recording the requests sent to a node, to be saved into a
+/// cassette with [`RecordingRpc::save`].
+pub struct RecordingRpc {
+    provider: AlloyFillProvider,
+    path: PathBuf,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl RecordingRpc {
+    /// Records the requests sent to the node at `url` into the cassette at
+    /// `path`.
+    pub fn new(url: Url, path: impl Into<PathBuf>) -> Self {
+        Self::with_transport(ReqwestTransport::new(url), path)
+    }
+
+    /// Records the requests sent through `transport` into the cassette at
+    /// `path`.
+    pub fn with_transport<T: Transport + Clone>(transport: T, path: impl Into<PathBuf>) -> Self {
+        let interactions = Arc::default();
+        let transport = RecordingTransport {
+            inner: transport,
+            interactions: Arc::clone(&interactions),
+        };
+        Self {
+            provider: fill_provider(RootProvider::new(RpcClient::new(transport, false))),
+            path: path.into(),
+            interactions,
+        }
+    }
+
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().unwrap().clone()
+    }
+
+    /// Writes the requests recorded so far into the cassette, overwriting
+    /// it.
+    pub fn save(&self) -> Result<(), CassetteError> {
+        let cassette = Cassette {
+            interactions: self.interactions(),
+        };
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|source| CassetteError::Writing {
+                path: self.path.clone(),
+                source,
+            })?;
+        }
+        let file = File::create(&self.path).map_err(|source| CassetteError::Writing {
+            path: self.path.clone(),
+            source,
+        })?;
+        serde_json::to_writer_pretty(file, &cassette).map_err(|source| CassetteError::Serializing {
+            path: self.path.clone(),
+            source,
+        })
+    }
+}
+
+impl AlloyRpc for RecordingRpc {
+    fn alloy_rpc(&self) -> &AlloyFillProvider {
+        &self.provider
+    }
+}
+
+/// [`AlloyRpc`] answering the requests from a cassette, without any network
+/// access.
+///
+/// The requests missing from the cassette fail with a [`MissingInteraction`]
+/// error.
+pub struct ReplayRpc {
+    provider: AlloyFillProvider,
+    replay: Arc<Mutex<Replay>>,
+}
+
+impl ReplayRpc {
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, CassetteError> {
+        let path = path.as_ref().to_owned();
+        let file = File::open(&path).map_err(|source| CassetteError::Reading {
+            path: path.clone(),
+            source,
+        })?;
+        let cassette: Cassette =
+            serde_json::from_reader(BufReader::new(file)).map_err(|source| {
+                CassetteError::Parsing {
+                    path: path.clone(),
+                    source,
+                }
+            })?;
+        Ok(Self::from_interactions(path, cassette.interactions))
+    }
+
+    fn from_interactions(path: PathBuf, interactions: Vec<Interaction>) -> Self {
+        let replay = Arc::new(Mutex::new(Replay {
+            path,
+            replayed: vec![false; interactions.len()],
+            interactions,
+        }));
+        let transport = ReplayTransport {
+            replay: Arc::clone(&replay),
+        };
+        Self {
+            provider: fill_provider(RootProvider::new(RpcClient::new(transport, true))),
+            replay,
+        }
+    }
+
+    /// Returns the recorded requests which were not replayed yet.
+    pub fn remaining(&self) -> Vec<Interaction> {
+        let replay = self.replay.lock().unwrap();
+        replay
+            .interactions
+            .iter()
+            .zip(&replay.replayed)
+            .filter(|(_, replayed)| !**replayed)
+            .map(|(interaction, _)| interaction.clone())
+            .collect()
+    }
+}
+
+impl AlloyRpc for ReplayRpc {
+    fn alloy_rpc(&self) -> &AlloyFillProvider {
+        &self.provider
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::{Address, Digest};
+    use alloy::{
+        providers::Provider,
+        transports::mock::{Asserter, MockTransport},
+    };
+
+    use super::*;
+    use crate::{get_verified_storage::tests::block, GetBlockHash, GetLatestGlobalExitRoot};
+
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "agglayer-evm-client-{}-{name}.json",
+            std::process::id()
+        ))
+    }
+
+    #[tokio::test]
+    async fn recorded_requests_are_replayed() {
+        let path = cassette_path("replayed");
+        let asserter = Asserter::new();
+        asserter.push_success(&block(Digest([0x01; 32])));
+        asserter.push_success(&Digest([0x42; 32]));
+        let recording = RecordingRpc::with_transport(MockTransport::new(asserter), &path);
+
+        let block_hash = recording.get_block_hash(7).await.unwrap();
+        let ger = recording
+            .get_latest_global_exit_root(Address::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(recording.interactions().len(), 2);
+        recording.save().unwrap();
+
+        let replay = ReplayRpc::new(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(replay.get_block_hash(7).await.unwrap(), block_hash);
+        assert_eq!(
+            replay
+                .get_latest_global_exit_root(Address::ZERO)
+                .await
+                .unwrap(),
+            ger
+        );
+        assert!(replay.remaining().is_empty());
+    }
+
+    #[tokio::test]
+    async fn identical_requests_are_replayed_in_order() {
+        let interactions = [0x01, 0x02]
+            .map(|byte| Interaction {
+                method: "eth_call".to_owned(),
+                params: serde_json::json!([{ "to": Address::ZERO }, "latest"]),
+                response: serde_json::json!({ "result": Digest([byte; 32]) }),
+            })
+            .to_vec();
+        let replay = ReplayRpc::from_interactions(PathBuf::from("ger.json"), interactions);
+
+        assert_eq!(
+            replay
+                .alloy_rpc()
+                .raw_request::<_, Digest>(
+                    "eth_call".into(),
+                    (serde_json::json!({ "to": Address::ZERO }), "latest"),
+                )
+                .await
+                .unwrap(),
+            Digest([0x01; 32])
+        );
+        assert_eq!(replay.remaining().len(), 1);
+        assert_eq!(
+            replay
+                .alloy_rpc()
+                .raw_request::<_, Digest>(
+                    "eth_call".into(),
+                    (serde_json::json!({ "to": Address::ZERO }), "latest"),
+                )
+                .await
+                .unwrap(),
+            Digest([0x02; 32])
+        );
+    }
+
+    #[tokio::test]
+    async fn topics_are_matched_in_any_order() {
+        let topics = [Digest([0x01; 32]), Digest([0x02; 32])];
+        let filter = |topics: [Digest; 2]| serde_json::json!({ "address": Address::ZERO, "topics": [topics] });
+        let interactions = vec![Interaction {
+            method: "eth_getLogs".to_owned(),
+            params: serde_json::json!([filter(topics)]),
+            response: serde_json::json!({ "result": [] }),
+        }];
+        let replay = ReplayRpc::from_interactions(PathBuf::from("logs.json"), interactions);
+
+        let [first, second] = topics;
+        replay
+            .alloy_rpc()
+            .raw_request::<_, Vec<Value>>("eth_getLogs".into(), [filter([second, first])])
+            .await
+            .unwrap();
+        assert!(replay.remaining().is_empty());
+    }
+
+    #[tokio::test]
+    async fn missing_requests_are_reported() {
+        let replay = ReplayRpc::from_interactions(PathBuf::from("empty.json"), Vec::new());
+
+        let error = replay.get_block_hash(7).await.unwrap_err();
+        let message = format!("{:?}", eyre::Report::from(error));
+        assert!(message.contains("No recorded response left for eth_getBlockByNumber"));
+        assert!(message.contains("empty.json"));
+    }
+
+    #[test]
+    fn missing_cassettes_are_reported() {
+        assert!(matches!(
+            ReplayRpc::new(cassette_path("missing")),
+            Err(CassetteError::Reading { .. })
+        ));
+    }
+}
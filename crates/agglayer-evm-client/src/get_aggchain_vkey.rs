@@ -0,0 +1,122 @@
+use agglayer_primitives::{vkey_hash::VKeyHash, Address};
+use async_trait::async_trait;
+
+use crate::{AggchainBase, AlloyRpc};
+
+#[async_trait]
+pub trait GetAggchainVKey {
+    type Error;
+
+    /// Returns the verifying key the aggchain proofs of the aggchain contract
+    /// are verified against for the given selector.
+    ///
+    /// The aggchain contract is the rollup contract of the rollups whose
+    /// verifier type is [`VerifierType::AggchainGateway`].
+    ///
+    /// [`VerifierType::AggchainGateway`]: crate::VerifierType::AggchainGateway
+    async fn get_aggchain_vkey(
+        &self,
+        aggchain_address: Address,
+        selector: [u8; 4],
+    ) -> Result<VKeyHash, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetAggchainVKeyError {
+    #[error(
+        "No aggchain verifying key registered for selector 0x{} on {aggchain_address}",
+        alloy::hex::encode(selector)
+    )]
+    VKeyNotFound {
+        aggchain_address: Address,
+        selector: [u8; 4],
+    },
+
+    #[error(
+        "Getting aggchain verifying key for selector 0x{} from {aggchain_address}",
+        alloy::hex::encode(selector)
+    )]
+    CallingContract {
+        aggchain_address: Address,
+        selector: [u8; 4],
+        source: eyre::Error,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetAggchainVKey for T {
+    type Error = GetAggchainVKeyError;
+
+    async fn get_aggchain_vkey(
+        &self,
+        aggchain_address: Address,
+        selector: [u8; 4],
+    ) -> Result<VKeyHash, Self::Error> {
+        let vkey = AggchainBase::new(aggchain_address.into_alloy(), self.alloy_rpc())
+            .getAggchainVKey(selector.into())
+            .call()
+            .await
+            .map_err(|source| GetAggchainVKeyError::CallingContract {
+                aggchain_address,
+                selector,
+                source: source.into(),
+            })?;
+        // The contracts revert on missing keys, an unset slot is handled the
+        // same way in case they do not.
+        if vkey.is_zero() {
+            return Err(GetAggchainVKeyError::VKeyNotFound {
+                aggchain_address,
+                selector,
+            });
+        }
+        Ok(VKeyHash::from_bytes(vkey))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::B256;
+
+    use super::*;
+    use crate::mocked_provider::MockedProvider;
+
+    const AGGCHAIN_ADDRESS: Address = Address::new([0xa6; 20]);
+    const SELECTOR: [u8; 4] = [0x00, 0x01, 0x00, 0x02];
+
+    #[tokio::test]
+    async fn vkey_is_read_from_the_contract() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_success(&B256::repeat_byte(0x77));
+
+        assert_eq!(
+            rpc.get_aggchain_vkey(AGGCHAIN_ADDRESS, SELECTOR)
+                .await
+                .unwrap(),
+            VKeyHash::from_bytes(B256::repeat_byte(0x77))
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_vkeys_are_reported() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_success(&B256::ZERO);
+        rpc.asserter.push_failure_msg("execution reverted");
+
+        assert!(matches!(
+            rpc.get_aggchain_vkey(AGGCHAIN_ADDRESS, SELECTOR).await,
+            Err(GetAggchainVKeyError::VKeyNotFound {
+                selector: SELECTOR,
+                ..
+            })
+        ));
+        let error = rpc
+            .get_aggchain_vkey(AGGCHAIN_ADDRESS, SELECTOR)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            GetAggchainVKeyError::CallingContract { .. }
+        ));
+        assert!(error.to_string().contains("selector 0x00010002"));
+    }
+}
@@ -0,0 +1,74 @@
+use alloy::providers::Provider as _;
+use async_trait::async_trait;
+
+use crate::{AlloyRpc, BlockHeader};
+
+#[async_trait]
+pub trait GetBlockHeader {
+    type Error;
+
+    /// Returns the header of the canonical block at the given number.
+    async fn get_block_header(&self, block_number: u64) -> Result<BlockHeader, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetBlockHeaderError {
+    #[error("Block number {block_number} not found")]
+    BlockNotFound { block_number: u64 },
+
+    #[error("Getting information for block number {block_number}")]
+    GettingBlock {
+        block_number: u64,
+        source: eyre::Error,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetBlockHeader for T {
+    type Error = GetBlockHeaderError;
+
+    async fn get_block_header(&self, block_number: u64) -> Result<BlockHeader, Self::Error> {
+        let header = self
+            .alloy_rpc()
+            .get_block_by_number(block_number.into())
+            .await
+            .map_err(|source| GetBlockHeaderError::GettingBlock {
+                block_number,
+                source: source.into(),
+            })?
+            .ok_or(GetBlockHeaderError::BlockNotFound { block_number })?
+            .header;
+        Ok(BlockHeader::new(header.hash.into(), header.inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::rpc::types::{Block, Header};
+
+    use super::*;
+    use crate::{block_header::tests::chain, mocked_provider::MockedProvider};
+
+    #[tokio::test]
+    async fn header_is_read_from_the_block() {
+        let rpc = MockedProvider::new();
+        let expected = chain(1).remove(0);
+        rpc.asserter
+            .push_success(&Block::<()>::empty(Header::new(expected.header().clone())));
+
+        let header = rpc.get_block_header(100).await.unwrap();
+        assert_eq!(header, expected);
+        assert_eq!(header.verify_hash(), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn missing_blocks_are_reported() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_success(&());
+
+        assert!(matches!(
+            rpc.get_block_header(100).await,
+            Err(GetBlockHeaderError::BlockNotFound { block_number: 100 })
+        ));
+    }
+}
@@ -0,0 +1,98 @@
+use agglayer_primitives::{Address, Digest};
+use alloy::{providers::Provider as _, rpc::types::Filter};
+use async_trait::async_trait;
+
+use crate::{AlloyRpc, BridgeContractEvent, DecodeBridgeEventError};
+
+/// Bridge contract event along with the block which emitted it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeEventLog {
+    pub block_number: u64,
+    pub block_hash: Digest,
+    pub event: BridgeContractEvent,
+}
+
+#[async_trait]
+pub trait GetBridgeEvents {
+    type Error;
+
+    /// Returns the bridge and claim events emitted by the bridge contract
+    /// between `from_block` and `to_block` (inclusive), in emission order.
+    async fn get_bridge_events(
+        &self,
+        bridge_address: Address,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<BridgeEventLog>, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetBridgeEventsError {
+    #[error("Getting logs for blocks {from_block} to {to_block}")]
+    GettingLogs {
+        from_block: u64,
+        to_block: u64,
+        source: eyre::Error,
+    },
+
+    #[error("Log {log_index:?} of transaction {transaction_hash:?} is not part of a block")]
+    PendingLog {
+        transaction_hash: Option<Digest>,
+        log_index: Option<u64>,
+    },
+
+    #[error("Decoding log of block {block_number}")]
+    DecodingLog {
+        block_number: u64,
+        source: DecodeBridgeEventError,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetBridgeEvents for T {
+    type Error = GetBridgeEventsError;
+
+    async fn get_bridge_events(
+        &self,
+        bridge_address: Address,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<BridgeEventLog>, Self::Error> {
+        let filter = Filter::new()
+            .address(bridge_address.into_alloy())
+            .event_signature(BridgeContractEvent::SIGNATURE_HASHES.to_vec())
+            .from_block(from_block)
+            .to_block(to_block);
+        let logs = self.alloy_rpc().get_logs(&filter).await.map_err(|source| {
+            GetBridgeEventsError::GettingLogs {
+                from_block,
+                to_block,
+                source: source.into(),
+            }
+        })?;
+
+        let mut events = Vec::with_capacity(logs.len());
+        for log in logs.into_iter().filter(|log| !log.removed) {
+            let (Some(block_number), Some(block_hash)) = (log.block_number, log.block_hash) else {
+                return Err(GetBridgeEventsError::PendingLog {
+                    transaction_hash: log.transaction_hash.map(Digest::from),
+                    log_index: log.log_index,
+                });
+            };
+            let event = BridgeContractEvent::decode(&log.inner).map_err(|source| {
+                GetBridgeEventsError::DecodingLog {
+                    block_number,
+                    source,
+                }
+            })?;
+            if let Some(event) = event {
+                events.push(BridgeEventLog {
+                    block_number,
+                    block_hash: block_hash.into(),
+                    event,
+                });
+            }
+        }
+        Ok(events)
+    }
+}
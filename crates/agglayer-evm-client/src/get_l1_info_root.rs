@@ -0,0 +1,64 @@
+use agglayer_primitives::{Address, Digest};
+use agglayer_tries::roots::L1InfoRoot;
+use async_trait::async_trait;
+
+use crate::{AlloyRpc, PolygonZkEVMGlobalExitRootV2};
+
+#[async_trait]
+pub trait GetL1InfoRoot {
+    type Error;
+
+    /// Returns the current root of the L1 info tree.
+    async fn get_l1_info_root(&self, ger_address: Address) -> Result<L1InfoRoot, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetL1InfoRootError {
+    #[error("Getting L1 info root from {ger_address}")]
+    CallingContract {
+        ger_address: Address,
+        source: eyre::Error,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetL1InfoRoot for T {
+    type Error = GetL1InfoRootError;
+
+    async fn get_l1_info_root(&self, ger_address: Address) -> Result<L1InfoRoot, Self::Error> {
+        let root = PolygonZkEVMGlobalExitRootV2::new(ger_address.into_alloy(), self.alloy_rpc())
+            .getRoot()
+            .call()
+            .await
+            .map_err(|source| GetL1InfoRootError::CallingContract {
+                ger_address,
+                source: source.into(),
+            })?;
+        Ok(L1InfoRoot::new(Digest::from(root)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::B256;
+
+    use super::*;
+    use crate::mocked_provider::MockedProvider;
+
+    #[tokio::test]
+    async fn root_is_read_from_the_contract() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_success(&B256::repeat_byte(0x42));
+        rpc.asserter.push_failure_msg("execution reverted");
+
+        let ger_address = Address::new([0x9e; 20]);
+        assert_eq!(
+            rpc.get_l1_info_root(ger_address).await.unwrap(),
+            L1InfoRoot::new(Digest([0x42; 32]))
+        );
+        assert!(matches!(
+            rpc.get_l1_info_root(ger_address).await,
+            Err(GetL1InfoRootError::CallingContract { .. })
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+use agglayer_primitives::{keccak::keccak256_combine, Address, Digest, B256, U256};
+use alloy::{
+    eips::BlockNumberOrTag,
+    providers::Provider as _,
+    rpc::types::{Filter, Log},
+    sol_types::SolEvent,
+};
+use async_trait::async_trait;
+use unified_bridge::{L1InfoTreeLeaf, L1InfoTreeLeafInner};
+
+use crate::{
+    global_exit_root_manager::PolygonZkEVMGlobalExitRootV2::{
+        UpdateL1InfoTree, UpdateL1InfoTreeV2,
+    },
+    AlloyRpc,
+};
+
+#[async_trait]
+pub trait GetL1InfoTreeLeaf {
+    type Error;
+
+    /// Returns the leaf of the L1 info tree at the given index, inserted by a
+    /// finalized block from `from_block` on.
+    ///
+    /// `from_block` bounds the range of blocks to search, it is usually the
+    /// block which inserted a previous leaf or the deployment block of the
+    /// global exit root manager.
+    async fn get_l1_info_tree_leaf(
+        &self,
+        ger_address: Address,
+        l1_info_tree_index: u32,
+        from_block: u64,
+    ) -> Result<L1InfoTreeLeaf, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetL1InfoTreeLeafError {
+    #[error("L1 info tree leaf {l1_info_tree_index} not found")]
+    LeafNotFound { l1_info_tree_index: u32 },
+
+    #[error("Exit roots of L1 info tree leaf {l1_info_tree_index} not found")]
+    ExitRootsNotFound { l1_info_tree_index: u32 },
+
+    #[error("Update of L1 info tree leaf {l1_info_tree_index} is not part of a block")]
+    PendingLog { l1_info_tree_index: u32 },
+
+    #[error("Getting logs for L1 info tree leaf {l1_info_tree_index}")]
+    GettingLogs {
+        l1_info_tree_index: u32,
+        source: eyre::Error,
+    },
+
+    #[error("Decoding logs for L1 info tree leaf {l1_info_tree_index}")]
+    DecodingLog {
+        l1_info_tree_index: u32,
+        source: alloy::sol_types::Error,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetL1InfoTreeLeaf for T {
+    type Error = GetL1InfoTreeLeafError;
+
+    /// Looks up the `UpdateL1InfoTreeV2` event which inserted the leaf, and
+    /// the `UpdateL1InfoTree` event emitted along with it for the exit roots.
+    ///
+    /// Leaves inserted before the contract emitted `UpdateL1InfoTreeV2`
+    /// cannot be found this way.
+    async fn get_l1_info_tree_leaf(
+        &self,
+        ger_address: Address,
+        l1_info_tree_index: u32,
+        from_block: u64,
+    ) -> Result<L1InfoTreeLeaf, Self::Error> {
+        let get_logs = |filter: Filter| async move {
+            self.alloy_rpc().get_logs(&filter).await.map_err(|source| {
+                GetL1InfoTreeLeafError::GettingLogs {
+                    l1_info_tree_index,
+                    source: source.into(),
+                }
+            })
+        };
+        let decoding_log = |source| GetL1InfoTreeLeafError::DecodingLog {
+            l1_info_tree_index,
+            source,
+        };
+
+        let leaf_count = U256::from(u64::from(l1_info_tree_index) + 1);
+        let update = get_logs(
+            Filter::new()
+                .address(ger_address.into_alloy())
+                .event_signature(UpdateL1InfoTreeV2::SIGNATURE_HASH)
+                .topic1(B256::from(leaf_count))
+                .from_block(from_block)
+                .to_block(BlockNumberOrTag::Finalized),
+        )
+        .await?
+        .into_iter()
+        .find(|log| !log.removed)
+        .ok_or(GetL1InfoTreeLeafError::LeafNotFound { l1_info_tree_index })?;
+        let Some(block_hash) = update.block_hash else {
+            return Err(GetL1InfoTreeLeafError::PendingLog { l1_info_tree_index });
+        };
+
+        // The exit roots are only part of the `UpdateL1InfoTree` event emitted
+        // right before by the same transaction.
+        let exit_roots = get_logs(
+            Filter::new()
+                .address(ger_address.into_alloy())
+                .event_signature(UpdateL1InfoTree::SIGNATURE_HASH)
+                .at_block_hash(block_hash),
+        )
+        .await?
+        .into_iter()
+        .filter(|log: &Log| {
+            log.transaction_hash == update.transaction_hash && log.log_index < update.log_index
+        })
+        .max_by_key(|log| log.log_index)
+        .ok_or(GetL1InfoTreeLeafError::ExitRootsNotFound { l1_info_tree_index })?;
+
+        let update = UpdateL1InfoTreeV2::decode_log(&update.inner).map_err(decoding_log)?;
+        let exit_roots = UpdateL1InfoTree::decode_log(&exit_roots.inner).map_err(decoding_log)?;
+
+        let mer = Digest::from(exit_roots.mainnetExitRoot);
+        let rer = Digest::from(exit_roots.rollupExitRoot);
+        Ok(L1InfoTreeLeaf {
+            l1_info_tree_index,
+            rer,
+            mer,
+            inner: L1InfoTreeLeafInner {
+                global_exit_root: keccak256_combine([mer, rer]),
+                block_hash: Digest::from(B256::from(update.blockhash)),
+                timestamp: update.minTimestamp,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::LogData;
+
+    use super::*;
+    use crate::mocked_provider::MockedProvider;
+
+    const GER_ADDRESS: Address = Address::new([0x9e; 20]);
+
+    fn log(data: LogData, transaction_hash: B256, log_index: u64) -> Log {
+        Log {
+            inner: alloy::primitives::Log {
+                address: GER_ADDRESS.into_alloy(),
+                data,
+            },
+            block_hash: Some(B256::repeat_byte(0xbb)),
+            block_number: Some(21_000_000),
+            block_timestamp: None,
+            transaction_hash: Some(transaction_hash),
+            transaction_index: Some(0),
+            log_index: Some(log_index),
+            removed: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn leaf_is_built_from_update_events() {
+        let rpc = MockedProvider::new();
+        let transaction_hash = B256::repeat_byte(0xaa);
+        let mer = B256::repeat_byte(0x11);
+        let rer = B256::repeat_byte(0x22);
+
+        let update = UpdateL1InfoTreeV2 {
+            currentL1InfoRoot: B256::repeat_byte(0x33),
+            leafCount: 8,
+            blockhash: U256::from_be_bytes([0xcc; 32]),
+            minTimestamp: 1_700_000_000,
+        };
+        rpc.asserter
+            .push_success(&[log(update.encode_log_data(), transaction_hash, 5)]);
+
+        let exit_roots = |mer, rer| {
+            UpdateL1InfoTree {
+                mainnetExitRoot: mer,
+                rollupExitRoot: rer,
+            }
+            .encode_log_data()
+        };
+        rpc.asserter.push_success(&[
+            // Update of another transaction of the same block.
+            log(exit_roots(rer, mer), B256::repeat_byte(0xa0), 1),
+            log(exit_roots(mer, rer), transaction_hash, 4),
+            // Update emitted after the leaf insertion.
+            log(exit_roots(rer, rer), transaction_hash, 7),
+        ]);
+
+        let leaf = rpc
+            .get_l1_info_tree_leaf(GER_ADDRESS, 7, 20_000_000)
+            .await
+            .unwrap();
+        assert_eq!(
+            leaf,
+            L1InfoTreeLeaf {
+                l1_info_tree_index: 7,
+                rer: rer.into(),
+                mer: mer.into(),
+                inner: L1InfoTreeLeafInner {
+                    global_exit_root: keccak256_combine([mer, rer]),
+                    block_hash: Digest([0xcc; 32]),
+                    timestamp: 1_700_000_000,
+                },
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_leaves_are_reported() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_success(&Vec::<Log>::new());
+
+        assert!(matches!(
+            rpc.get_l1_info_tree_leaf(GER_ADDRESS, 7, 20_000_000).await,
+            Err(GetL1InfoTreeLeafError::LeafNotFound {
+                l1_info_tree_index: 7
+            })
+        ));
+    }
+}
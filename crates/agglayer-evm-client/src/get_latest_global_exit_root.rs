@@ -0,0 +1,65 @@
+use agglayer_primitives::{Address, Digest};
+use async_trait::async_trait;
+
+use crate::{AlloyRpc, PolygonZkEVMGlobalExitRootV2};
+
+#[async_trait]
+pub trait GetLatestGlobalExitRoot {
+    type Error;
+
+    /// Returns the global exit root last inserted in the L1 info tree.
+    async fn get_latest_global_exit_root(
+        &self,
+        ger_address: Address,
+    ) -> Result<Digest, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetLatestGlobalExitRootError {
+    #[error("Getting latest global exit root from {ger_address}")]
+    CallingContract {
+        ger_address: Address,
+        source: eyre::Error,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetLatestGlobalExitRoot for T {
+    type Error = GetLatestGlobalExitRootError;
+
+    async fn get_latest_global_exit_root(
+        &self,
+        ger_address: Address,
+    ) -> Result<Digest, Self::Error> {
+        let ger = PolygonZkEVMGlobalExitRootV2::new(ger_address.into_alloy(), self.alloy_rpc())
+            .getLastGlobalExitRoot()
+            .call()
+            .await
+            .map_err(|source| GetLatestGlobalExitRootError::CallingContract {
+                ger_address,
+                source: source.into(),
+            })?;
+        Ok(Digest::from(ger))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::B256;
+
+    use super::*;
+    use crate::mocked_provider::MockedProvider;
+
+    #[tokio::test]
+    async fn global_exit_root_is_read_from_the_contract() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_success(&B256::repeat_byte(0x42));
+
+        assert_eq!(
+            rpc.get_latest_global_exit_root(Address::new([0x9e; 20]))
+                .await
+                .unwrap(),
+            Digest([0x42; 32])
+        );
+    }
+}
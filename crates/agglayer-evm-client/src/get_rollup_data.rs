@@ -0,0 +1,180 @@
+use agglayer_primitives::{vkey_hash::VKeyHash, Address, Digest};
+use agglayer_tries::roots::{LocalExitRoot, PessimisticRoot};
+use async_trait::async_trait;
+use unified_bridge::RollupId;
+
+use crate::{AlloyRpc, InvalidVerifierTypeError, PolygonRollupManager, VerifierType};
+
+/// State of a rollup as settled on the rollup manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollupData {
+    /// Address of the rollup contract.
+    pub rollup_contract: Address,
+    pub chain_id: u64,
+    /// Rollup type, as given to [`GetRollupType::get_rollup_type`].
+    ///
+    /// [`GetRollupType::get_rollup_type`]: crate::GetRollupType::get_rollup_type
+    pub rollup_type_id: u32,
+    pub verifier_type: VerifierType,
+    /// Last settled local exit root.
+    pub last_local_exit_root: LocalExitRoot,
+    /// Last settled pessimistic root.
+    pub last_pessimistic_root: PessimisticRoot,
+    /// Verifying key of the pessimistic program the rollup is verified
+    /// against.
+    ///
+    /// Aggchain proofs are verified against a key of the aggchain contract
+    /// instead, see [`GetAggchainVKey`].
+    ///
+    /// [`GetAggchainVKey`]: crate::GetAggchainVKey
+    pub pessimistic_program_vkey: VKeyHash,
+}
+
+#[async_trait]
+pub trait GetRollupData {
+    type Error;
+
+    async fn get_rollup_data(
+        &self,
+        rollup_manager_address: Address,
+        rollup_id: RollupId,
+    ) -> Result<RollupData, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetRollupDataError {
+    #[error("Rollup {rollup_id} not found")]
+    RollupNotFound { rollup_id: RollupId },
+
+    #[error("Getting data of rollup {rollup_id}")]
+    CallingContract {
+        rollup_id: RollupId,
+        source: eyre::Error,
+    },
+
+    #[error("Rollup {rollup_id} has an invalid rollup type {rollup_type_id}")]
+    InvalidRollupType {
+        rollup_id: RollupId,
+        rollup_type_id: u64,
+    },
+
+    #[error("Rollup {rollup_id} has an invalid verifier type")]
+    InvalidVerifierType {
+        rollup_id: RollupId,
+        source: InvalidVerifierTypeError,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetRollupData for T {
+    type Error = GetRollupDataError;
+
+    async fn get_rollup_data(
+        &self,
+        rollup_manager_address: Address,
+        rollup_id: RollupId,
+    ) -> Result<RollupData, Self::Error> {
+        let data = PolygonRollupManager::new(rollup_manager_address.into_alloy(), self.alloy_rpc())
+            .rollupIDToRollupDataV2(rollup_id.to_u32())
+            .call()
+            .await
+            .map_err(|source| GetRollupDataError::CallingContract {
+                rollup_id,
+                source: source.into(),
+            })?;
+        if data.rollupContract.is_zero() {
+            return Err(GetRollupDataError::RollupNotFound { rollup_id });
+        }
+
+        Ok(RollupData {
+            rollup_contract: data.rollupContract.into(),
+            chain_id: data.chainID,
+            // Rollup types are indexed by a `uint32` on the contract.
+            rollup_type_id: u32::try_from(data.rollupTypeID).map_err(|_| {
+                GetRollupDataError::InvalidRollupType {
+                    rollup_id,
+                    rollup_type_id: data.rollupTypeID,
+                }
+            })?,
+            verifier_type: data
+                .rollupVerifierType
+                .try_into()
+                .map_err(|source| GetRollupDataError::InvalidVerifierType { rollup_id, source })?,
+            last_local_exit_root: LocalExitRoot::new(Digest::from(data.lastLocalExitRoot)),
+            last_pessimistic_root: PessimisticRoot::new(Digest::from(data.lastPessimisticRoot)),
+            pessimistic_program_vkey: VKeyHash::from_bytes(data.programVKey),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::B256;
+    use alloy::{primitives::Bytes, sol_types::SolCall as _};
+
+    use super::*;
+    use crate::{
+        mocked_provider::MockedProvider, PolygonRollupManager::rollupIDToRollupDataV2Call,
+    };
+
+    const ROLLUP_MANAGER_ADDRESS: Address = Address::new([0x5e; 20]);
+
+    fn rollup_data(rollup_contract: Address, verifier_type: u8) -> Bytes {
+        rollupIDToRollupDataV2Call::abi_encode_returns(&PolygonRollupManager::RollupDataReturnV2 {
+            rollupContract: rollup_contract.into_alloy(),
+            chainID: 747,
+            forkID: 12,
+            lastLocalExitRoot: B256::repeat_byte(0x1e),
+            rollupTypeID: 5,
+            rollupVerifierType: verifier_type,
+            lastPessimisticRoot: B256::repeat_byte(0x9e),
+            programVKey: B256::repeat_byte(0x77),
+            ..Default::default()
+        })
+        .into()
+    }
+
+    #[tokio::test]
+    async fn rollup_data_is_read_from_the_contract() {
+        let rpc = MockedProvider::new();
+        rpc.asserter
+            .push_success(&rollup_data(Address::new([0xc0; 20]), 1));
+
+        let rollup_id = RollupId::new(3).unwrap();
+        assert_eq!(
+            rpc.get_rollup_data(ROLLUP_MANAGER_ADDRESS, rollup_id)
+                .await
+                .unwrap(),
+            RollupData {
+                rollup_contract: Address::new([0xc0; 20]),
+                chain_id: 747,
+                rollup_type_id: 5,
+                verifier_type: VerifierType::Pessimistic,
+                last_local_exit_root: LocalExitRoot::new(Digest([0x1e; 32])),
+                last_pessimistic_root: PessimisticRoot::new(Digest([0x9e; 32])),
+                pessimistic_program_vkey: VKeyHash::from_bytes(B256::repeat_byte(0x77)),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_rollups_are_reported() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_success(&rollup_data(Address::ZERO, 0));
+        rpc.asserter
+            .push_success(&rollup_data(Address::new([0xc0; 20]), 3));
+
+        let rollup_id = RollupId::new(3).unwrap();
+        assert!(matches!(
+            rpc.get_rollup_data(ROLLUP_MANAGER_ADDRESS, rollup_id).await,
+            Err(GetRollupDataError::RollupNotFound { .. })
+        ));
+        assert!(matches!(
+            rpc.get_rollup_data(ROLLUP_MANAGER_ADDRESS, rollup_id).await,
+            Err(GetRollupDataError::InvalidVerifierType {
+                source: InvalidVerifierTypeError(3),
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+use agglayer_primitives::{vkey_hash::VKeyHash, Address, Digest};
+use async_trait::async_trait;
+
+use crate::{AlloyRpc, InvalidVerifierTypeError, PolygonRollupManager, VerifierType};
+
+/// Rollup type registered on the rollup manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollupType {
+    /// Implementation of the rollup contracts of this type.
+    pub consensus_implementation: Address,
+    pub verifier: Address,
+    pub fork_id: u64,
+    pub verifier_type: VerifierType,
+    /// Whether new rollups can no longer be created with this type.
+    pub obsolete: bool,
+    pub genesis: Digest,
+    /// Verifying key of the pessimistic program the rollups of this type are
+    /// verified against.
+    pub pessimistic_program_vkey: VKeyHash,
+}
+
+#[async_trait]
+pub trait GetRollupType {
+    type Error;
+
+    async fn get_rollup_type(
+        &self,
+        rollup_manager_address: Address,
+        rollup_type_id: u32,
+    ) -> Result<RollupType, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetRollupTypeError {
+    #[error("Rollup type {rollup_type_id} not found")]
+    RollupTypeNotFound { rollup_type_id: u32 },
+
+    #[error("Getting rollup type {rollup_type_id}")]
+    CallingContract {
+        rollup_type_id: u32,
+        source: eyre::Error,
+    },
+
+    #[error("Rollup type {rollup_type_id} has an invalid verifier type")]
+    InvalidVerifierType {
+        rollup_type_id: u32,
+        source: InvalidVerifierTypeError,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetRollupType for T {
+    type Error = GetRollupTypeError;
+
+    async fn get_rollup_type(
+        &self,
+        rollup_manager_address: Address,
+        rollup_type_id: u32,
+    ) -> Result<RollupType, Self::Error> {
+        let rollup_type =
+            PolygonRollupManager::new(rollup_manager_address.into_alloy(), self.alloy_rpc())
+                .rollupTypeMap(rollup_type_id)
+                .call()
+                .await
+                .map_err(|source| GetRollupTypeError::CallingContract {
+                    rollup_type_id,
+                    source: source.into(),
+                })?;
+        if rollup_type.consensusImplementation.is_zero() {
+            return Err(GetRollupTypeError::RollupTypeNotFound { rollup_type_id });
+        }
+
+        Ok(RollupType {
+            consensus_implementation: rollup_type.consensusImplementation.into(),
+            verifier: rollup_type.verifier.into(),
+            fork_id: rollup_type.forkID,
+            verifier_type: rollup_type
+                .rollupVerifierType
+                .try_into()
+                .map_err(|source| GetRollupTypeError::InvalidVerifierType {
+                    rollup_type_id,
+                    source,
+                })?,
+            obsolete: rollup_type.obsolete,
+            genesis: Digest::from(rollup_type.genesis),
+            pessimistic_program_vkey: VKeyHash::from_bytes(rollup_type.programVKey),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::B256;
+    use alloy::{primitives::Bytes, sol_types::SolCall as _};
+
+    use super::*;
+    use crate::{
+        mocked_provider::MockedProvider,
+        PolygonRollupManager::{rollupTypeMapCall, rollupTypeMapReturn},
+    };
+
+    #[tokio::test]
+    async fn rollup_type_is_read_from_the_contract() {
+        let rpc = MockedProvider::new();
+        let rollup_type: Bytes = rollupTypeMapCall::abi_encode_returns(&rollupTypeMapReturn {
+            consensusImplementation: Address::new([0xc1; 20]).into_alloy(),
+            verifier: Address::new([0xc2; 20]).into_alloy(),
+            forkID: 0,
+            rollupVerifierType: 2,
+            obsolete: false,
+            genesis: B256::ZERO,
+            programVKey: B256::repeat_byte(0x77),
+        })
+        .into();
+        rpc.asserter.push_success(&rollup_type);
+
+        assert_eq!(
+            rpc.get_rollup_type(Address::new([0x5e; 20]), 5)
+                .await
+                .unwrap(),
+            RollupType {
+                consensus_implementation: Address::new([0xc1; 20]),
+                verifier: Address::new([0xc2; 20]),
+                fork_id: 0,
+                verifier_type: VerifierType::AggchainGateway,
+                obsolete: false,
+                genesis: Digest::ZERO,
+                pessimistic_program_vkey: VKeyHash::from_bytes(B256::repeat_byte(0x77)),
+            }
+        );
+    }
+}
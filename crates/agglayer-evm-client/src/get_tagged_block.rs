@@ -0,0 +1,125 @@
+use std::fmt;
+
+use agglayer_primitives::Digest;
+use alloy::{eips::BlockNumberOrTag, providers::Provider as _};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::AlloyRpc;
+
+/// Block tags resolved by the node, from the least to the most final.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockTag {
+    Latest,
+    Safe,
+    Finalized,
+}
+
+impl fmt::Display for BlockTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BlockTag::Latest => "latest",
+            BlockTag::Safe => "safe",
+            BlockTag::Finalized => "finalized",
+        })
+    }
+}
+
+impl From<BlockTag> for BlockNumberOrTag {
+    fn from(tag: BlockTag) -> Self {
+        match tag {
+            BlockTag::Latest => BlockNumberOrTag::Latest,
+            BlockTag::Safe => BlockNumberOrTag::Safe,
+            BlockTag::Finalized => BlockNumberOrTag::Finalized,
+        }
+    }
+}
+
+/// Number and hash identifying a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BlockRef {
+    pub number: u64,
+    pub hash: Digest,
+}
+
+#[async_trait]
+pub trait GetTaggedBlock {
+    type Error;
+
+    /// Returns the block the given tag currently resolves to.
+    async fn get_tagged_block(&self, tag: BlockTag) -> Result<BlockRef, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetTaggedBlockError {
+    #[error("No {tag} block")]
+    BlockNotFound { tag: BlockTag },
+
+    #[error("Getting {tag} block")]
+    GettingBlock { tag: BlockTag, source: eyre::Error },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetTaggedBlock for T {
+    type Error = GetTaggedBlockError;
+
+    async fn get_tagged_block(&self, tag: BlockTag) -> Result<BlockRef, Self::Error> {
+        let header = self
+            .alloy_rpc()
+            .get_block_by_number(tag.into())
+            .await
+            .map_err(|source| GetTaggedBlockError::GettingBlock {
+                tag,
+                source: source.into(),
+            })?
+            .ok_or(GetTaggedBlockError::BlockNotFound { tag })?
+            .header;
+        Ok(BlockRef {
+            number: header.number,
+            hash: header.hash.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        consensus,
+        rpc::types::{Block, Header},
+    };
+
+    use super::*;
+    use crate::mocked_provider::MockedProvider;
+
+    #[tokio::test]
+    async fn tags_are_resolved_to_blocks() {
+        let rpc = MockedProvider::new();
+        let block = Block::<()>::empty(Header::new(consensus::Header {
+            number: 21_000_000,
+            ..Default::default()
+        }));
+        rpc.asserter.push_success(&block);
+
+        assert_eq!(
+            rpc.get_tagged_block(BlockTag::Finalized).await.unwrap(),
+            BlockRef {
+                number: 21_000_000,
+                hash: block.header.hash.into(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn unsupported_tags_are_reported() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_success(&());
+
+        assert!(matches!(
+            rpc.get_tagged_block(BlockTag::Safe).await,
+            Err(GetTaggedBlockError::BlockNotFound {
+                tag: BlockTag::Safe
+            })
+        ));
+    }
+}
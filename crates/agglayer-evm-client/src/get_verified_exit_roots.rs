@@ -0,0 +1,190 @@
+use agglayer_primitives::{keccak::keccak256_combine, Address, Digest, U256};
+use agglayer_tries::roots::LocalExitRoot;
+use async_trait::async_trait;
+use unified_bridge::LocalExitTree;
+
+use crate::{get_verified_storage::GetVerifiedStorageError, AlloyRpc, GetVerifiedStorage};
+
+/// Slot of `lastRollupExitRoot` in the `PolygonZkEVMGlobalExitRootV2`
+/// storage, followed by `lastMainnetExitRoot`.
+const LAST_ROLLUP_EXIT_ROOT_SLOT: u64 = 0;
+
+/// Slot of the `_branch` frontier in the `PolygonZkEVMBridgeV2` storage,
+/// right after the 51 slots of `ReentrancyGuardUpgradeable`.
+const BRANCH_SLOT: u64 = 51;
+
+/// Slot of `depositCount` in the `PolygonZkEVMBridgeV2` storage, right after
+/// the 32 slots of the frontier.
+const DEPOSIT_COUNT_SLOT: u64 = BRANCH_SLOT + 32;
+
+/// Exit roots last stored by the global exit root manager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifiedExitRoots {
+    pub mainnet_exit_root: Digest,
+    pub rollup_exit_root: Digest,
+}
+
+impl VerifiedExitRoots {
+    pub fn global_exit_root(&self) -> Digest {
+        keccak256_combine([self.mainnet_exit_root, self.rollup_exit_root])
+    }
+}
+
+#[async_trait]
+pub trait GetVerifiedExitRoots {
+    type Error;
+
+    /// Returns the local exit root of the bridge contract at the block with
+    /// the given trusted hash, rebuilt from its proven frontier and deposit
+    /// count.
+    async fn get_verified_local_exit_root(
+        &self,
+        bridge_address: Address,
+        block_hash: Digest,
+    ) -> Result<LocalExitRoot, Self::Error>;
+
+    /// Returns the exit roots last stored by the global exit root manager at
+    /// the block with the given trusted hash, proven against the state root
+    /// of that block.
+    async fn get_verified_exit_roots(
+        &self,
+        ger_address: Address,
+        block_hash: Digest,
+    ) -> Result<VerifiedExitRoots, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetVerifiedExitRootsError {
+    #[error("Getting verified storage of {address}")]
+    GettingStorage {
+        address: Address,
+        source: GetVerifiedStorageError,
+    },
+
+    #[error("Invalid deposit count {deposit_count} of bridge {bridge_address}")]
+    InvalidDepositCount {
+        bridge_address: Address,
+        deposit_count: U256,
+    },
+}
+
+fn slot(index: u64) -> Digest {
+    Digest::from(U256::from(index).to_be_bytes())
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetVerifiedExitRoots for T {
+    type Error = GetVerifiedExitRootsError;
+
+    async fn get_verified_local_exit_root(
+        &self,
+        bridge_address: Address,
+        block_hash: Digest,
+    ) -> Result<LocalExitRoot, Self::Error> {
+        let slots = (BRANCH_SLOT..=DEPOSIT_COUNT_SLOT)
+            .map(slot)
+            .collect::<Vec<_>>();
+        let values = self
+            .get_verified_storage(bridge_address, &slots, block_hash)
+            .await
+            .map_err(|source| GetVerifiedExitRootsError::GettingStorage {
+                address: bridge_address,
+                source,
+            })?;
+
+        let deposit_count = U256::from_be_bytes(values[32].0);
+        let leaf_count = u32::try_from(deposit_count).map_err(|_| {
+            GetVerifiedExitRootsError::InvalidDepositCount {
+                bridge_address,
+                deposit_count,
+            }
+        })?;
+        let frontier = <[Digest; 32]>::try_from(&values[..32]).unwrap();
+        Ok(LocalExitTree::<32>::from_parts(leaf_count, frontier)
+            .get_root()
+            .into())
+    }
+
+    async fn get_verified_exit_roots(
+        &self,
+        ger_address: Address,
+        block_hash: Digest,
+    ) -> Result<VerifiedExitRoots, Self::Error> {
+        let slots = [
+            slot(LAST_ROLLUP_EXIT_ROOT_SLOT),
+            slot(LAST_ROLLUP_EXIT_ROOT_SLOT + 1),
+        ];
+        let values = self
+            .get_verified_storage(ger_address, &slots, block_hash)
+            .await
+            .map_err(|source| GetVerifiedExitRootsError::GettingStorage {
+                address: ger_address,
+                source,
+            })?;
+
+        Ok(VerifiedExitRoots {
+            mainnet_exit_root: values[1],
+            rollup_exit_root: values[0],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        get_verified_storage::tests::block, mocked_provider::MockedProvider,
+        storage_proof::tests::synthetic_proofs,
+    };
+
+    #[tokio::test]
+    async fn local_exit_root_is_rebuilt_from_the_frontier() {
+        let rpc = MockedProvider::new();
+        let proofs = synthetic_proofs();
+        let block = block(proofs.state_root);
+        rpc.asserter.push_success(&block);
+        rpc.asserter.push_success(&proofs.bridge);
+
+        let mut tree = LocalExitTree::<32>::new();
+        for i in 1..=5 {
+            tree.add_leaf(Digest([i; 32])).unwrap();
+        }
+        assert_eq!(
+            rpc.get_verified_local_exit_root(
+                proofs.bridge.address.into(),
+                block.header.hash.into()
+            )
+            .await
+            .unwrap(),
+            LocalExitRoot::from(tree.get_root())
+        );
+    }
+
+    #[tokio::test]
+    async fn exit_roots_are_read_from_the_ger_storage() {
+        let rpc = MockedProvider::new();
+        let proofs = synthetic_proofs();
+        let block = block(proofs.state_root);
+        rpc.asserter.push_success(&block);
+        rpc.asserter.push_success(&proofs.global_exit_root_manager);
+
+        let exit_roots = rpc
+            .get_verified_exit_roots(
+                proofs.global_exit_root_manager.address.into(),
+                block.header.hash.into(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            exit_roots,
+            VerifiedExitRoots {
+                mainnet_exit_root: Digest([0x22; 32]),
+                rollup_exit_root: Digest([0x11; 32]),
+            }
+        );
+        assert_eq!(
+            exit_roots.global_exit_root(),
+            keccak256_combine([Digest([0x22; 32]), Digest([0x11; 32])])
+        );
+    }
+}
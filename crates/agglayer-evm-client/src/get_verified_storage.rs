@@ -0,0 +1,175 @@
+use agglayer_primitives::{Address, Digest, B256};
+use alloy::providers::Provider as _;
+use async_trait::async_trait;
+
+use crate::{
+    storage_proof::verify_storage_proof, AlloyRpc, BlockHeader, BlockHeaderError, StorageProofError,
+};
+
+#[async_trait]
+pub trait GetVerifiedStorage {
+    type Error;
+
+    /// Returns the values of the given storage slots of a contract at the
+    /// block with the given trusted hash, proven against the state root of
+    /// that block.
+    async fn get_verified_storage(
+        &self,
+        address: Address,
+        slots: &[Digest],
+        block_hash: Digest,
+    ) -> Result<Vec<Digest>, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetVerifiedStorageError {
+    #[error("Block {block_hash} not found")]
+    BlockNotFound { block_hash: Digest },
+
+    #[error("Getting information for block {block_hash}")]
+    GettingBlock {
+        block_hash: Digest,
+        source: eyre::Error,
+    },
+
+    #[error("Invalid header for block {block_hash}")]
+    InvalidHeader {
+        block_hash: Digest,
+        source: BlockHeaderError,
+    },
+
+    #[error("Getting storage proof of {address} at block {block_hash}")]
+    GettingProof {
+        address: Address,
+        block_hash: Digest,
+        source: eyre::Error,
+    },
+
+    #[error("Verifying storage proof of {address} at block {block_hash}")]
+    InvalidProof {
+        address: Address,
+        block_hash: Digest,
+        source: StorageProofError,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> GetVerifiedStorage for T {
+    type Error = GetVerifiedStorageError;
+
+    /// Only the block hash is trusted: the header reported by the RPC is
+    /// checked to hash to it, and the storage values are checked against the
+    /// state root of that header. The proof is requested at the same block
+    /// hash, so a reorg between the two requests cannot mix two blocks.
+    async fn get_verified_storage(
+        &self,
+        address: Address,
+        slots: &[Digest],
+        block_hash: Digest,
+    ) -> Result<Vec<Digest>, Self::Error> {
+        let header = self
+            .alloy_rpc()
+            .get_block_by_hash(block_hash.into())
+            .await
+            .map_err(|source| GetVerifiedStorageError::GettingBlock {
+                block_hash,
+                source: source.into(),
+            })?
+            .ok_or(GetVerifiedStorageError::BlockNotFound { block_hash })?
+            .header;
+        let header = BlockHeader::new(block_hash, header.inner);
+        header
+            .verify_hash()
+            .map_err(|source| GetVerifiedStorageError::InvalidHeader { block_hash, source })?;
+
+        let keys = slots.iter().copied().map(B256::from).collect();
+        let proof = self
+            .alloy_rpc()
+            .get_proof(address.into_alloy(), keys)
+            .hash(block_hash.into())
+            .await
+            .map_err(|source| GetVerifiedStorageError::GettingProof {
+                address,
+                block_hash,
+                source: source.into(),
+            })?;
+
+        verify_storage_proof(header.state_root(), address, slots, &proof).map_err(|source| {
+            GetVerifiedStorageError::InvalidProof {
+                address,
+                block_hash,
+                source,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloy::{
+        consensus,
+        rpc::types::{Block, Header},
+    };
+
+    use super::*;
+    use crate::{mocked_provider::MockedProvider, storage_proof::tests::synthetic_proofs};
+
+    /// Block whose header carries the given state root.
+    pub(crate) fn block(state_root: Digest) -> Block {
+        Block::empty(Header::new(consensus::Header {
+            number: 21_000_000,
+            state_root: state_root.into(),
+            ..Default::default()
+        }))
+    }
+
+    #[tokio::test]
+    async fn storage_is_verified_against_the_state_root() {
+        let rpc = MockedProvider::new();
+        let proofs = synthetic_proofs();
+        let ger = proofs.global_exit_root_manager;
+        let slots = [Digest::ZERO, Digest::from(B256::with_last_byte(1))];
+
+        let trusted = block(proofs.state_root);
+        rpc.asserter.push_success(&trusted);
+        rpc.asserter.push_success(&ger);
+        let values = rpc
+            .get_verified_storage(ger.address.into(), &slots, trusted.header.hash.into())
+            .await
+            .unwrap();
+        assert_eq!(values, [Digest([0x11; 32]), Digest([0x22; 32])]);
+
+        // The same proof is rejected for a block with another state root.
+        let other = block(Digest([0x42; 32]));
+        rpc.asserter.push_success(&other);
+        rpc.asserter.push_success(&ger);
+        assert!(matches!(
+            rpc.get_verified_storage(ger.address.into(), &slots, other.header.hash.into())
+                .await,
+            Err(GetVerifiedStorageError::InvalidProof {
+                source: StorageProofError::InvalidAccountProof { .. },
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn headers_not_matching_the_trusted_hash_are_rejected() {
+        let rpc = MockedProvider::new();
+        let proofs = synthetic_proofs();
+        let ger = proofs.global_exit_root_manager;
+
+        // The header carries the state root the proof was built against, but
+        // it is not the header of the trusted block.
+        rpc.asserter.push_success(&block(proofs.state_root));
+        assert!(matches!(
+            rpc.get_verified_storage(ger.address.into(), &[Digest::ZERO], Digest([0x42; 32]))
+                .await,
+            Err(GetVerifiedStorageError::InvalidHeader {
+                source: BlockHeaderError::HashMismatch { .. },
+                ..
+            })
+        ));
+        assert!(rpc.asserter.read_q().is_empty());
+    }
+}
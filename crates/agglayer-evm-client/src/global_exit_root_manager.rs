@@ -0,0 +1,21 @@
+use alloy::sol;
+
+sol! {
+    /// Events and getters of the `PolygonZkEVMGlobalExitRootV2` contract.
+    #[sol(rpc)]
+    #[derive(Debug, PartialEq, Eq)]
+    contract PolygonZkEVMGlobalExitRootV2 {
+        event UpdateL1InfoTree(bytes32 indexed mainnetExitRoot, bytes32 indexed rollupExitRoot);
+
+        event UpdateL1InfoTreeV2(
+            bytes32 currentL1InfoRoot,
+            uint32 indexed leafCount,
+            uint256 blockhash,
+            uint64 minTimestamp
+        );
+
+        function getRoot() external view returns (bytes32);
+
+        function getLastGlobalExitRoot() external view returns (bytes32);
+    }
+}
@@ -0,0 +1,81 @@
+use agglayer_primitives::Digest;
+use alloy::providers::Provider as _;
+use async_trait::async_trait;
+
+use crate::{AlloyRpc, BlockRef};
+
+#[async_trait]
+pub trait IsCanonical {
+    type Error;
+
+    /// Returns whether the given block is still part of the canonical chain,
+    /// i.e. whether it has not been reorged out.
+    async fn is_canonical(&self, block: BlockRef) -> Result<bool, Self::Error>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum IsCanonicalError {
+    #[error("Getting information for block number {block_number}")]
+    GettingBlock {
+        block_number: u64,
+        source: eyre::Error,
+    },
+}
+
+#[async_trait]
+impl<T: AlloyRpc> IsCanonical for T {
+    type Error = IsCanonicalError;
+
+    /// Blocks past the head of the chain are not canonical.
+    async fn is_canonical(&self, block: BlockRef) -> Result<bool, Self::Error> {
+        let canonical = self
+            .alloy_rpc()
+            .get_block_by_number(block.number.into())
+            .await
+            .map_err(|source| IsCanonicalError::GettingBlock {
+                block_number: block.number,
+                source: source.into(),
+            })?;
+        Ok(canonical.is_some_and(|canonical| Digest::from(canonical.header.hash) == block.hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        consensus,
+        rpc::types::{Block, Header},
+    };
+
+    use super::*;
+    use crate::mocked_provider::MockedProvider;
+
+    #[tokio::test]
+    async fn hashes_are_compared_to_the_canonical_block() {
+        let rpc = MockedProvider::new();
+        let block = Block::<()>::empty(Header::new(consensus::Header {
+            number: 100,
+            ..Default::default()
+        }));
+        let canonical = BlockRef {
+            number: 100,
+            hash: block.header.hash.into(),
+        };
+
+        rpc.asserter.push_success(&block);
+        assert!(rpc.is_canonical(canonical).await.unwrap());
+
+        rpc.asserter.push_success(&block);
+        assert!(!rpc
+            .is_canonical(BlockRef {
+                hash: Digest([0x42; 32]),
+                ..canonical
+            })
+            .await
+            .unwrap());
+
+        // Not produced yet.
+        rpc.asserter.push_success(&());
+        assert!(!rpc.is_canonical(canonical).await.unwrap());
+    }
+}
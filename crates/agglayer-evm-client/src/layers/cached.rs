@@ -0,0 +1,285 @@
+use std::{num::NonZeroUsize, sync::Mutex};
+
+use agglayer_primitives::{vkey_hash::VKeyHash, Address, Digest};
+use agglayer_tries::roots::{L1InfoRoot, LocalExitRoot};
+use async_trait::async_trait;
+use lru::LruCache;
+use unified_bridge::{L1InfoTreeLeaf, RollupId};
+
+use super::for_each_rpc_trait;
+use crate::{
+    BlockHeader, BlockRef, BlockTag, BridgeEventLog, GetAggchainVKey, GetBlockHash, GetBlockHeader,
+    GetBlockNumber, GetBridgeEvents, GetL1InfoRoot, GetL1InfoTreeLeaf, GetLatestGlobalExitRoot,
+    GetRollupData, GetRollupType, GetTaggedBlock, GetVerifiedExitRoots, GetVerifiedStorage,
+    IsCanonical, RollupData, RollupType, VerifiedExitRoots,
+};
+
+struct Cache {
+    /// Last finalized block, nothing past it is cached.
+    finalized_block_number: Option<u64>,
+    hashes: LruCache<u64, Digest>,
+    numbers: LruCache<Digest, u64>,
+}
+
+impl Cache {
+    /// Caches a lookup if the block was finalized when the lookup was sent,
+    /// as `finalized_block_number` may have moved past a block which was
+    /// reorged while the response was in flight.
+    fn insert(&mut self, block_number: u64, block_hash: Digest, finalized: Option<u64>) {
+        if finalized.is_some_and(|finalized| block_number <= finalized) {
+            self.hashes.put(block_number, block_hash);
+            self.numbers.put(block_hash, block_number);
+        }
+    }
+}
+
+/// Caches the block hash and number lookups of finalized blocks, which
+/// cannot be reorged, and forwards every other request to the wrapped RPC.
+///
+/// The finalized block has to be kept up to date with
+/// [`CachedRpc::update_finalized_block`] or
+/// [`CachedRpc::set_finalized_block_number`], until then nothing is cached.
+pub struct CachedRpc<R> {
+    inner: R,
+    cache: Mutex<Cache>,
+}
+
+impl<R> CachedRpc<R> {
+    /// Creates a cache holding up to `capacity` blocks.
+    pub fn new(inner: R, capacity: NonZeroUsize) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(Cache {
+                finalized_block_number: None,
+                hashes: LruCache::new(capacity),
+                numbers: LruCache::new(capacity),
+            }),
+        }
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    /// Sets the last finalized block, the lookups of the blocks up to it
+    /// are cached from then on.
+    pub fn set_finalized_block_number(&self, block_number: u64) {
+        let mut cache = self.cache.lock().unwrap();
+        cache.finalized_block_number = cache.finalized_block_number.max(Some(block_number));
+    }
+
+    /// Fetches the finalized block from the wrapped RPC and caches the
+    /// lookups up to it from then on.
+    pub async fn update_finalized_block(&self) -> Result<BlockRef, R::Error>
+    where
+        R: GetTaggedBlock + Sync,
+    {
+        let finalized = self.inner.get_tagged_block(BlockTag::Finalized).await?;
+        self.set_finalized_block_number(finalized.number);
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(finalized.number, finalized.hash, Some(finalized.number));
+        Ok(finalized)
+    }
+}
+
+#[async_trait]
+impl<R: GetBlockHash + Sync> GetBlockHash for CachedRpc<R> {
+    type Error = R::Error;
+
+    async fn get_block_hash(&self, block_number: u64) -> Result<Digest, Self::Error> {
+        let finalized = {
+            let mut cache = self.cache.lock().unwrap();
+            if let Some(hash) = cache.hashes.get(&block_number) {
+                return Ok(*hash);
+            }
+            cache.finalized_block_number
+        };
+        let hash = self.inner.get_block_hash(block_number).await?;
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(block_number, hash, finalized);
+        Ok(hash)
+    }
+}
+
+#[async_trait]
+impl<R: GetBlockNumber + Sync> GetBlockNumber for CachedRpc<R> {
+    type Error = R::Error;
+
+    async fn get_block_number(&self, block_hash: Digest) -> Result<u64, Self::Error> {
+        let finalized = {
+            let mut cache = self.cache.lock().unwrap();
+            if let Some(number) = cache.numbers.get(&block_hash) {
+                return Ok(*number);
+            }
+            cache.finalized_block_number
+        };
+        let number = self.inner.get_block_number(block_hash).await?;
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(number, block_hash, finalized);
+        Ok(number)
+    }
+}
+
+macro_rules! impl_forwarded {
+    ($trait:ident { $(fn $method:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)+ }) => {
+        #[async_trait]
+        impl<R: $trait + Sync> $trait for CachedRpc<R> {
+            type Error = R::Error;
+
+            $(async fn $method(&self, $($arg: $ty),*) -> Result<$ret, Self::Error> {
+                self.inner.$method($($arg),*).await
+            })+
+        }
+    };
+}
+
+for_each_rpc_trait!(except_block_lookups impl_forwarded);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::sync::Notify;
+
+    use super::*;
+    use crate::MockRpc;
+
+    fn cached(mock: MockRpc) -> CachedRpc<MockRpc> {
+        CachedRpc::new(mock, NonZeroUsize::new(2).unwrap())
+    }
+
+    #[tokio::test]
+    async fn finalized_lookups_are_cached() {
+        let mut mock = MockRpc::new();
+        mock.expect_get_block_hash()
+            .withf(|block_number| *block_number == 10)
+            .once()
+            .returning(|_| Ok(Digest([10; 32])));
+        let rpc = cached(mock);
+        rpc.set_finalized_block_number(10);
+
+        assert_eq!(rpc.get_block_hash(10).await.unwrap(), Digest([10; 32]));
+        assert_eq!(rpc.get_block_hash(10).await.unwrap(), Digest([10; 32]));
+        // The reverse lookup is served from the same entry.
+        assert_eq!(rpc.get_block_number(Digest([10; 32])).await.unwrap(), 10);
+    }
+
+    #[tokio::test]
+    async fn finalized_block_is_fetched_from_the_rpc() {
+        let mut mock = MockRpc::new();
+        mock.expect_get_tagged_block()
+            .withf(|tag| *tag == BlockTag::Finalized)
+            .once()
+            .returning(|_| {
+                Ok(BlockRef {
+                    number: 10,
+                    hash: Digest([10; 32]),
+                })
+            });
+        let rpc = cached(mock);
+
+        rpc.update_finalized_block().await.unwrap();
+        assert_eq!(rpc.get_block_hash(10).await.unwrap(), Digest([10; 32]));
+    }
+
+    #[tokio::test]
+    async fn unfinalized_lookups_are_not_cached() {
+        let mut mock = MockRpc::new();
+        mock.expect_get_block_number()
+            .times(2)
+            .returning(|_| Ok(11));
+        mock.expect_get_block_hash()
+            .times(2)
+            .returning(|block_number| Ok(Digest([block_number as u8; 32])));
+        let rpc = cached(mock);
+
+        // Nothing is cached until the finalized block is known.
+        rpc.get_block_hash(5).await.unwrap();
+        rpc.get_block_hash(5).await.unwrap();
+
+        rpc.set_finalized_block_number(10);
+        rpc.get_block_number(Digest([11; 32])).await.unwrap();
+        rpc.get_block_number(Digest([11; 32])).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn blocks_finalized_during_the_lookup_are_not_cached() {
+        /// Answers the lookups once released.
+        #[derive(Default)]
+        struct Slow {
+            calls: AtomicUsize,
+            release: Notify,
+        }
+
+        #[async_trait]
+        impl GetBlockHash for Slow {
+            type Error = eyre::Error;
+
+            async fn get_block_hash(&self, block_number: u64) -> eyre::Result<Digest> {
+                self.calls.fetch_add(1, Ordering::Relaxed);
+                self.release.notified().await;
+                Ok(Digest([block_number as u8; 32]))
+            }
+        }
+
+        let rpc = CachedRpc::new(Slow::default(), NonZeroUsize::new(2).unwrap());
+        rpc.set_finalized_block_number(5);
+
+        // The response may be from a fork which got reorged before block 8
+        // was finalized.
+        let (hash, ()) = tokio::join!(rpc.get_block_hash(8), async {
+            rpc.set_finalized_block_number(10);
+            rpc.inner().release.notify_one();
+        });
+        assert_eq!(hash.unwrap(), Digest([8; 32]));
+
+        rpc.inner().release.notify_one();
+        rpc.get_block_hash(8).await.unwrap();
+        rpc.inner().release.notify_one();
+        rpc.get_block_hash(8).await.unwrap();
+        assert_eq!(rpc.inner().calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn least_recently_used_blocks_are_evicted() {
+        let mut mock = MockRpc::new();
+        mock.expect_get_block_hash()
+            .withf(|block_number| *block_number == 1)
+            .times(2)
+            .returning(|_| Ok(Digest([1; 32])));
+        mock.expect_get_block_hash()
+            .withf(|block_number| *block_number != 1)
+            .times(2)
+            .returning(|block_number| Ok(Digest([block_number as u8; 32])));
+        let rpc = cached(mock);
+        rpc.set_finalized_block_number(10);
+
+        for block_number in [1, 2, 3, 1] {
+            rpc.get_block_hash(block_number).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn other_requests_are_forwarded() {
+        let mut mock = MockRpc::new();
+        mock.expect_get_latest_global_exit_root()
+            .times(2)
+            .returning(|_| Ok(Digest([0x42; 32])));
+        let rpc = cached(mock);
+
+        for _ in 0..2 {
+            assert_eq!(
+                rpc.get_latest_global_exit_root(Address::ZERO)
+                    .await
+                    .unwrap(),
+                Digest([0x42; 32])
+            );
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use std::{future::Future, sync::Mutex, time::Duration};
+
+use agglayer_primitives::{vkey_hash::VKeyHash, Address, Digest};
+use agglayer_tries::roots::{L1InfoRoot, LocalExitRoot};
+use async_trait::async_trait;
+use tokio::time::Instant;
+use tracing::warn;
+use unified_bridge::{L1InfoTreeLeaf, RollupId};
+
+use super::for_each_rpc_trait;
+use crate::{
+    BlockHeader, BlockRef, BlockTag, BridgeEventLog, GetAggchainVKey, GetBlockHash, GetBlockHeader,
+    GetBlockNumber, GetBridgeEvents, GetL1InfoRoot, GetL1InfoTreeLeaf, GetLatestGlobalExitRoot,
+    GetRollupData, GetRollupType, GetTaggedBlock, GetVerifiedExitRoots, GetVerifiedStorage,
+    IsCanonical, RollupData, RollupType, TransientError, VerifiedExitRoots,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum FallbackError<E> {
+    #[error("No endpoint to send the request to")]
+    NoEndpoint,
+
+    #[error("Endpoint {endpoint} rejected the request")]
+    Rejected {
+        endpoint: usize,
+        #[source]
+        source: E,
+    },
+
+    #[error("All {attempts} endpoints failed")]
+    AllEndpointsFailed {
+        attempts: usize,
+        /// Error of the last endpoint tried.
+        #[source]
+        last: E,
+    },
+}
+
+#[derive(Debug, Default)]
+struct Health {
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+}
+
+/// Sends the requests to the first healthy endpoint, falling back to the
+/// next ones when it fails.
+///
+/// Only [transient](TransientError) errors are failures of the endpoint:
+/// other errors, such as the node rejecting the request, would be the same
+/// on every endpoint and are returned right away.
+///
+/// An endpoint failing `max_failures` times in a row is considered unhealthy
+/// and is only tried after the healthy ones until its cooldown elapses.
+pub struct FallbackRpc<R> {
+    endpoints: Vec<R>,
+    health: Vec<Mutex<Health>>,
+    max_failures: u32,
+    cooldown: Duration,
+}
+
+impl<R> FallbackRpc<R> {
+    /// Creates a fallback over the given endpoints, in order of preference.
+    pub fn new(endpoints: Vec<R>) -> Self {
+        let health = endpoints.iter().map(|_| Mutex::default()).collect();
+        Self {
+            endpoints,
+            health,
+            max_failures: 3,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+
+    pub fn with_max_failures(mut self, max_failures: u32) -> Self {
+        self.max_failures = max_failures.max(1);
+        self
+    }
+
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    pub fn endpoints(&self) -> &[R] {
+        &self.endpoints
+    }
+
+    /// Returns whether the endpoint at `index` is currently healthy.
+    pub fn is_healthy(&self, index: usize) -> bool {
+        self.health[index]
+            .lock()
+            .unwrap()
+            .unhealthy_until
+            .is_none_or(|until| until <= Instant::now())
+    }
+
+    fn record_success(&self, index: usize) {
+        *self.health[index].lock().unwrap() = Health::default();
+    }
+
+    fn record_failure(&self, index: usize) {
+        let mut health = self.health[index].lock().unwrap();
+        health.consecutive_failures += 1;
+        if health.consecutive_failures >= self.max_failures {
+            warn!(
+                endpoint = index,
+                consecutive_failures = health.consecutive_failures,
+                "RPC endpoint marked unhealthy"
+            );
+            health.unhealthy_until = Some(Instant::now() + self.cooldown);
+        }
+    }
+
+    async fn call<'a, T, E, Fut>(
+        &'a self,
+        request: impl Fn(&'a R) -> Fut,
+    ) -> Result<T, FallbackError<E>>
+    where
+        Fut: Future<Output = Result<T, E>>,
+        E: TransientError,
+    {
+        // Healthy endpoints first, unhealthy ones as a last resort.
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) =
+            (0..self.endpoints.len()).partition(|&index| self.is_healthy(index));
+
+        let mut last = None;
+        for index in healthy.into_iter().chain(unhealthy) {
+            match request(&self.endpoints[index]).await {
+                Ok(response) => {
+                    self.record_success(index);
+                    return Ok(response);
+                }
+                Err(error) if error.is_transient() => {
+                    self.record_failure(index);
+                    last = Some(error);
+                }
+                Err(source) => {
+                    return Err(FallbackError::Rejected {
+                        endpoint: index,
+                        source,
+                    });
+                }
+            }
+        }
+
+        match last {
+            Some(last) => Err(FallbackError::AllEndpointsFailed {
+                attempts: self.endpoints.len(),
+                last,
+            }),
+            None => Err(FallbackError::NoEndpoint),
+        }
+    }
+}
+
+macro_rules! impl_fallback {
+    ($trait:ident { $(fn $method:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)+ }) => {
+        #[async_trait]
+        impl<R: $trait + Sync + Send> $trait for FallbackRpc<R>
+        where
+            R::Error: TransientError + Send,
+        {
+            type Error = FallbackError<R::Error>;
+
+            $(async fn $method(&self, $($arg: $ty),*) -> Result<$ret, Self::Error> {
+                self.call(|endpoint| endpoint.$method($($arg),*)).await
+            })+
+        }
+    };
+}
+
+for_each_rpc_trait!(impl_fallback);
+
+#[cfg(test)]
+mod tests {
+    use alloy::transports::TransportErrorKind;
+
+    use super::*;
+    use crate::MockRpc;
+
+    fn unavailable() -> eyre::Error {
+        TransportErrorKind::backend_gone().into()
+    }
+
+    fn endpoint(hash: Option<Digest>) -> MockRpc {
+        let mut mock = MockRpc::new();
+        mock.expect_get_block_hash().returning(move |_| match hash {
+            Some(hash) => Ok(hash),
+            None => Err(unavailable()),
+        });
+        mock
+    }
+
+    #[tokio::test]
+    async fn failing_endpoints_are_skipped() {
+        let rpc = FallbackRpc::new(vec![
+            endpoint(None),
+            endpoint(Some(Digest([1; 32]))),
+            endpoint(Some(Digest([2; 32]))),
+        ]);
+
+        assert_eq!(rpc.get_block_hash(7).await.unwrap(), Digest([1; 32]));
+        assert!(rpc.is_healthy(0));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unhealthy_endpoints_are_tried_last_until_cooldown() {
+        let mut failing = MockRpc::new();
+        let mut seq = mockall::Sequence::new();
+        failing
+            .expect_get_block_hash()
+            .times(2)
+            .in_sequence(&mut seq)
+            .returning(|_| Err(unavailable()));
+        failing
+            .expect_get_block_hash()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(Digest([0; 32])));
+        let rpc = FallbackRpc::new(vec![failing, endpoint(Some(Digest([1; 32])))])
+            .with_max_failures(2)
+            .with_cooldown(Duration::from_secs(10));
+
+        for _ in 0..2 {
+            assert_eq!(rpc.get_block_hash(7).await.unwrap(), Digest([1; 32]));
+        }
+        assert!(!rpc.is_healthy(0));
+
+        // The first endpoint is not queried during its cooldown.
+        assert_eq!(rpc.get_block_hash(7).await.unwrap(), Digest([1; 32]));
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert!(rpc.is_healthy(0));
+        assert_eq!(rpc.get_block_hash(7).await.unwrap(), Digest([0; 32]));
+    }
+
+    #[tokio::test]
+    async fn last_error_is_reported() {
+        let rpc = FallbackRpc::new(vec![endpoint(None), endpoint(None)]);
+        assert!(matches!(
+            rpc.get_block_hash(7).await,
+            Err(FallbackError::AllEndpointsFailed { attempts: 2, .. })
+        ));
+
+        let rpc = FallbackRpc::<MockRpc>::new(Vec::new());
+        assert!(matches!(
+            rpc.get_block_hash(7).await,
+            Err(FallbackError::NoEndpoint)
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejected_requests_are_not_sent_to_other_endpoints() {
+        let mut rejecting = MockRpc::new();
+        rejecting
+            .expect_get_block_hash()
+            .times(2)
+            .returning(|_| Err(eyre::eyre!("header not found")));
+        let mut other = MockRpc::new();
+        other.expect_get_block_hash().never();
+        let rpc = FallbackRpc::new(vec![rejecting, other]).with_max_failures(1);
+
+        for _ in 0..2 {
+            assert!(matches!(
+                rpc.get_block_hash(7).await,
+                Err(FallbackError::Rejected { endpoint: 0, .. })
+            ));
+        }
+        assert!(rpc.is_healthy(0));
+    }
+}
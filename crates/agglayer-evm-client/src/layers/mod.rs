@@ -0,0 +1,80 @@
+//! Layers wrapping an RPC client while implementing the same traits, so that
+//! they can be stacked without changes to the callers.
+
+mod cached;
+mod fallback;
+mod rate_limited;
+
+pub use cached::CachedRpc;
+pub use fallback::{FallbackError, FallbackRpc};
+pub use rate_limited::{RateLimit, RateLimitedRpc};
+
+/// Invokes `$impl_trait!` with the methods of every RPC trait, so that each
+/// layer only has to describe how it wraps a single method.
+macro_rules! for_each_rpc_trait {
+    ($impl_trait:ident) => {
+        $impl_trait! { GetBlockHash {
+            fn get_block_hash(block_number: u64) -> Digest;
+        } }
+        $impl_trait! { GetBlockNumber {
+            fn get_block_number(block_hash: Digest) -> u64;
+        } }
+        for_each_rpc_trait!(except_block_lookups $impl_trait);
+    };
+    (except_block_lookups $impl_trait:ident) => {
+        $impl_trait! { GetAggchainVKey {
+            fn get_aggchain_vkey(aggchain_address: Address, selector: [u8; 4]) -> VKeyHash;
+        } }
+        $impl_trait! { GetBlockHeader {
+            fn get_block_header(block_number: u64) -> BlockHeader;
+        } }
+        $impl_trait! { GetBridgeEvents {
+            fn get_bridge_events(
+                bridge_address: Address,
+                from_block: u64,
+                to_block: u64,
+            ) -> Vec<BridgeEventLog>;
+        } }
+        $impl_trait! { GetL1InfoTreeLeaf {
+            fn get_l1_info_tree_leaf(
+                ger_address: Address,
+                l1_info_tree_index: u32,
+                from_block: u64,
+            ) -> L1InfoTreeLeaf;
+        } }
+        $impl_trait! { GetL1InfoRoot {
+            fn get_l1_info_root(ger_address: Address) -> L1InfoRoot;
+        } }
+        $impl_trait! { GetLatestGlobalExitRoot {
+            fn get_latest_global_exit_root(ger_address: Address) -> Digest;
+        } }
+        $impl_trait! { GetRollupData {
+            fn get_rollup_data(rollup_manager_address: Address, rollup_id: RollupId) -> RollupData;
+        } }
+        $impl_trait! { GetRollupType {
+            fn get_rollup_type(rollup_manager_address: Address, rollup_type_id: u32) -> RollupType;
+        } }
+        $impl_trait! { GetTaggedBlock {
+            fn get_tagged_block(tag: BlockTag) -> BlockRef;
+        } }
+        $impl_trait! { IsCanonical {
+            fn is_canonical(block: BlockRef) -> bool;
+        } }
+        $impl_trait! { GetVerifiedStorage {
+            fn get_verified_storage(
+                address: Address,
+                slots: &[Digest],
+                block_hash: Digest,
+            ) -> Vec<Digest>;
+        } }
+        $impl_trait! { GetVerifiedExitRoots {
+            fn get_verified_local_exit_root(
+                bridge_address: Address,
+                block_hash: Digest,
+            ) -> LocalExitRoot;
+            fn get_verified_exit_roots(ger_address: Address, block_hash: Digest) -> VerifiedExitRoots;
+        } }
+    };
+}
+
+use for_each_rpc_trait;
@@ -0,0 +1,135 @@
+use std::{collections::VecDeque, num::NonZeroU32, sync::Mutex, time::Duration};
+
+use agglayer_primitives::{vkey_hash::VKeyHash, Address, Digest};
+use agglayer_tries::roots::{L1InfoRoot, LocalExitRoot};
+use async_trait::async_trait;
+use tokio::time::Instant;
+use unified_bridge::{L1InfoTreeLeaf, RollupId};
+
+use super::for_each_rpc_trait;
+use crate::{
+    BlockHeader, BlockRef, BlockTag, BridgeEventLog, GetAggchainVKey, GetBlockHash, GetBlockHeader,
+    GetBlockNumber, GetBridgeEvents, GetL1InfoRoot, GetL1InfoTreeLeaf, GetLatestGlobalExitRoot,
+    GetRollupData, GetRollupType, GetTaggedBlock, GetVerifiedExitRoots, GetVerifiedStorage,
+    IsCanonical, RollupData, RollupType, VerifiedExitRoots,
+};
+
+/// Maximum number of requests sent to an endpoint over a period of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_requests: NonZeroU32,
+    pub period: Duration,
+}
+
+impl RateLimit {
+    pub const fn per_second(max_requests: NonZeroU32) -> Self {
+        Self {
+            max_requests,
+            period: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Delays the requests to the wrapped RPC so that they stay within a
+/// [`RateLimit`].
+///
+/// Each request counts once, whatever the number of JSON-RPC calls it takes,
+/// so the limit should be set with the most expensive request in mind.
+pub struct RateLimitedRpc<R> {
+    inner: R,
+    rate_limit: RateLimit,
+    /// Start of the requests sent during the last period.
+    sent_at: Mutex<VecDeque<Instant>>,
+}
+
+impl<R> RateLimitedRpc<R> {
+    pub fn new(inner: R, rate_limit: RateLimit) -> Self {
+        Self {
+            inner,
+            rate_limit,
+            sent_at: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    /// Waits until a request can be sent without exceeding the rate limit.
+    async fn acquire(&self) {
+        loop {
+            let now = Instant::now();
+            let wait_until = {
+                let mut sent_at = self.sent_at.lock().unwrap();
+                while sent_at
+                    .front()
+                    .is_some_and(|sent_at| *sent_at + self.rate_limit.period <= now)
+                {
+                    sent_at.pop_front();
+                }
+                if sent_at.len() < self.rate_limit.max_requests.get() as usize {
+                    sent_at.push_back(now);
+                    return;
+                }
+                sent_at[0] + self.rate_limit.period
+            };
+            tokio::time::sleep_until(wait_until).await;
+        }
+    }
+}
+
+macro_rules! impl_rate_limited {
+    ($trait:ident { $(fn $method:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)+ }) => {
+        #[async_trait]
+        impl<R: $trait + Sync> $trait for RateLimitedRpc<R> {
+            type Error = R::Error;
+
+            $(async fn $method(&self, $($arg: $ty),*) -> Result<$ret, Self::Error> {
+                self.acquire().await;
+                self.inner.$method($($arg),*).await
+            })+
+        }
+    };
+}
+
+for_each_rpc_trait!(impl_rate_limited);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockRpc;
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_are_delayed_over_the_limit() {
+        let mut mock = MockRpc::new();
+        mock.expect_get_block_hash()
+            .times(5)
+            .returning(|block_number| Ok(Digest([block_number as u8; 32])));
+        let rpc = RateLimitedRpc::new(mock, RateLimit::per_second(NonZeroU32::new(2).unwrap()));
+
+        let start = Instant::now();
+        let mut elapsed = Vec::new();
+        for block_number in 0..5 {
+            assert_eq!(
+                rpc.get_block_hash(block_number).await.unwrap(),
+                Digest([block_number as u8; 32])
+            );
+            elapsed.push((Instant::now() - start).as_secs());
+        }
+        assert_eq!(elapsed, [0, 0, 1, 1, 2]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn errors_are_forwarded() {
+        let mut mock = MockRpc::new();
+        mock.expect_get_latest_global_exit_root()
+            .once()
+            .returning(|_| Err(eyre::eyre!("unavailable")));
+        let rpc = RateLimitedRpc::new(mock, RateLimit::per_second(NonZeroU32::new(1).unwrap()));
+
+        assert!(rpc
+            .get_latest_global_exit_root(Address::ZERO)
+            .await
+            .is_err());
+    }
+}
@@ -1,11 +1,69 @@
+mod aggchain;
 mod alloy_rpc;
+mod block_header;
+mod bridge_events;
+#[cfg(feature = "testutils")]
+mod cassette;
+mod get_aggchain_vkey;
 mod get_block_hash;
+mod get_block_header;
 mod get_block_number;
+mod get_bridge_events;
+mod get_l1_info_root;
+mod get_l1_info_tree_leaf;
+mod get_latest_global_exit_root;
+mod get_rollup_data;
+mod get_rollup_type;
+mod get_tagged_block;
+mod get_verified_exit_roots;
+mod get_verified_storage;
+mod global_exit_root_manager;
+mod is_canonical;
+pub mod layers;
+mod local_exit_tree_indexer;
 #[cfg(feature = "testutils")]
 mod mock_rpc;
+#[cfg(test)]
+mod mocked_provider;
+mod rollup_manager;
+mod storage_proof;
+mod transaction_submitter;
+mod transient_error;
+mod wait_until_final;
 
-pub use alloy_rpc::AlloyRpc;
-pub use get_block_hash::GetBlockHash;
-pub use get_block_number::GetBlockNumber;
+pub use aggchain::AggchainBase;
+pub use alloy_rpc::{AlloyRpc, HttpRpc};
+pub use block_header::{verify_header_chain, BlockHeader, BlockHeaderError};
+pub use bridge_events::{
+    BridgeContractEvent, DecodeBridgeEventError, DecodedBridgeExit, DecodedClaim,
+    PolygonZkEVMBridgeV2,
+};
+#[cfg(feature = "testutils")]
+pub use cassette::{CassetteError, Interaction, MissingInteraction, RecordingRpc, ReplayRpc};
+pub use get_aggchain_vkey::{GetAggchainVKey, GetAggchainVKeyError};
+pub use get_block_hash::{GetBlockHash, GetBlockHashError};
+pub use get_block_header::{GetBlockHeader, GetBlockHeaderError};
+pub use get_block_number::{GetBlockNumber, GetBlockNumberError};
+pub use get_bridge_events::{BridgeEventLog, GetBridgeEvents, GetBridgeEventsError};
+pub use get_l1_info_root::{GetL1InfoRoot, GetL1InfoRootError};
+pub use get_l1_info_tree_leaf::{GetL1InfoTreeLeaf, GetL1InfoTreeLeafError};
+pub use get_latest_global_exit_root::{GetLatestGlobalExitRoot, GetLatestGlobalExitRootError};
+pub use get_rollup_data::{GetRollupData, GetRollupDataError, RollupData};
+pub use get_rollup_type::{GetRollupType, GetRollupTypeError, RollupType};
+pub use get_tagged_block::{BlockRef, BlockTag, GetTaggedBlock, GetTaggedBlockError};
+pub use get_verified_exit_roots::{
+    GetVerifiedExitRoots, GetVerifiedExitRootsError, VerifiedExitRoots,
+};
+pub use get_verified_storage::{GetVerifiedStorage, GetVerifiedStorageError};
+pub use global_exit_root_manager::PolygonZkEVMGlobalExitRootV2;
+pub use is_canonical::{IsCanonical, IsCanonicalError};
+pub use local_exit_tree_indexer::{
+    LocalExitTreeCheckpoint, LocalExitTreeIndexer, LocalExitTreeIndexerError,
+};
 #[cfg(feature = "testutils")]
 pub use mock_rpc::MockRpc;
+pub use rollup_manager::{InvalidVerifierTypeError, PolygonRollupManager, VerifierType};
+pub use storage_proof::{verify_storage_proof, StorageProofError};
+pub use transaction_submitter::{SubmissionConfig, SubmitTransactionError, TransactionSubmitter};
+pub use transient_error::TransientError;
+pub use wait_until_final::{wait_until_final, WaitUntilFinalError};
@@ -0,0 +1,676 @@
+use std::collections::BTreeMap;
+
+use agglayer_primitives::{Address, Digest, Hashable as _};
+use agglayer_tries::roots::LocalExitRoot;
+use serde::{Deserialize, Serialize};
+use unified_bridge::{LocalExitTree, LocalExitTreeError};
+
+use crate::{BridgeContractEvent, GetBlockHash, GetBridgeEvents};
+
+/// Indexing progress from which a [`LocalExitTreeIndexer`] can resume.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalExitTreeCheckpoint {
+    /// Last indexed block.
+    pub block_number: u64,
+    pub block_hash: Digest,
+    /// Local exit tree after the last indexed block.
+    pub tree: LocalExitTree,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LocalExitTreeIndexerError {
+    #[error("Getting bridge events for blocks {from_block} to {to_block}")]
+    GettingEvents {
+        from_block: u64,
+        to_block: u64,
+        source: eyre::Error,
+    },
+
+    #[error("Getting hash of block {block_number}")]
+    GettingBlockHash {
+        block_number: u64,
+        source: eyre::Error,
+    },
+
+    #[error(
+        "Bridge exit of block {block_number} has deposit count {leaf_index}, expected {expected}"
+    )]
+    UnexpectedLeafIndex {
+        block_number: u64,
+        leaf_index: u32,
+        expected: u32,
+    },
+
+    #[error("Adding bridge exit of block {block_number} to the local exit tree")]
+    AddingLeaf {
+        block_number: u64,
+        source: LocalExitTreeError,
+    },
+
+    #[error("Block {block_number} the indexing resumed from has been reorged")]
+    ReorgBeyondCheckpoint { block_number: u64 },
+
+    #[error("Blocks {from_block} to {to_block} were reorged while being indexed")]
+    ReorgWhileIndexing { from_block: u64, to_block: u64 },
+}
+
+/// Rebuilds the local exit tree of a network from the events of its bridge
+/// contract.
+///
+/// The tree after every block with bridge exits is kept in memory so that the
+/// local exit root can be queried at any indexed block, and so that the
+/// indexing can rewind to the last canonical block on reorgs. Finalized blocks
+/// should be forgotten with [`LocalExitTreeIndexer::prune_finalized`] to
+/// bound the memory used.
+pub struct LocalExitTreeIndexer<R> {
+    rpc: R,
+    bridge_address: Address,
+    page_size: u64,
+    /// First block whose events are indexed.
+    start_block: u64,
+    /// Tree before `start_block`.
+    base_tree: LocalExitTree,
+    /// Hash of the block before `start_block`, if resumed from a checkpoint.
+    base_hash: Option<Digest>,
+    /// Last indexed block.
+    synced_to: Option<u64>,
+    /// Tree after each indexed block with bridge exits.
+    trees: BTreeMap<u64, LocalExitTree>,
+    /// Hashes of the indexed blocks used to detect reorgs.
+    block_hashes: BTreeMap<u64, Digest>,
+}
+
+impl<R> LocalExitTreeIndexer<R>
+where
+    R: GetBlockHash + GetBridgeEvents + Sync,
+    <R as GetBlockHash>::Error: Into<eyre::Error>,
+    <R as GetBridgeEvents>::Error: Into<eyre::Error>,
+{
+    pub const DEFAULT_PAGE_SIZE: u64 = 1_000;
+
+    /// Indexes the bridge contract from `start_block`, usually the block the
+    /// contract was deployed at.
+    pub fn new(rpc: R, bridge_address: Address, start_block: u64) -> Self {
+        Self {
+            rpc,
+            bridge_address,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            start_block,
+            base_tree: LocalExitTree::new(),
+            base_hash: None,
+            synced_to: None,
+            trees: BTreeMap::new(),
+            block_hashes: BTreeMap::new(),
+        }
+    }
+
+    /// Resumes the indexing after the given checkpoint.
+    pub fn from_checkpoint(
+        rpc: R,
+        bridge_address: Address,
+        checkpoint: LocalExitTreeCheckpoint,
+    ) -> Self {
+        Self {
+            start_block: checkpoint.block_number + 1,
+            base_tree: checkpoint.tree,
+            base_hash: Some(checkpoint.block_hash),
+            ..Self::new(rpc, bridge_address, 0)
+        }
+    }
+
+    /// Sets the maximum number of blocks of a single `eth_getLogs` request.
+    pub fn with_page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Last indexed block, if any.
+    pub fn synced_to(&self) -> Option<u64> {
+        self.synced_to.or(self.start_block.checked_sub(1))
+    }
+
+    /// Local exit tree after the last indexed block.
+    pub fn tree(&self) -> &LocalExitTree {
+        self.trees
+            .last_key_value()
+            .map_or(&self.base_tree, |(_, tree)| tree)
+    }
+
+    /// Returns the local exit root after the given block, or `None` if the
+    /// block has not been indexed.
+    pub fn local_exit_root_at(&self, block_number: u64) -> Option<LocalExitRoot> {
+        if block_number > self.synced_to()? || block_number + 1 < self.start_block {
+            return None;
+        }
+        let tree = self
+            .trees
+            .range(..=block_number)
+            .next_back()
+            .map_or(&self.base_tree, |(_, tree)| tree);
+        Some(LocalExitRoot::new(tree.get_root()))
+    }
+
+    /// Returns the progress to resume the indexing from, if any block has
+    /// been indexed.
+    pub fn checkpoint(&self) -> Option<LocalExitTreeCheckpoint> {
+        let block_number = self.synced_to()?;
+        let block_hash = match self.block_hashes.get(&block_number) {
+            Some(block_hash) => *block_hash,
+            None => self.base_hash?,
+        };
+        Some(LocalExitTreeCheckpoint {
+            block_number,
+            block_hash,
+            tree: self.tree().clone(),
+        })
+    }
+
+    /// Forgets the trees and hashes of the blocks up to `finalized_block`,
+    /// which cannot be reorged anymore, keeping the tree after the last
+    /// indexed block up to it as the base of the indexing.
+    ///
+    /// The local exit roots of the forgotten blocks are no longer available.
+    pub fn prune_finalized(&mut self, finalized_block: u64) {
+        let Some((&block_number, &block_hash)) =
+            self.block_hashes.range(..=finalized_block).next_back()
+        else {
+            return;
+        };
+        let kept = self.trees.split_off(&(block_number + 1));
+        if let Some((_, tree)) = std::mem::replace(&mut self.trees, kept).pop_last() {
+            self.base_tree = tree;
+        }
+        self.block_hashes = self.block_hashes.split_off(&(block_number + 1));
+        self.start_block = block_number + 1;
+        self.base_hash = Some(block_hash);
+    }
+
+    /// Indexes the bridge events up to `to_block` (inclusive), first rewinding
+    /// the blocks which are no longer canonical.
+    ///
+    /// Fails with [`LocalExitTreeIndexerError::ReorgWhileIndexing`] if a page
+    /// is reorged while being indexed, in which case the pages indexed so far
+    /// are kept and the sync can be retried.
+    pub async fn sync(&mut self, to_block: u64) -> Result<(), LocalExitTreeIndexerError> {
+        self.rewind_reorged_blocks().await?;
+
+        let mut from_block = self.synced_to().map_or(self.start_block, |block| block + 1);
+        while from_block <= to_block {
+            let page_end = to_block.min(from_block.saturating_add(self.page_size - 1));
+            self.index_page(from_block, page_end).await?;
+            from_block = page_end + 1;
+        }
+        Ok(())
+    }
+
+    async fn index_page(
+        &mut self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<(), LocalExitTreeIndexerError> {
+        // The logs are only trusted to come from the fork of `to_block` if its
+        // hash is the same before and after fetching them.
+        let to_block_hash = self.block_hash(to_block).await?;
+        let logs = self
+            .rpc
+            .get_bridge_events(self.bridge_address, from_block, to_block)
+            .await
+            .map_err(|source| LocalExitTreeIndexerError::GettingEvents {
+                from_block,
+                to_block,
+                source: source.into(),
+            })?;
+
+        // Only update the state once the whole page is indexed.
+        let mut tree = self.tree().clone();
+        let mut trees = BTreeMap::new();
+        let mut block_hashes = BTreeMap::new();
+        for log in logs {
+            let BridgeContractEvent::BridgeExit(exit) = log.event else {
+                continue;
+            };
+            if exit.leaf_index != tree.leaf_count() {
+                return Err(LocalExitTreeIndexerError::UnexpectedLeafIndex {
+                    block_number: log.block_number,
+                    leaf_index: exit.leaf_index,
+                    expected: tree.leaf_count(),
+                });
+            }
+            tree.add_leaf(exit.bridge_exit.hash()).map_err(|source| {
+                LocalExitTreeIndexerError::AddingLeaf {
+                    block_number: log.block_number,
+                    source,
+                }
+            })?;
+            trees.insert(log.block_number, tree.clone());
+            block_hashes.insert(log.block_number, log.block_hash);
+        }
+        if self.block_hash(to_block).await? != to_block_hash {
+            return Err(LocalExitTreeIndexerError::ReorgWhileIndexing {
+                from_block,
+                to_block,
+            });
+        }
+        block_hashes.insert(to_block, to_block_hash);
+
+        self.trees.append(&mut trees);
+        self.block_hashes.append(&mut block_hashes);
+        self.synced_to = Some(to_block);
+        Ok(())
+    }
+
+    /// Rewinds to the last indexed block which is still canonical.
+    async fn rewind_reorged_blocks(&mut self) -> Result<(), LocalExitTreeIndexerError> {
+        let indexed: Vec<_> = self
+            .block_hashes
+            .iter()
+            .rev()
+            .map(|(block_number, block_hash)| (*block_number, *block_hash))
+            .collect();
+        for (block_number, block_hash) in indexed {
+            if self.block_hash(block_number).await? == block_hash {
+                self.rewind_to(block_number);
+                return Ok(());
+            }
+        }
+
+        if let (Some(block_hash), Some(block_number)) =
+            (self.base_hash, self.start_block.checked_sub(1))
+        {
+            if self.block_hash(block_number).await? != block_hash {
+                return Err(LocalExitTreeIndexerError::ReorgBeyondCheckpoint { block_number });
+            }
+        }
+        self.trees.clear();
+        self.block_hashes.clear();
+        self.synced_to = None;
+        Ok(())
+    }
+
+    fn rewind_to(&mut self, block_number: u64) {
+        if self.synced_to != Some(block_number) {
+            tracing::warn!(
+                from = self.synced_to,
+                to = block_number,
+                "Rewinding the local exit tree after a reorg"
+            );
+        }
+        self.trees.split_off(&(block_number + 1));
+        self.block_hashes.split_off(&(block_number + 1));
+        self.synced_to = Some(block_number);
+    }
+
+    async fn block_hash(&self, block_number: u64) -> Result<Digest, LocalExitTreeIndexerError> {
+        self.rpc
+            .get_block_hash(block_number)
+            .await
+            .map_err(|source| LocalExitTreeIndexerError::GettingBlockHash {
+                block_number,
+                source: source.into(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicU8, Ordering},
+        Mutex,
+    };
+
+    use agglayer_primitives::{keccak::keccak256_combine, U256};
+    use async_trait::async_trait;
+    use unified_bridge::{BridgeExit, LeafType, NetworkId};
+
+    use super::*;
+    use crate::{BridgeEventLog, DecodedBridgeExit, MockRpc, ReplayRpc};
+
+    const BRIDGE_ADDRESS: Address = Address::new([0xb1; 20]);
+
+    /// In-memory chain whose blocks contain bridge exits.
+    #[derive(Default)]
+    struct FakeChain {
+        /// Hash and bridge exits of each block.
+        blocks: Mutex<Vec<(Digest, Vec<BridgeExit>)>>,
+    }
+
+    impl FakeChain {
+        /// Appends blocks with the given number of bridge exits, tagging the
+        /// block hashes with `fork` to tell forks apart.
+        fn extend(&self, fork: u8, exits_per_block: &[usize]) {
+            let mut blocks = self.blocks.lock().unwrap();
+            for exits in exits_per_block {
+                let number = blocks.len() as u64;
+                let hash = keccak256_combine([&number.to_be_bytes()[..], &[fork]]);
+                let exits = (0..*exits)
+                    .map(|i| bridge_exit(number * 100 + i as u64 + fork as u64 * 10_000))
+                    .collect();
+                blocks.push((hash, exits));
+            }
+        }
+
+        /// Replaces the blocks from `block_number` on.
+        fn reorg(&self, block_number: u64, fork: u8, exits_per_block: &[usize]) {
+            self.blocks.lock().unwrap().truncate(block_number as usize);
+            self.extend(fork, exits_per_block);
+        }
+
+        /// Reference local exit root after the given block.
+        fn local_exit_root(&self, block_number: u64) -> LocalExitRoot {
+            let blocks = self.blocks.lock().unwrap();
+            let leaves = blocks[..=block_number as usize]
+                .iter()
+                .flat_map(|(_, exits)| exits.iter().map(|exit| exit.hash()));
+            LocalExitRoot::new(LocalExitTree::<32>::from_leaves(leaves).unwrap().get_root())
+        }
+    }
+
+    fn bridge_exit(amount: u64) -> BridgeExit {
+        BridgeExit::new(
+            LeafType::Transfer,
+            NetworkId::new(0),
+            Address::ZERO,
+            NetworkId::new(1),
+            Address::new([0xde; 20]),
+            U256::from(amount),
+            vec![],
+        )
+    }
+
+    #[async_trait]
+    impl GetBlockHash for FakeChain {
+        type Error = eyre::Error;
+
+        async fn get_block_hash(&self, block_number: u64) -> eyre::Result<Digest> {
+            let blocks = self.blocks.lock().unwrap();
+            let (hash, _) = blocks
+                .get(block_number as usize)
+                .ok_or_else(|| eyre::eyre!("block {block_number} not found"))?;
+            Ok(*hash)
+        }
+    }
+
+    #[async_trait]
+    impl GetBridgeEvents for FakeChain {
+        type Error = eyre::Error;
+
+        async fn get_bridge_events(
+            &self,
+            bridge_address: Address,
+            from_block: u64,
+            to_block: u64,
+        ) -> eyre::Result<Vec<BridgeEventLog>> {
+            assert_eq!(bridge_address, BRIDGE_ADDRESS);
+            let blocks = self.blocks.lock().unwrap();
+            eyre::ensure!(
+                (to_block as usize) < blocks.len(),
+                "block {to_block} not found"
+            );
+
+            let mut leaf_index = blocks[..from_block as usize]
+                .iter()
+                .map(|(_, exits)| exits.len() as u32)
+                .sum::<u32>();
+            let mut logs = vec![];
+            for (block_number, (block_hash, exits)) in blocks
+                .iter()
+                .enumerate()
+                .take(to_block as usize + 1)
+                .skip(from_block as usize)
+            {
+                for bridge_exit in exits {
+                    logs.push(BridgeEventLog {
+                        block_number: block_number as u64,
+                        block_hash: *block_hash,
+                        event: BridgeContractEvent::BridgeExit(DecodedBridgeExit {
+                            leaf_index,
+                            bridge_exit: bridge_exit.clone(),
+                        }),
+                    });
+                    leaf_index += 1;
+                }
+            }
+            Ok(logs)
+        }
+    }
+
+    fn assert_roots_match(indexer: &LocalExitTreeIndexer<FakeChain>, blocks: std::ops::Range<u64>) {
+        for block_number in blocks {
+            assert_eq!(
+                indexer.local_exit_root_at(block_number),
+                Some(indexer.rpc.local_exit_root(block_number)),
+                "block {block_number}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn sync_in_pages() {
+        let chain = FakeChain::default();
+        chain.extend(0, &[0, 1, 0, 0, 3, 0, 1, 1, 0, 2, 0, 0, 0, 5, 0, 1]);
+        let mut indexer = LocalExitTreeIndexer::new(chain, BRIDGE_ADDRESS, 0).with_page_size(3);
+
+        assert_eq!(indexer.local_exit_root_at(0), None);
+        indexer.sync(10).await.unwrap();
+        assert_eq!(indexer.synced_to(), Some(10));
+        assert_roots_match(&indexer, 0..11);
+        assert_eq!(indexer.local_exit_root_at(11), None);
+
+        indexer.sync(15).await.unwrap();
+        assert_roots_match(&indexer, 0..16);
+        assert_eq!(indexer.tree().leaf_count(), 14);
+    }
+
+    #[tokio::test]
+    async fn reorgs_are_rewound() {
+        let chain = FakeChain::default();
+        chain.extend(0, &[1, 0, 2, 0, 1, 1, 0, 3, 0, 1]);
+        let mut indexer = LocalExitTreeIndexer::new(chain, BRIDGE_ADDRESS, 0).with_page_size(4);
+        indexer.sync(9).await.unwrap();
+
+        // Replace the last blocks with a longer fork with other exits.
+        indexer.rpc.reorg(5, 1, &[0, 2, 0, 0, 1, 1]);
+        indexer.sync(10).await.unwrap();
+        assert_roots_match(&indexer, 0..11);
+
+        // Deep reorg down to the first block.
+        indexer
+            .rpc
+            .reorg(0, 2, &[3, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        indexer.sync(11).await.unwrap();
+        assert_roots_match(&indexer, 0..12);
+    }
+
+    #[tokio::test]
+    async fn resume_from_checkpoint() {
+        let chain = FakeChain::default();
+        chain.extend(0, &[1, 0, 2, 0, 1, 1, 0, 3, 0, 1, 0, 2]);
+        let mut indexer = LocalExitTreeIndexer::new(chain, BRIDGE_ADDRESS, 0);
+        indexer.sync(6).await.unwrap();
+        let checkpoint = indexer.checkpoint().unwrap();
+        assert_eq!(checkpoint.block_number, 6);
+
+        let mut resumed = LocalExitTreeIndexer::from_checkpoint(
+            indexer.rpc,
+            BRIDGE_ADDRESS,
+            serde_json::from_value(serde_json::to_value(&checkpoint).unwrap()).unwrap(),
+        );
+        assert_eq!(resumed.checkpoint(), Some(checkpoint));
+        resumed.sync(11).await.unwrap();
+        assert_roots_match(&resumed, 6..12);
+        assert_eq!(resumed.local_exit_root_at(5), None);
+
+        // Reorgs after the checkpoint are handled.
+        resumed.rpc.reorg(8, 1, &[1, 1, 1, 1]);
+        resumed.sync(11).await.unwrap();
+        assert_roots_match(&resumed, 6..12);
+
+        // Reorgs of the checkpoint itself cannot be.
+        resumed.rpc.reorg(6, 2, &[0; 6]);
+        assert!(matches!(
+            resumed.sync(11).await,
+            Err(LocalExitTreeIndexerError::ReorgBeyondCheckpoint { block_number: 6 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn resume_from_synthetic_cassette() {
+        // Requests of a sync of the bridge contract from block 0x1406f3f to
+        // 0x1406f41, saved by a `RecordingRpc` over a mocked transport. The
+        // logs are the ones of `bridge_logs.json` and the block headers are
+        // made up, so only the request flow of the indexer is covered, not
+        // the encoding of a real node.
+        let rpc = ReplayRpc::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/synthetic_indexer_cassette.json"
+        ))
+        .unwrap();
+        let logs: Vec<alloy::rpc::types::Log> =
+            serde_json::from_str(include_str!("../tests/fixtures/bridge_logs.json")).unwrap();
+        let logged_exits =
+            logs.iter().filter_map(
+                |log| match BridgeContractEvent::decode(&log.inner).unwrap() {
+                    Some(BridgeContractEvent::BridgeExit(exit)) => Some(exit.bridge_exit.hash()),
+                    _ => None,
+                },
+            );
+
+        // The logged exits start at deposit count 41.
+        let earlier_exits = (0..41).map(|amount| bridge_exit(amount).hash());
+        let checkpoint = LocalExitTreeCheckpoint {
+            block_number: 0x1406f3f,
+            block_hash: Digest(hex_literal::hex!(
+                "8d1e2f3a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f6"
+            )),
+            tree: LocalExitTree::from_leaves(earlier_exits.clone()).unwrap(),
+        };
+        let mut indexer = LocalExitTreeIndexer::from_checkpoint(
+            rpc,
+            Address::new(hex_literal::hex!(
+                "2a3dd3eb832af982ec71669e178424b10dca2ede"
+            )),
+            checkpoint.clone(),
+        );
+        indexer.sync(0x1406f41).await.unwrap();
+
+        let expected = LocalExitTree::<32>::from_leaves(earlier_exits.chain(logged_exits)).unwrap();
+        assert_eq!(indexer.tree().leaf_count(), 43);
+        assert_eq!(
+            indexer.local_exit_root_at(0x1406f41),
+            Some(LocalExitRoot::new(expected.get_root()))
+        );
+        assert_eq!(
+            indexer.local_exit_root_at(0x1406f3f),
+            Some(LocalExitRoot::new(checkpoint.tree.get_root()))
+        );
+
+        // Syncing again only checks that the last indexed block is canonical.
+        indexer.sync(0x1406f41).await.unwrap();
+        assert_eq!(indexer.tree(), &expected);
+        assert!(indexer.rpc.remaining().is_empty());
+    }
+
+    #[tokio::test]
+    async fn missing_exits_are_detected() {
+        let mut rpc = MockRpc::new();
+        rpc.expect_get_bridge_events().returning(|_, _, _| {
+            Ok(vec![BridgeEventLog {
+                block_number: 3,
+                block_hash: Digest([0x03; 32]),
+                event: BridgeContractEvent::BridgeExit(DecodedBridgeExit {
+                    leaf_index: 2,
+                    bridge_exit: bridge_exit(1),
+                }),
+            }])
+        });
+        rpc.expect_get_block_hash()
+            .times(1)
+            .returning(|_| Ok(Digest([0x05; 32])));
+        let mut indexer = LocalExitTreeIndexer::new(rpc, BRIDGE_ADDRESS, 0);
+
+        assert!(matches!(
+            indexer.sync(5).await,
+            Err(LocalExitTreeIndexerError::UnexpectedLeafIndex {
+                block_number: 3,
+                leaf_index: 2,
+                expected: 0,
+            })
+        ));
+        assert_eq!(indexer.synced_to(), None);
+    }
+
+    #[tokio::test]
+    async fn rpc_errors_are_forwarded() {
+        let mut rpc = MockRpc::new();
+        rpc.expect_get_bridge_events()
+            .returning(|_, _, _| Err(eyre::eyre!("connection refused")));
+        rpc.expect_get_block_hash()
+            .returning(|_| Ok(Digest([0x14; 32])));
+        let mut indexer = LocalExitTreeIndexer::new(rpc, BRIDGE_ADDRESS, 10);
+
+        let err = indexer.sync(20).await.unwrap_err();
+        assert!(matches!(
+            err,
+            LocalExitTreeIndexerError::GettingEvents {
+                from_block: 10,
+                to_block: 20,
+                ..
+            }
+        ));
+        assert_eq!(indexer.synced_to(), Some(9));
+    }
+
+    #[tokio::test]
+    async fn reorgs_while_indexing_are_detected() {
+        let mut rpc = MockRpc::new();
+        let calls = AtomicU8::new(0);
+        rpc.expect_get_block_hash().returning(move |_| {
+            // The head block changes between the two lookups of the page.
+            Ok(Digest([calls.fetch_add(1, Ordering::Relaxed); 32]))
+        });
+        rpc.expect_get_bridge_events()
+            .returning(|_, _, _| Ok(vec![]));
+        let mut indexer = LocalExitTreeIndexer::new(rpc, BRIDGE_ADDRESS, 0);
+
+        assert!(matches!(
+            indexer.sync(5).await,
+            Err(LocalExitTreeIndexerError::ReorgWhileIndexing {
+                from_block: 0,
+                to_block: 5,
+            })
+        ));
+        assert_eq!(indexer.synced_to(), None);
+    }
+
+    #[tokio::test]
+    async fn finalized_blocks_are_pruned() {
+        let chain = FakeChain::default();
+        chain.extend(0, &[1, 0, 2, 0, 1, 1, 0, 3, 0, 1, 0, 2]);
+        let mut indexer = LocalExitTreeIndexer::new(chain, BRIDGE_ADDRESS, 0).with_page_size(4);
+        indexer.sync(9).await.unwrap();
+
+        // Only the hashes of the blocks with exits and of the page ends are
+        // known, so the pruning stops at block 5.
+        indexer.prune_finalized(6);
+        assert_eq!(indexer.trees.keys().collect::<Vec<_>>(), [&7, &9]);
+        assert_eq!(indexer.block_hashes.keys().collect::<Vec<_>>(), [&7, &9]);
+        assert_eq!(indexer.local_exit_root_at(4), None);
+        assert_roots_match(&indexer, 5..10);
+
+        // Reorgs after the finalized block are still handled.
+        indexer.rpc.reorg(7, 1, &[2, 0, 1, 1, 1]);
+        indexer.sync(11).await.unwrap();
+        assert_roots_match(&indexer, 5..12);
+
+        indexer.prune_finalized(11);
+        assert!(indexer.trees.is_empty());
+        assert_eq!(indexer.synced_to(), Some(11));
+        assert_roots_match(&indexer, 11..12);
+        assert_eq!(
+            indexer
+                .checkpoint()
+                .map(|checkpoint| checkpoint.block_number),
+            Some(11)
+        );
+    }
+}
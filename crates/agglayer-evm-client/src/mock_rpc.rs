@@ -1,21 +1,136 @@
-use agglayer_primitives::Digest;
+use agglayer_primitives::{vkey_hash::VKeyHash, Address, Digest};
+use agglayer_tries::roots::{L1InfoRoot, LocalExitRoot};
 use async_trait::async_trait;
 use mockall::mock;
+use unified_bridge::{L1InfoTreeLeaf, RollupId};
 
-use crate::{GetBlockHash, GetBlockNumber};
+use crate::{
+    BlockHeader, BlockRef, BlockTag, BridgeEventLog, GetAggchainVKey, GetBlockHash, GetBlockHeader,
+    GetBlockNumber, GetBridgeEvents, GetL1InfoRoot, GetL1InfoTreeLeaf, GetLatestGlobalExitRoot,
+    GetRollupData, GetRollupType, GetTaggedBlock, GetVerifiedExitRoots, GetVerifiedStorage,
+    IsCanonical, RollupData, RollupType, VerifiedExitRoots,
+};
 
 mock! {
     pub Rpc {}
 
+    #[async_trait]
+    impl GetAggchainVKey for Rpc {
+        type Error = eyre::Error;
+        async fn get_aggchain_vkey(
+            &self,
+            aggchain_address: Address,
+            selector: [u8; 4],
+        ) -> eyre::Result<VKeyHash>;
+    }
+
     #[async_trait]
     impl GetBlockHash for Rpc {
         type Error = eyre::Error;
         async fn get_block_hash(&self, block_number: u64) -> eyre::Result<Digest>;
     }
 
+    #[async_trait]
+    impl GetBlockHeader for Rpc {
+        type Error = eyre::Error;
+        async fn get_block_header(&self, block_number: u64) -> eyre::Result<BlockHeader>;
+    }
+
     #[async_trait]
     impl GetBlockNumber for Rpc {
         type Error = eyre::Error;
         async fn get_block_number(&self, block_hash: Digest) -> eyre::Result<u64>;
     }
+
+    #[async_trait]
+    impl GetBridgeEvents for Rpc {
+        type Error = eyre::Error;
+        async fn get_bridge_events(
+            &self,
+            bridge_address: Address,
+            from_block: u64,
+            to_block: u64,
+        ) -> eyre::Result<Vec<BridgeEventLog>>;
+    }
+
+    #[async_trait]
+    impl GetL1InfoTreeLeaf for Rpc {
+        type Error = eyre::Error;
+        async fn get_l1_info_tree_leaf(
+            &self,
+            ger_address: Address,
+            l1_info_tree_index: u32,
+            from_block: u64,
+        ) -> eyre::Result<L1InfoTreeLeaf>;
+    }
+
+    #[async_trait]
+    impl GetL1InfoRoot for Rpc {
+        type Error = eyre::Error;
+        async fn get_l1_info_root(&self, ger_address: Address) -> eyre::Result<L1InfoRoot>;
+    }
+
+    #[async_trait]
+    impl GetLatestGlobalExitRoot for Rpc {
+        type Error = eyre::Error;
+        async fn get_latest_global_exit_root(&self, ger_address: Address) -> eyre::Result<Digest>;
+    }
+
+    #[async_trait]
+    impl GetRollupData for Rpc {
+        type Error = eyre::Error;
+        async fn get_rollup_data(
+            &self,
+            rollup_manager_address: Address,
+            rollup_id: RollupId,
+        ) -> eyre::Result<RollupData>;
+    }
+
+    #[async_trait]
+    impl GetRollupType for Rpc {
+        type Error = eyre::Error;
+        async fn get_rollup_type(
+            &self,
+            rollup_manager_address: Address,
+            rollup_type_id: u32,
+        ) -> eyre::Result<RollupType>;
+    }
+
+    #[async_trait]
+    impl GetVerifiedStorage for Rpc {
+        type Error = eyre::Error;
+        async fn get_verified_storage(
+            &self,
+            address: Address,
+            slots: &[Digest],
+            block_hash: Digest,
+        ) -> eyre::Result<Vec<Digest>>;
+    }
+
+    #[async_trait]
+    impl GetVerifiedExitRoots for Rpc {
+        type Error = eyre::Error;
+        async fn get_verified_local_exit_root(
+            &self,
+            bridge_address: Address,
+            block_hash: Digest,
+        ) -> eyre::Result<LocalExitRoot>;
+        async fn get_verified_exit_roots(
+            &self,
+            ger_address: Address,
+            block_hash: Digest,
+        ) -> eyre::Result<VerifiedExitRoots>;
+    }
+
+    #[async_trait]
+    impl GetTaggedBlock for Rpc {
+        type Error = eyre::Error;
+        async fn get_tagged_block(&self, tag: BlockTag) -> eyre::Result<BlockRef>;
+    }
+
+    #[async_trait]
+    impl IsCanonical for Rpc {
+        type Error = eyre::Error;
+        async fn is_canonical(&self, block: BlockRef) -> eyre::Result<bool>;
+    }
 }
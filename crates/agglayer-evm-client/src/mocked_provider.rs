@@ -0,0 +1,27 @@
+use alloy::{providers::RootProvider, rpc::client::RpcClient, transports::mock::Asserter};
+
+use crate::{
+    alloy_rpc::{fill_provider, AlloyFillProvider},
+    AlloyRpc,
+};
+
+/// [`AlloyRpc`] answering the requests with the responses queued in its
+/// [`Asserter`].
+pub(crate) struct MockedProvider {
+    pub(crate) asserter: Asserter,
+    provider: AlloyFillProvider,
+}
+
+impl MockedProvider {
+    pub(crate) fn new() -> Self {
+        let asserter = Asserter::new();
+        let provider = fill_provider(RootProvider::new(RpcClient::mocked(asserter.clone())));
+        Self { asserter, provider }
+    }
+}
+
+impl AlloyRpc for MockedProvider {
+    fn alloy_rpc(&self) -> &AlloyFillProvider {
+        &self.provider
+    }
+}
@@ -0,0 +1,72 @@
+use alloy::sol;
+
+sol! {
+    /// Getters of the `PolygonRollupManager` contract.
+    #[sol(rpc)]
+    #[derive(Debug, Default, PartialEq, Eq)]
+    contract PolygonRollupManager {
+        /// The verifier type is an enum on the contract side, kept as a
+        /// `uint8` to report unknown values.
+        struct RollupDataReturnV2 {
+            address rollupContract;
+            uint64 chainID;
+            address verifier;
+            uint64 forkID;
+            bytes32 lastLocalExitRoot;
+            uint64 lastBatchSequenced;
+            uint64 lastVerifiedBatch;
+            uint64 _legacyLastPendingState;
+            uint64 _legacyLastPendingStateConsolidated;
+            uint64 lastVerifiedBatchBeforeUpgrade;
+            uint64 rollupTypeID;
+            uint8 rollupVerifierType;
+            bytes32 lastPessimisticRoot;
+            bytes32 programVKey;
+        }
+
+        function rollupIDToRollupDataV2(uint32 rollupID)
+            external
+            view
+            returns (RollupDataReturnV2 memory rollupData);
+
+        function rollupTypeMap(uint32 rollupTypeID)
+            external
+            view
+            returns (
+                address consensusImplementation,
+                address verifier,
+                uint64 forkID,
+                uint8 rollupVerifierType,
+                bool obsolete,
+                bytes32 genesis,
+                bytes32 programVKey
+            );
+    }
+}
+
+/// How the state transitions of a rollup are verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifierType {
+    StateTransition,
+    Pessimistic,
+    /// Verified through the aggchain proofs of the agglayer gateway.
+    AggchainGateway,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid verifier type {0}")]
+pub struct InvalidVerifierTypeError(pub u8);
+
+impl TryFrom<u8> for VerifierType {
+    type Error = InvalidVerifierTypeError;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::StateTransition),
+            1 => Ok(Self::Pessimistic),
+            2 => Ok(Self::AggchainGateway),
+            _ => Err(InvalidVerifierTypeError(value)),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use agglayer_primitives::{keccak::keccak256, Address, Digest};
+use alloy::{
+    rpc::types::EIP1186AccountProofResponse,
+    trie::{
+        proof::{verify_proof, ProofVerificationError},
+        Nibbles, TrieAccount, EMPTY_ROOT_HASH, KECCAK_EMPTY,
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum StorageProofError {
+    #[error("Proof is for account {got} instead of {expected}")]
+    UnexpectedAccount { expected: Address, got: Address },
+
+    #[error("Invalid proof of account {address}")]
+    InvalidAccountProof {
+        address: Address,
+        source: Box<ProofVerificationError>,
+    },
+
+    #[error("Proof is for storage slot {got:?} instead of {expected}")]
+    UnexpectedSlot {
+        expected: Digest,
+        got: Option<Digest>,
+    },
+
+    #[error("Invalid proof of storage slot {slot}")]
+    InvalidStorageProof {
+        slot: Digest,
+        source: Box<ProofVerificationError>,
+    },
+}
+
+/// Verifies an `eth_getProof` response against the state root of a block,
+/// and returns the proven values of the given storage slots.
+///
+/// The account is proven against the state root, and each storage slot
+/// against the storage root of the proven account. The response has to
+/// carry the storage proofs in the order of `slots`.
+pub fn verify_storage_proof(
+    state_root: Digest,
+    address: Address,
+    slots: &[Digest],
+    proof: &EIP1186AccountProofResponse,
+) -> Result<Vec<Digest>, StorageProofError> {
+    let got = Address::from(proof.address);
+    if got != address {
+        return Err(StorageProofError::UnexpectedAccount {
+            expected: address,
+            got,
+        });
+    }
+
+    // Accounts missing from the state trie are reported with default fields.
+    let account = TrieAccount {
+        nonce: proof.nonce,
+        balance: proof.balance,
+        storage_root: proof.storage_hash,
+        code_hash: proof.code_hash,
+    };
+    let is_missing = account.nonce == 0
+        && account.balance.is_zero()
+        && account.storage_root == EMPTY_ROOT_HASH
+        && account.code_hash == KECCAK_EMPTY;
+    verify_proof(
+        state_root.into(),
+        Nibbles::unpack(keccak256(address.as_slice())),
+        (!is_missing).then(|| alloy::rlp::encode(account)),
+        &proof.account_proof,
+    )
+    .map_err(|source| StorageProofError::InvalidAccountProof {
+        address,
+        source: Box::new(source),
+    })?;
+
+    slots
+        .iter()
+        .enumerate()
+        .map(|(index, &slot)| {
+            let storage_proof = proof.storage_proof.get(index);
+            let got = storage_proof.map(|storage_proof| Digest::from(storage_proof.key.as_b256()));
+            let storage_proof = storage_proof.filter(|_| got == Some(slot)).ok_or(
+                StorageProofError::UnexpectedSlot {
+                    expected: slot,
+                    got,
+                },
+            )?;
+
+            // Zero values are not stored in the storage trie.
+            let value = storage_proof.value;
+            verify_proof(
+                account.storage_root,
+                Nibbles::unpack(keccak256(slot.as_slice())),
+                (!value.is_zero()).then(|| alloy::rlp::encode(value)),
+                &storage_proof.proof,
+            )
+            .map_err(|source| StorageProofError::InvalidStorageProof {
+                slot,
+                source: Box::new(source),
+            })?;
+
+            Ok(Digest::from(value.to_be_bytes()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use agglayer_primitives::{B256, U256};
+    use serde::Deserialize;
+
+    use super::*;
+
+    /// `eth_getProof` responses for the bridge and global exit root manager
+    /// contracts, along with the state root they are proven against.
+    ///
+    /// They are built from a made-up state rather than taken from a node, so
+    /// they check the proof verification but neither the encoding of a real
+    /// node nor the storage layout of the deployed contracts.
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(crate) struct SyntheticProofs {
+        pub(crate) state_root: Digest,
+        pub(crate) bridge: EIP1186AccountProofResponse,
+        pub(crate) global_exit_root_manager: EIP1186AccountProofResponse,
+    }
+
+    pub(crate) fn synthetic_proofs() -> SyntheticProofs {
+        serde_json::from_str(include_str!(
+            "../tests/fixtures/synthetic_storage_proofs.json"
+        ))
+        .unwrap()
+    }
+
+    fn slots(proof: &EIP1186AccountProofResponse) -> Vec<Digest> {
+        proof
+            .storage_proof
+            .iter()
+            .map(|storage_proof| storage_proof.key.as_b256().into())
+            .collect()
+    }
+
+    #[test]
+    fn synthetic_proofs_are_valid() {
+        let proofs = synthetic_proofs();
+        let ger = &proofs.global_exit_root_manager;
+
+        let values =
+            verify_storage_proof(proofs.state_root, ger.address.into(), &slots(ger), ger).unwrap();
+        assert_eq!(values, [Digest([0x11; 32]), Digest([0x22; 32])]);
+
+        // Slots which were never written are proven to be zero.
+        let bridge = &proofs.bridge;
+        let values = verify_storage_proof(
+            proofs.state_root,
+            bridge.address.into(),
+            &slots(bridge),
+            bridge,
+        )
+        .unwrap();
+        assert_eq!(values.len(), 33);
+        assert!(values.contains(&Digest::ZERO));
+        assert_eq!(values[32], Digest::from(U256::from(5).to_be_bytes()));
+    }
+
+    #[test]
+    fn tampered_values_are_rejected() {
+        let proofs = synthetic_proofs();
+        let mut ger = proofs.global_exit_root_manager;
+        let slots = slots(&ger);
+        ger.storage_proof[1].value = U256::from_be_bytes([0x33; 32]);
+
+        assert!(matches!(
+            verify_storage_proof(proofs.state_root, ger.address.into(), &slots, &ger),
+            Err(StorageProofError::InvalidStorageProof { slot, .. }) if slot == slots[1]
+        ));
+    }
+
+    #[test]
+    fn tampered_accounts_are_rejected() {
+        let proofs = synthetic_proofs();
+        let mut ger = proofs.global_exit_root_manager;
+        let slots = slots(&ger);
+        ger.storage_hash = B256::repeat_byte(0x42);
+
+        assert!(matches!(
+            verify_storage_proof(proofs.state_root, ger.address.into(), &slots, &ger),
+            Err(StorageProofError::InvalidAccountProof { .. })
+        ));
+
+        let bridge = proofs.bridge;
+        assert!(matches!(
+            verify_storage_proof(proofs.state_root, bridge.address.into(), &slots, &bridge),
+            Err(StorageProofError::UnexpectedSlot { .. })
+        ));
+    }
+
+    #[test]
+    fn proofs_are_bound_to_the_state_root() {
+        let proofs = synthetic_proofs();
+        let ger = &proofs.global_exit_root_manager;
+
+        assert!(matches!(
+            verify_storage_proof(Digest([0x42; 32]), ger.address.into(), &slots(ger), ger),
+            Err(StorageProofError::InvalidAccountProof { .. })
+        ));
+        assert!(matches!(
+            verify_storage_proof(proofs.state_root, Address::ZERO, &slots(ger), ger),
+            Err(StorageProofError::UnexpectedAccount { .. })
+        ));
+    }
+}
@@ -0,0 +1,555 @@
+use std::time::Duration;
+
+use agglayer_primitives::{Address, Digest};
+use alloy::{
+    eips::Encodable2718 as _,
+    network::{Ethereum, EthereumWallet, NetworkWallet, TransactionBuilder as _},
+    primitives::TxHash,
+    providers::{Provider as _, SendableTx},
+    rpc::types::{TransactionReceipt, TransactionRequest},
+};
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+use crate::AlloyRpc;
+
+/// Parameters of the tracking and replacement of the submitted transactions.
+#[derive(Debug, Clone)]
+pub struct SubmissionConfig {
+    /// Number of blocks, including the one the transaction is included in,
+    /// after which the transaction is considered settled.
+    pub confirmations: u64,
+    pub poll_interval: Duration,
+    /// Time after which a pending transaction is replaced with higher fees.
+    pub bump_after: Duration,
+    /// Increase of the fees of each replacement, in percent. Nodes require
+    /// at least 10% to accept a replacement.
+    pub fee_bump_percent: u64,
+    /// Number of replacements after which a pending transaction is reported
+    /// as stuck.
+    pub max_bumps: u32,
+}
+
+impl Default for SubmissionConfig {
+    fn default() -> Self {
+        Self {
+            confirmations: 3,
+            poll_interval: Duration::from_secs(4),
+            bump_after: Duration::from_secs(60),
+            fee_bump_percent: 20,
+            max_bumps: 5,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubmitTransactionError {
+    #[error("Filling transaction")]
+    Filling { source: eyre::Error },
+
+    #[error("Signing transaction")]
+    Signing { source: eyre::Error },
+
+    #[error("Sending transaction")]
+    Sending { source: eyre::Error },
+
+    #[error("Tracking transaction {tx_hash}")]
+    Tracking {
+        tx_hash: Digest,
+        source: eyre::Error,
+    },
+
+    #[error("Transaction {tx_hash} reverted in block {block_number:?}")]
+    Reverted {
+        tx_hash: Digest,
+        block_number: Option<u64>,
+    },
+
+    #[error("Transaction {tx_hash} was dropped from the mempool")]
+    Dropped { tx_hash: Digest },
+
+    #[error("Nonce {nonce} of {sender} was used by another transaction")]
+    Replaced { sender: Address, nonce: u64 },
+
+    #[error("Transaction {tx_hash} still pending after {bumps} fee bumps")]
+    Stuck { tx_hash: Digest, bumps: u32 },
+}
+
+/// Builds, signs and sends transactions, and tracks them until they are
+/// settled.
+///
+/// Pending transactions are replaced with higher fees, keeping their nonce,
+/// once they have been waiting for [`SubmissionConfig::bump_after`].
+pub struct TransactionSubmitter<R> {
+    rpc: R,
+    wallet: EthereumWallet,
+    config: SubmissionConfig,
+}
+
+/// Transactions sent for a single nonce.
+struct Submission {
+    nonce: u64,
+    request: TransactionRequest,
+    /// Hashes of the transaction and its replacements, the last one being
+    /// the most recent.
+    tx_hashes: Vec<TxHash>,
+    sent_at: Instant,
+    bumps: u32,
+}
+
+impl Submission {
+    fn last_tx_hash(&self) -> Digest {
+        (*self.tx_hashes.last().unwrap()).into()
+    }
+}
+
+impl<R: AlloyRpc> TransactionSubmitter<R> {
+    pub fn new(rpc: R, wallet: EthereumWallet) -> Self {
+        Self {
+            rpc,
+            wallet,
+            config: SubmissionConfig::default(),
+        }
+    }
+
+    pub fn with_config(mut self, config: SubmissionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Address the transactions are sent from.
+    pub fn sender(&self) -> Address {
+        self.signer_address().into()
+    }
+
+    fn signer_address(&self) -> alloy::primitives::Address {
+        NetworkWallet::<Ethereum>::default_signer_address(&self.wallet)
+    }
+
+    /// Submits the transaction and waits until it has the configured number
+    /// of confirmations.
+    ///
+    /// The missing gas, fees, nonce and chain id are filled by the provider.
+    pub async fn submit(
+        &self,
+        request: TransactionRequest,
+    ) -> Result<TransactionReceipt, SubmitTransactionError> {
+        let request = request.with_from(self.signer_address());
+        let request = match self.rpc.alloy_rpc().fill(request).await.map_err(|source| {
+            SubmitTransactionError::Filling {
+                source: source.into(),
+            }
+        })? {
+            SendableTx::Builder(request) => request,
+            SendableTx::Envelope(_) => unreachable!("the provider has no wallet filler"),
+        };
+        let nonce = request
+            .nonce
+            .ok_or_else(|| SubmitTransactionError::Filling {
+                source: eyre::eyre!("nonce not filled"),
+            })?;
+
+        let tx_hash = self.send(request.clone()).await?;
+        info!(%tx_hash, nonce, "Sent transaction");
+
+        let mut submission = Submission {
+            nonce,
+            request,
+            tx_hashes: vec![tx_hash],
+            sent_at: Instant::now(),
+            bumps: 0,
+        };
+        loop {
+            tokio::time::sleep(self.config.poll_interval).await;
+            if let Some(receipt) = self.track(&mut submission).await? {
+                return Ok(receipt);
+            }
+        }
+    }
+
+    async fn send(&self, request: TransactionRequest) -> Result<TxHash, SubmitTransactionError> {
+        let envelope = NetworkWallet::<Ethereum>::sign_request(&self.wallet, request)
+            .await
+            .map_err(|source| SubmitTransactionError::Signing {
+                source: source.into(),
+            })?;
+        let pending = self
+            .rpc
+            .alloy_rpc()
+            .send_raw_transaction(&envelope.encoded_2718())
+            .await
+            .map_err(|source| SubmitTransactionError::Sending {
+                source: source.into(),
+            })?;
+        if pending.tx_hash() != envelope.tx_hash() {
+            return Err(SubmitTransactionError::Sending {
+                source: eyre::eyre!(
+                    "node reported hash {} for transaction {}",
+                    pending.tx_hash(),
+                    envelope.tx_hash()
+                ),
+            });
+        }
+        Ok(*envelope.tx_hash())
+    }
+
+    /// Returns the first receipt found among the sent transactions.
+    async fn receipt(
+        &self,
+        submission: &Submission,
+    ) -> Result<Option<TransactionReceipt>, SubmitTransactionError> {
+        for tx_hash in submission.tx_hashes.iter().rev() {
+            let receipt = self
+                .rpc
+                .alloy_rpc()
+                .get_transaction_receipt(*tx_hash)
+                .await
+                .map_err(|source| SubmitTransactionError::Tracking {
+                    tx_hash: (*tx_hash).into(),
+                    source: source.into(),
+                })?;
+            if receipt.is_some() {
+                return Ok(receipt);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Checks the progress of the submission, returning the receipt once
+    /// the transaction is settled.
+    async fn track(
+        &self,
+        submission: &mut Submission,
+    ) -> Result<Option<TransactionReceipt>, SubmitTransactionError> {
+        let tx_hash = submission.last_tx_hash();
+        let tracking =
+            |source: alloy::transports::TransportError| SubmitTransactionError::Tracking {
+                tx_hash,
+                source: source.into(),
+            };
+
+        if let Some(receipt) = self.receipt(submission).await? {
+            if !receipt.status() {
+                return Err(SubmitTransactionError::Reverted {
+                    tx_hash: receipt.transaction_hash.into(),
+                    block_number: receipt.block_number,
+                });
+            }
+            // Receipts are only returned for mined transactions.
+            let included_in = receipt.block_number.unwrap_or(u64::MAX);
+            let latest = self
+                .rpc
+                .alloy_rpc()
+                .get_block_number()
+                .await
+                .map_err(tracking)?;
+            let confirmations = (latest + 1).saturating_sub(included_in);
+            debug!(%tx_hash, confirmations, "Transaction included");
+            return Ok((confirmations >= self.config.confirmations).then_some(receipt));
+        }
+
+        let mined_nonce = self
+            .rpc
+            .alloy_rpc()
+            .get_transaction_count(self.signer_address())
+            .await
+            .map_err(tracking)?;
+        if mined_nonce > submission.nonce {
+            // One of the sent transactions may have been mined since the
+            // receipts were checked.
+            if self.receipt(submission).await?.is_some() {
+                return Ok(None);
+            }
+            return Err(SubmitTransactionError::Replaced {
+                sender: self.sender(),
+                nonce: submission.nonce,
+            });
+        }
+
+        if submission.sent_at.elapsed() >= self.config.bump_after {
+            if submission.bumps == self.config.max_bumps {
+                return Err(SubmitTransactionError::Stuck {
+                    tx_hash,
+                    bumps: submission.bumps,
+                });
+            }
+            self.bump(submission).await;
+            return Ok(None);
+        }
+
+        let pending = self
+            .rpc
+            .alloy_rpc()
+            .get_transaction_by_hash(tx_hash.into())
+            .await
+            .map_err(tracking)?;
+        if pending.is_none() {
+            return Err(SubmitTransactionError::Dropped { tx_hash });
+        }
+        Ok(None)
+    }
+
+    /// Replaces the pending transaction with one paying higher fees.
+    async fn bump(&self, submission: &mut Submission) {
+        let bump = |fee: u128| {
+            fee.saturating_mul(100 + u128::from(self.config.fee_bump_percent))
+                .div_ceil(100)
+        };
+        let request = &mut submission.request;
+        request.gas_price = request.gas_price.map(bump);
+        request.max_fee_per_gas = request.max_fee_per_gas.map(bump);
+        request.max_priority_fee_per_gas = request.max_priority_fee_per_gas.map(bump);
+        submission.bumps += 1;
+        submission.sent_at = Instant::now();
+
+        // Failing to send the replacement is not fatal, as the original
+        // transaction may have been mined in the meantime.
+        match self.send(submission.request.clone()).await {
+            Ok(tx_hash) => {
+                info!(
+                    %tx_hash,
+                    nonce = submission.nonce,
+                    bumps = submission.bumps,
+                    "Sent replacement transaction"
+                );
+                submission.tx_hashes.push(tx_hash);
+            }
+            Err(error) => warn!(
+                nonce = submission.nonce,
+                "Failed to send replacement transaction: {error:?}"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        consensus::{Receipt, ReceiptEnvelope, ReceiptWithBloom, TxEnvelope},
+        primitives::{Bloom, B256, U256},
+        rpc::types::Transaction,
+        signers::local::PrivateKeySigner,
+    };
+
+    use super::*;
+    use crate::mocked_provider::MockedProvider;
+
+    const NONCE: u64 = 7;
+    const POLL_INTERVAL: Duration = Duration::from_secs(4);
+
+    fn signer() -> PrivateKeySigner {
+        PrivateKeySigner::from_bytes(&B256::repeat_byte(0x01)).unwrap()
+    }
+
+    fn submitter(config: SubmissionConfig) -> TransactionSubmitter<MockedProvider> {
+        TransactionSubmitter::new(MockedProvider::new(), EthereumWallet::from(signer()))
+            .with_config(config)
+    }
+
+    fn config() -> SubmissionConfig {
+        SubmissionConfig {
+            confirmations: 3,
+            poll_interval: POLL_INTERVAL,
+            bump_after: 10 * POLL_INTERVAL,
+            fee_bump_percent: 20,
+            max_bumps: 1,
+        }
+    }
+
+    /// Settlement transaction with all the fields the fillers would query.
+    fn request() -> TransactionRequest {
+        TransactionRequest::default()
+            .with_from(signer().address())
+            .with_to(alloy::primitives::Address::repeat_byte(0x5e))
+            .with_input(vec![0xca, 0xfe])
+            .with_nonce(NONCE)
+            .with_chain_id(1)
+            .with_gas_limit(100_000)
+            .with_max_fee_per_gas(30_000_000_000)
+            .with_max_priority_fee_per_gas(1_000_000_000)
+    }
+
+    async fn signed(request: TransactionRequest) -> TxEnvelope {
+        NetworkWallet::<Ethereum>::sign_request(&EthereumWallet::from(signer()), request)
+            .await
+            .unwrap()
+    }
+
+    fn receipt(tx_hash: TxHash, block_number: u64, status: bool) -> TransactionReceipt {
+        TransactionReceipt {
+            inner: ReceiptEnvelope::Eip1559(ReceiptWithBloom {
+                receipt: Receipt {
+                    status: status.into(),
+                    cumulative_gas_used: 50_000,
+                    logs: Vec::new(),
+                },
+                logs_bloom: Bloom::ZERO,
+            }),
+            transaction_hash: tx_hash,
+            transaction_index: Some(0),
+            block_hash: Some(B256::repeat_byte(0xbb)),
+            block_number: Some(block_number),
+            gas_used: 50_000,
+            effective_gas_price: 10_000_000_000,
+            blob_gas_used: None,
+            blob_gas_price: None,
+            from: signer().address(),
+            to: request().to.and_then(|to| to.to().copied()),
+            contract_address: None,
+        }
+    }
+
+    fn pending(envelope: TxEnvelope) -> Transaction {
+        Transaction {
+            inner: alloy::consensus::transaction::Recovered::new_unchecked(
+                envelope,
+                signer().address(),
+            ),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+            block_timestamp: None,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn settles_after_confirmations() {
+        let submitter = submitter(config());
+        let envelope = signed(request()).await;
+        let tx_hash = *envelope.tx_hash();
+        let asserter = &submitter.rpc.asserter;
+
+        asserter.push_success(&tx_hash);
+        // Still pending.
+        asserter.push_success(&());
+        asserter.push_success(&U256::from(NONCE));
+        asserter.push_success(&pending(envelope));
+        // Included, then confirmed.
+        for latest in [100, 102] {
+            asserter.push_success(&receipt(tx_hash, 100, true));
+            asserter.push_success(&U256::from(latest));
+        }
+
+        let settled = submitter.submit(request()).await.unwrap();
+        assert_eq!(settled.transaction_hash, tx_hash);
+        assert_eq!(settled.block_number, Some(100));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reverts_are_reported() {
+        let submitter = submitter(config());
+        let tx_hash = *signed(request()).await.tx_hash();
+        let asserter = &submitter.rpc.asserter;
+
+        asserter.push_success(&tx_hash);
+        asserter.push_success(&receipt(tx_hash, 100, false));
+
+        assert!(matches!(
+            submitter.submit(request()).await,
+            Err(SubmitTransactionError::Reverted {
+                tx_hash: reverted,
+                block_number: Some(100),
+            }) if reverted == tx_hash.into()
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn dropped_transactions_are_reported() {
+        let submitter = submitter(config());
+        let tx_hash = *signed(request()).await.tx_hash();
+        let asserter = &submitter.rpc.asserter;
+
+        asserter.push_success(&tx_hash);
+        asserter.push_success(&());
+        asserter.push_success(&U256::from(NONCE));
+        asserter.push_success(&());
+
+        assert!(matches!(
+            submitter.submit(request()).await,
+            Err(SubmitTransactionError::Dropped { tx_hash: dropped }) if dropped == tx_hash.into()
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn replacements_by_others_are_reported() {
+        let submitter = submitter(config());
+        let tx_hash = *signed(request()).await.tx_hash();
+        let asserter = &submitter.rpc.asserter;
+
+        asserter.push_success(&tx_hash);
+        asserter.push_success(&());
+        // The nonce was used, but not by the sent transaction.
+        asserter.push_success(&U256::from(NONCE + 1));
+        asserter.push_success(&());
+
+        assert!(matches!(
+            submitter.submit(request()).await,
+            Err(SubmitTransactionError::Replaced { nonce: NONCE, .. })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stuck_transactions_are_bumped() {
+        let submitter = submitter(SubmissionConfig {
+            bump_after: POLL_INTERVAL,
+            confirmations: 1,
+            ..config()
+        });
+        let tx_hash = *signed(request()).await.tx_hash();
+        let replacement = signed(
+            request()
+                .with_max_fee_per_gas(36_000_000_000)
+                .with_max_priority_fee_per_gas(1_200_000_000),
+        )
+        .await;
+        let replacement_hash = *replacement.tx_hash();
+        let asserter = &submitter.rpc.asserter;
+
+        asserter.push_success(&tx_hash);
+        // Pending for too long, replaced with 20% higher fees.
+        asserter.push_success(&());
+        asserter.push_success(&U256::from(NONCE));
+        asserter.push_success(&replacement_hash);
+        // The replacement is mined.
+        asserter.push_success(&receipt(replacement_hash, 100, true));
+        asserter.push_success(&U256::from(100));
+
+        let settled = submitter.submit(request()).await.unwrap();
+        assert_eq!(settled.transaction_hash, replacement_hash);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn mismatching_hashes_are_rejected() {
+        let submitter = submitter(config());
+        submitter
+            .rpc
+            .asserter
+            .push_success(&B256::repeat_byte(0x42));
+
+        assert!(matches!(
+            submitter.submit(request()).await,
+            Err(SubmitTransactionError::Sending { .. })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stuck_transactions_are_reported_after_max_bumps() {
+        let submitter = submitter(SubmissionConfig {
+            bump_after: POLL_INTERVAL,
+            max_bumps: 0,
+            ..config()
+        });
+        let tx_hash = *signed(request()).await.tx_hash();
+        let asserter = &submitter.rpc.asserter;
+
+        asserter.push_success(&tx_hash);
+        asserter.push_success(&());
+        asserter.push_success(&U256::from(NONCE));
+
+        assert!(matches!(
+            submitter.submit(request()).await,
+            Err(SubmitTransactionError::Stuck { bumps: 0, .. })
+        ));
+    }
+}
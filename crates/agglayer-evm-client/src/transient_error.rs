@@ -0,0 +1,110 @@
+use alloy::transports::TransportError;
+
+use crate::{
+    get_aggchain_vkey::GetAggchainVKeyError, get_block_hash::GetBlockHashError,
+    get_block_header::GetBlockHeaderError, get_block_number::GetBlockNumberError,
+    get_bridge_events::GetBridgeEventsError, get_l1_info_root::GetL1InfoRootError,
+    get_l1_info_tree_leaf::GetL1InfoTreeLeafError,
+    get_latest_global_exit_root::GetLatestGlobalExitRootError, get_rollup_data::GetRollupDataError,
+    get_rollup_type::GetRollupTypeError, get_tagged_block::GetTaggedBlockError,
+    get_verified_exit_roots::GetVerifiedExitRootsError,
+    get_verified_storage::GetVerifiedStorageError, is_canonical::IsCanonicalError,
+};
+
+/// Error which may not happen again when sending the same request to another
+/// endpoint or later on.
+pub trait TransientError {
+    /// Returns whether the request failed to reach the node or to get its
+    /// response back, e.g. because of a connection error or a timeout.
+    ///
+    /// Errors reported by the node itself and invalid responses are not
+    /// transient.
+    fn is_transient(&self) -> bool;
+}
+
+/// Looks for the transport error at the root of an error.
+fn is_transport_error(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut next = Some(error);
+    while let Some(error) = next {
+        if let Some(error) = error.downcast_ref::<TransportError>() {
+            return error.is_transport_error();
+        }
+        // Transparent, so its source is the source of the transport error.
+        if let Some(alloy::contract::Error::TransportError(error)) =
+            error.downcast_ref::<alloy::contract::Error>()
+        {
+            return error.is_transport_error();
+        }
+        next = error.source();
+    }
+    false
+}
+
+impl TransientError for eyre::Error {
+    fn is_transient(&self) -> bool {
+        is_transport_error(self.as_ref())
+    }
+}
+
+macro_rules! impl_transient_error {
+    ($($error:ty),+ $(,)?) => {
+        $(impl TransientError for $error {
+            fn is_transient(&self) -> bool {
+                is_transport_error(self)
+            }
+        })+
+    };
+}
+
+impl_transient_error!(
+    GetAggchainVKeyError,
+    GetBlockHashError,
+    GetBlockHeaderError,
+    GetBlockNumberError,
+    GetBridgeEventsError,
+    GetL1InfoRootError,
+    GetL1InfoTreeLeafError,
+    GetLatestGlobalExitRootError,
+    GetRollupDataError,
+    GetRollupTypeError,
+    GetTaggedBlockError,
+    GetVerifiedExitRootsError,
+    GetVerifiedStorageError,
+    IsCanonicalError,
+);
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::{Address, Digest};
+    use alloy::transports::TransportErrorKind;
+
+    use super::*;
+    use crate::{mocked_provider::MockedProvider, GetBlockHash, GetVerifiedExitRoots};
+
+    #[test]
+    fn transport_errors_are_transient() {
+        assert!(eyre::Error::from(TransportErrorKind::backend_gone()).is_transient());
+        assert!(
+            eyre::Error::from(TransportErrorKind::http_error(503, String::new()))
+                .wrap_err("Getting block")
+                .is_transient()
+        );
+        assert!(!eyre::eyre!("execution reverted").is_transient());
+    }
+
+    #[tokio::test]
+    async fn node_errors_are_not_transient() {
+        let rpc = MockedProvider::new();
+        rpc.asserter.push_failure_msg("header not found");
+        assert!(!rpc.get_block_hash(7).await.unwrap_err().is_transient());
+
+        // Nothing is left to answer, which the mock reports as a transport
+        // error, including when wrapped in the errors of other requests.
+        assert!(rpc.get_block_hash(7).await.unwrap_err().is_transient());
+        assert!(rpc
+            .get_verified_exit_roots(Address::ZERO, Digest::ZERO)
+            .await
+            .unwrap_err()
+            .is_transient());
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use tracing::debug;
+
+use crate::{BlockRef, BlockTag, GetTaggedBlock, IsCanonical};
+
+#[derive(Debug, thiserror::Error)]
+pub enum WaitUntilFinalError {
+    #[error("Getting finalized block")]
+    GettingFinalizedBlock { source: eyre::Error },
+
+    #[error("Checking whether block {block:?} is canonical")]
+    CheckingCanonical {
+        block: BlockRef,
+        source: eyre::Error,
+    },
+
+    #[error("Block {block:?} has been reorged before being finalized")]
+    Reorged { block: BlockRef },
+
+    #[error("Finalized block went back from {previous:?} to {current:?}")]
+    FinalizedBlockRewound {
+        previous: BlockRef,
+        current: BlockRef,
+    },
+}
+
+/// Waits until the given block is finalized, polling the node every
+/// `poll_interval`, and returns the finalized block it was first covered by.
+///
+/// Fails as soon as the block is no longer canonical, or if the finalized
+/// block ever goes backwards, which a node switching to another fork would
+/// do.
+pub async fn wait_until_final<R>(
+    rpc: &R,
+    block: BlockRef,
+    poll_interval: Duration,
+) -> Result<BlockRef, WaitUntilFinalError>
+where
+    R: GetTaggedBlock + IsCanonical + Sync,
+    <R as GetTaggedBlock>::Error: Into<eyre::Error>,
+    <R as IsCanonical>::Error: Into<eyre::Error>,
+{
+    let mut previous: Option<BlockRef> = None;
+    loop {
+        let finalized = rpc
+            .get_tagged_block(BlockTag::Finalized)
+            .await
+            .map_err(|source| WaitUntilFinalError::GettingFinalizedBlock {
+                source: source.into(),
+            })?;
+        if let Some(previous) = previous.filter(|previous| previous.number > finalized.number) {
+            return Err(WaitUntilFinalError::FinalizedBlockRewound {
+                previous,
+                current: finalized,
+            });
+        }
+
+        // Checked after getting the finalized block so that a block reported
+        // as canonical below the finalized one cannot be reorged anymore.
+        let canonical = rpc.is_canonical(block).await.map_err(|source| {
+            WaitUntilFinalError::CheckingCanonical {
+                block,
+                source: source.into(),
+            }
+        })?;
+        if !canonical {
+            return Err(WaitUntilFinalError::Reorged { block });
+        }
+        if finalized.number >= block.number {
+            return Ok(finalized);
+        }
+
+        debug!(
+            block_number = block.number,
+            finalized = finalized.number,
+            "Waiting for block to be finalized"
+        );
+        previous = Some(finalized);
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::Digest;
+    use mockall::Sequence;
+
+    use super::*;
+    use crate::MockRpc;
+
+    const POLL_INTERVAL: Duration = Duration::from_secs(12);
+
+    fn block(number: u64) -> BlockRef {
+        BlockRef {
+            number,
+            hash: Digest([number as u8; 32]),
+        }
+    }
+
+    fn finalized_sequence(mock: &mut MockRpc, numbers: &[u64]) {
+        let mut seq = Sequence::new();
+        for &number in numbers {
+            mock.expect_get_tagged_block()
+                .withf(|tag| *tag == BlockTag::Finalized)
+                .once()
+                .in_sequence(&mut seq)
+                .returning(move |_| Ok(block(number)));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn returns_once_the_block_is_finalized() {
+        let mut mock = MockRpc::new();
+        finalized_sequence(&mut mock, &[8, 9, 10]);
+        mock.expect_is_canonical().times(3).returning(|_| Ok(true));
+
+        let start = tokio::time::Instant::now();
+        assert_eq!(
+            wait_until_final(&mock, block(10), POLL_INTERVAL)
+                .await
+                .unwrap(),
+            block(10)
+        );
+        assert_eq!(start.elapsed(), 2 * POLL_INTERVAL);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reorgs_are_reported() {
+        let mut mock = MockRpc::new();
+        finalized_sequence(&mut mock, &[8, 9]);
+        let mut seq = Sequence::new();
+        mock.expect_is_canonical()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(true));
+        mock.expect_is_canonical()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(false));
+
+        assert!(matches!(
+            wait_until_final(&mock, block(10), POLL_INTERVAL).await,
+            Err(WaitUntilFinalError::Reorged { block }) if block.number == 10
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn finalized_block_going_backwards_is_reported() {
+        let mut mock = MockRpc::new();
+        finalized_sequence(&mut mock, &[9, 7]);
+        mock.expect_is_canonical().once().returning(|_| Ok(true));
+
+        assert!(matches!(
+            wait_until_final(&mock, block(10), POLL_INTERVAL).await,
+            Err(WaitUntilFinalError::FinalizedBlockRewound { previous, current })
+                if previous.number == 9 && current.number == 7
+        ));
+    }
+}
@@ -1 +1,6 @@
+#[macro_use]
 pub mod v1;
+pub mod v2;
+
+#[cfg(all(test, feature = "service"))]
+mod testutils;
@@ -0,0 +1,115 @@
+//! Fixtures shared by the tests of the compat layer.
+
+use std::{collections::BTreeMap, sync::Mutex};
+
+use agglayer_interop_types::{
+    aggchain_proof::AggchainData,
+    certificate::{Certificate, CertificateHeader, CertificateStatus},
+    Address, BridgeExit, CertificateId, Digest, Height, L1InfoTreeLeaf, L1InfoTreeLeafInner,
+    LeafType, LocalExitRoot, NetworkId, Signature, TokenInfo, U256,
+};
+use tonic::Status;
+
+use crate::compat::v1::service::AgglayerService;
+
+/// [`AgglayerService`] keeping the submitted certificates in memory.
+#[derive(Default)]
+pub(crate) struct InMemoryAgglayer {
+    certificates: Mutex<BTreeMap<CertificateId, Certificate>>,
+}
+
+#[tonic::async_trait]
+impl AgglayerService for InMemoryAgglayer {
+    async fn submit_certificate(&self, certificate: Certificate) -> Result<CertificateId, Status> {
+        let mut certificates = self.certificates.lock().unwrap();
+        let certificate_id = Digest([certificates.len() as u8 + 1; 32]);
+        certificates.insert(certificate_id, certificate);
+        Ok(certificate_id)
+    }
+
+    async fn get_certificate_header(
+        &self,
+        certificate_id: CertificateId,
+    ) -> Result<CertificateHeader, Status> {
+        let certificates = self.certificates.lock().unwrap();
+        let certificate = certificates
+            .get(&certificate_id)
+            .ok_or_else(|| Status::not_found("unknown certificate"))?;
+
+        Ok(CertificateHeader {
+            network_id: certificate.network_id,
+            height: certificate.height,
+            epoch_number: None,
+            certificate_index: None,
+            certificate_id,
+            prev_local_exit_root: certificate.prev_local_exit_root,
+            new_local_exit_root: certificate.new_local_exit_root,
+            metadata: certificate.metadata,
+            status: CertificateStatus::InError {
+                error: "not settled in tests".to_owned(),
+            },
+            settlement_tx_hash: None,
+        })
+    }
+
+    async fn get_latest_settled_local_exit_root(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<(Height, LocalExitRoot), Status> {
+        self.certificates
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|certificate| certificate.network_id == network_id)
+            .max_by_key(|certificate| certificate.height)
+            .map(|certificate| (certificate.height, certificate.new_local_exit_root))
+            .ok_or_else(|| Status::not_found("no settled certificate"))
+    }
+
+    async fn get_l1_info_tree_entry(
+        &self,
+        l1_info_tree_index: u32,
+    ) -> Result<L1InfoTreeLeaf, Status> {
+        Ok(l1_info_tree_leaf(l1_info_tree_index))
+    }
+}
+
+pub(crate) fn l1_info_tree_leaf(l1_info_tree_index: u32) -> L1InfoTreeLeaf {
+    L1InfoTreeLeaf {
+        l1_info_tree_index,
+        rer: Digest([0x40; 32]),
+        mer: Digest([0x50; 32]),
+        inner: L1InfoTreeLeafInner {
+            global_exit_root: Digest([0x60; 32]),
+            block_hash: Digest([0x70; 32]),
+            timestamp: 1_700_000_000,
+        },
+    }
+}
+
+pub(crate) fn certificate(height: Height) -> Certificate {
+    Certificate {
+        network_id: NetworkId::new(3),
+        height,
+        prev_local_exit_root: LocalExitRoot::new(Digest([0x11; 32])),
+        new_local_exit_root: LocalExitRoot::new(Digest([0x12 + height as u8; 32])),
+        bridge_exits: vec![BridgeExit {
+            leaf_type: LeafType::Transfer,
+            token_info: TokenInfo {
+                origin_network: NetworkId::new(0),
+                origin_token_address: Address::new([0xa0; 20]),
+            },
+            dest_network: NetworkId::new(1),
+            dest_address: Address::new([0xc0; 20]),
+            amount: U256::from(1_000u64),
+            metadata: None,
+        }],
+        imported_bridge_exits: vec![],
+        metadata: Digest([0x22; 32]),
+        aggchain_data: AggchainData::ECDSA {
+            signature: Signature::new(U256::from(1u64), U256::from(2u64), false),
+        },
+        custom_chain_data: vec![0xca, 0xfe],
+        l1_info_tree_leaf_count: Some(7),
+    }
+}
@@ -1,4 +1,4 @@
-use super::Error;
+use super::{required, Error};
 
 mod types_to_v1;
 mod v1_to_types;
@@ -1,8 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use agglayer_bincode as bincode_codec;
 use agglayer_interop_types::aggchain_proof::{
     AggchainData, AggchainProof, MultisigPayload, Proof, SP1StarkWithContext,
+    PUBLIC_VALUES_CONTEXT_KEY,
 };
 use prost::bytes::Bytes;
 
@@ -25,6 +26,24 @@ where
     ))
 }
 
+fn serialize_context(
+    context: BTreeMap<String, Vec<u8>>,
+    public_values: Option<Bytes>,
+) -> Result<HashMap<String, Bytes>, Error> {
+    if context.contains_key(PUBLIC_VALUES_CONTEXT_KEY) {
+        return Err(Error::invalid_data(format!(
+            "context key {PUBLIC_VALUES_CONTEXT_KEY:?} is reserved for the public values"
+        ))
+        .inside_field("context"));
+    }
+
+    Ok(context
+        .into_iter()
+        .map(|(key, value)| (key, Bytes::from(value)))
+        .chain(public_values.map(|bytes| (PUBLIC_VALUES_CONTEXT_KEY.to_owned(), bytes)))
+        .collect())
+}
+
 impl TryFrom<AggchainProof> for v1::AggchainProof {
     type Error = Error;
 
@@ -33,13 +52,13 @@ impl TryFrom<AggchainProof> for v1::AggchainProof {
             proof: Some(value.proof.try_into()?),
             aggchain_params: Some(value.aggchain_params.into()),
             signature: None,
-            context: match value.public_values {
-                Some(public_values) => HashMap::from([(
-                    "public_values".to_owned(),
-                    serialize_public_values(&public_values)?,
-                )]),
-                None => HashMap::new(),
-            },
+            context: serialize_context(
+                value.context,
+                value
+                    .public_values
+                    .map(|public_values| serialize_public_values(&public_values))
+                    .transpose()?,
+            )?,
         })
     }
 }
@@ -78,11 +97,12 @@ impl TryFrom<AggchainData> for v1::AggchainData {
                     signature,
                     aggchain_params,
                     public_values,
+                    context,
                 } => v1::aggchain_data::Data::Generic(v1::AggchainProof {
-                    context: HashMap::from([(
-                        "public_values".to_owned(),
-                        serialize_public_values(&public_values)?,
-                    )]),
+                    context: serialize_context(
+                        context,
+                        Some(serialize_public_values(&public_values)?),
+                    )?,
                     aggchain_params: Some(aggchain_params.into()),
                     signature: signature.map(|signature| v1::FixedBytes65 {
                         value: Bytes::copy_from_slice(&signature.as_bytes()),
@@ -1,9 +1,13 @@
+use std::collections::{BTreeMap, HashMap};
+
 use agglayer_bincode as bincode_codec;
 use agglayer_interop_types::aggchain_proof::{
     AggchainData, AggchainProof, MultisigPayload, Proof, SP1StarkWithContext,
+    PUBLIC_VALUES_CONTEXT_KEY,
 };
+use prost::bytes::Bytes;
 
-use super::Error;
+use super::{required, Error};
 use crate::v1::{self};
 
 /// Maximum number of signers allowed in a multisig payload.
@@ -22,18 +26,38 @@ where
         .map_err(Error::deserializing_aggchain_proof_public_values)
 }
 
+/// Splits the public values entry from the remaining opaque context entries.
+fn split_context(
+    mut context: HashMap<String, Bytes>,
+) -> (Option<Bytes>, BTreeMap<String, Vec<u8>>) {
+    let public_values = context.remove(PUBLIC_VALUES_CONTEXT_KEY);
+    let context = context
+        .into_iter()
+        .map(|(key, value)| (key, value.to_vec()))
+        .collect();
+
+    (public_values, context)
+}
+
 impl TryFrom<v1::AggchainProof> for AggchainProof {
     type Error = Error;
 
     fn try_from(value: v1::AggchainProof) -> Result<Self, Self::Error> {
+        let (public_values, context) = split_context(value.context);
+
+        try_fields! {
+            proof = required(value.proof, "proof"),
+            aggchain_params = required(value.aggchain_params, "aggchain_params"),
+            public_values = public_values
+                .map(|b| deserialize_public_values(&b).map(Box::new))
+                .transpose(),
+        }
+
         Ok(Self {
-            proof: required_field!(value, proof),
-            aggchain_params: required_field!(value, aggchain_params),
-            public_values: value
-                .context
-                .get("public_values")
-                .map(|b| deserialize_public_values(b).map(Box::new))
-                .transpose()?,
+            proof,
+            aggchain_params,
+            public_values,
+            context,
         })
     }
 }
@@ -77,32 +101,33 @@ impl TryFrom<v1::AggchainData> for AggchainData {
                     .map_err(Error::parsing_signature)?,
             },
             Some(v1::aggchain_data::Data::Generic(aggchain_proof)) => {
-                let signature = aggchain_proof
-                    .signature
-                    .as_ref()
-                    .map(|signature| {
-                        (&*signature.value)
-                            .try_into()
-                            .map_err(Error::parsing_signature)
-                    })
-                    .transpose()?
-                    .map(Box::new);
-
-                let public_values = aggchain_proof
-                    .context
-                    .get("public_values")
-                    .map(|b| deserialize_public_values(b))
-                    .transpose()?
-                    .unwrap_or(None);
-
-                let proof = required_field!(aggchain_proof, proof);
-                let aggchain_params = required_field!(aggchain_proof, aggchain_params);
+                let (public_values, context) = split_context(aggchain_proof.context);
+
+                try_fields! {
+                    signature = aggchain_proof
+                        .signature
+                        .as_ref()
+                        .map(|signature| {
+                            (&*signature.value)
+                                .try_into()
+                                .map(Box::new)
+                                .map_err(Error::parsing_signature)
+                        })
+                        .transpose(),
+                    public_values = public_values
+                        .map(|b| deserialize_public_values(&b))
+                        .transpose()
+                        .map(Option::flatten),
+                    proof = required(aggchain_proof.proof, "proof"),
+                    aggchain_params = required(aggchain_proof.aggchain_params, "aggchain_params"),
+                }
 
                 AggchainData::Generic {
                     public_values,
                     aggchain_params,
                     signature,
                     proof,
+                    context,
                 }
             }
             Some(v1::aggchain_data::Data::Multisig(multisig)) => AggchainData::MultisigOnly {
@@ -110,10 +135,20 @@ impl TryFrom<v1::AggchainData> for AggchainData {
             },
             Some(v1::aggchain_data::Data::MultisigAndAggchainProof(
                 multisig_and_aggchain_proof,
-            )) => AggchainData::MultisigAndAggchainProof {
-                multisig: required_field!(multisig_and_aggchain_proof, multisig),
-                aggchain_proof: required_field!(multisig_and_aggchain_proof, aggchain_proof),
-            },
+            )) => {
+                try_fields! {
+                    multisig = required(multisig_and_aggchain_proof.multisig, "multisig"),
+                    aggchain_proof = required(
+                        multisig_and_aggchain_proof.aggchain_proof,
+                        "aggchain_proof",
+                    ),
+                }
+
+                AggchainData::MultisigAndAggchainProof {
+                    multisig,
+                    aggchain_proof,
+                }
+            }
             None => return Err(Error::missing_field("data")),
         })
     }
@@ -1,30 +1,37 @@
 use agglayer_interop_types::{BridgeExit, LeafType, NetworkId};
 
-use super::Error;
+use super::{optional, required, Error};
 use crate::v1;
 
 impl TryFrom<v1::BridgeExit> for BridgeExit {
     type Error = Error;
 
     fn try_from(value: v1::BridgeExit) -> Result<Self, Self::Error> {
+        let leaf_type = match value.leaf_type() {
+            v1::LeafType::Transfer => Ok(LeafType::Transfer),
+            v1::LeafType::Message => Ok(LeafType::Message),
+            _ => {
+                let t = value.leaf_type;
+                Err(Error::invalid_data(format!("invalid leaf type: {t}"))
+                    .inside_field("leaf_type"))
+            }
+        };
+
+        try_fields! {
+            leaf_type = leaf_type,
+            token_info = required(value.token_info, "token_info"),
+            dest_address = required(value.dest_address, "dest_address"),
+            amount = required(value.amount, "amount"),
+            metadata = optional(value.metadata, "metadata"),
+        }
+
         Ok(BridgeExit {
-            leaf_type: match value.leaf_type() {
-                v1::LeafType::Transfer => LeafType::Transfer,
-                v1::LeafType::Message => LeafType::Message,
-                _ => {
-                    let t = value.leaf_type;
-                    return Err(Error::invalid_data(format!("invalid leaf type: {t}")));
-                }
-            },
-            token_info: required_field!(value, token_info),
+            leaf_type,
+            token_info,
             dest_network: NetworkId::new(value.dest_network),
-            dest_address: required_field!(value, dest_address),
-            amount: required_field!(value, amount),
-            metadata: value
-                .metadata
-                .map(TryInto::try_into)
-                .transpose()
-                .map_err(|e: Error| e.inside_field("metadata"))?,
+            dest_address,
+            amount,
+            metadata,
         })
     }
 }
@@ -0,0 +1,156 @@
+use agglayer_interop_types::{
+    certificate::{Certificate, CertificateHeader, CertificateStatus},
+    CertificateId, NetworkId,
+};
+use prost::bytes::Bytes;
+
+use super::{optional, repeated, required, Error};
+use crate::service::v1 as service;
+
+impl TryFrom<service::Certificate> for Certificate {
+    type Error = Error;
+
+    fn try_from(value: service::Certificate) -> Result<Self, Self::Error> {
+        try_fields! {
+            prev_local_exit_root = required(value.prev_local_exit_root, "prev_local_exit_root"),
+            new_local_exit_root = required(value.new_local_exit_root, "new_local_exit_root"),
+            bridge_exits = repeated(value.bridge_exits, "bridge_exits"),
+            imported_bridge_exits = repeated(value.imported_bridge_exits, "imported_bridge_exits"),
+            metadata = required(value.metadata, "metadata"),
+            aggchain_data = required(value.aggchain_data, "aggchain_data"),
+        }
+
+        Ok(Certificate {
+            network_id: NetworkId::new(value.network_id),
+            height: value.height,
+            prev_local_exit_root,
+            new_local_exit_root,
+            bridge_exits,
+            imported_bridge_exits,
+            metadata,
+            aggchain_data,
+            custom_chain_data: value.custom_chain_data.to_vec(),
+            l1_info_tree_leaf_count: value.l1_info_tree_leaf_count,
+        })
+    }
+}
+
+impl TryFrom<Certificate> for service::Certificate {
+    type Error = Error;
+
+    fn try_from(value: Certificate) -> Result<Self, Self::Error> {
+        Ok(service::Certificate {
+            network_id: value.network_id.into(),
+            height: value.height,
+            prev_local_exit_root: Some(value.prev_local_exit_root.into()),
+            new_local_exit_root: Some(value.new_local_exit_root.into()),
+            bridge_exits: value.bridge_exits.into_iter().map(Into::into).collect(),
+            imported_bridge_exits: value
+                .imported_bridge_exits
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            metadata: Some(value.metadata.into()),
+            aggchain_data: Some(
+                value
+                    .aggchain_data
+                    .try_into()
+                    .map_err(|e: Error| e.inside_field("aggchain_data"))?,
+            ),
+            custom_chain_data: Bytes::from(value.custom_chain_data),
+            l1_info_tree_leaf_count: value.l1_info_tree_leaf_count,
+        })
+    }
+}
+
+impl TryFrom<service::CertificateId> for CertificateId {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: service::CertificateId) -> Result<Self, Self::Error> {
+        Ok(required_field!(value, value))
+    }
+}
+
+impl From<CertificateId> for service::CertificateId {
+    #[inline]
+    fn from(value: CertificateId) -> Self {
+        service::CertificateId {
+            value: Some(value.into()),
+        }
+    }
+}
+
+impl TryFrom<service::CertificateHeader> for CertificateHeader {
+    type Error = Error;
+
+    fn try_from(value: service::CertificateHeader) -> Result<Self, Self::Error> {
+        let status = match value.status() {
+            service::CertificateStatus::Pending => Ok(CertificateStatus::Pending),
+            service::CertificateStatus::Proven => Ok(CertificateStatus::Proven),
+            service::CertificateStatus::Candidate => Ok(CertificateStatus::Candidate),
+            service::CertificateStatus::InError => value
+                .error
+                .ok_or(Error::missing_field("error"))
+                .map(|error| CertificateStatus::InError { error }),
+            service::CertificateStatus::Settled => Ok(CertificateStatus::Settled),
+            service::CertificateStatus::Unspecified => {
+                let s = value.status;
+                Err(
+                    Error::invalid_data(format!("invalid certificate status: {s}"))
+                        .inside_field("status"),
+                )
+            }
+        };
+
+        try_fields! {
+            certificate_id = required(value.certificate_id, "certificate_id"),
+            prev_local_exit_root = required(value.prev_local_exit_root, "prev_local_exit_root"),
+            new_local_exit_root = required(value.new_local_exit_root, "new_local_exit_root"),
+            metadata = required(value.metadata, "metadata"),
+            status = status,
+            settlement_tx_hash = optional(value.settlement_tx_hash, "settlement_tx_hash"),
+        }
+
+        Ok(CertificateHeader {
+            network_id: NetworkId::new(value.network_id),
+            height: value.height,
+            epoch_number: value.epoch_number,
+            certificate_index: value.certificate_index,
+            certificate_id,
+            prev_local_exit_root,
+            new_local_exit_root,
+            metadata,
+            status,
+            settlement_tx_hash,
+        })
+    }
+}
+
+impl From<CertificateHeader> for service::CertificateHeader {
+    fn from(value: CertificateHeader) -> Self {
+        let (status, error) = match value.status {
+            CertificateStatus::Pending => (service::CertificateStatus::Pending, None),
+            CertificateStatus::Proven => (service::CertificateStatus::Proven, None),
+            CertificateStatus::Candidate => (service::CertificateStatus::Candidate, None),
+            CertificateStatus::InError { error } => {
+                (service::CertificateStatus::InError, Some(error))
+            }
+            CertificateStatus::Settled => (service::CertificateStatus::Settled, None),
+        };
+
+        service::CertificateHeader {
+            network_id: value.network_id.into(),
+            height: value.height,
+            epoch_number: value.epoch_number,
+            certificate_index: value.certificate_index,
+            certificate_id: Some(value.certificate_id.into()),
+            prev_local_exit_root: Some(value.prev_local_exit_root.into()),
+            new_local_exit_root: Some(value.new_local_exit_root.into()),
+            metadata: Some(value.metadata.into()),
+            status: status.into(),
+            error,
+            settlement_tx_hash: value.settlement_tx_hash.map(Into::into),
+        }
+    }
+}
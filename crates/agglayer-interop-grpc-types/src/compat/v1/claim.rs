@@ -1,6 +1,6 @@
 use agglayer_interop_types::{Claim, ClaimFromMainnet, ClaimFromRollup};
 
-use super::Error;
+use super::{required, Error};
 use crate::v1;
 
 impl TryFrom<v1::ClaimFromMainnet> for ClaimFromMainnet {
@@ -8,10 +8,16 @@ impl TryFrom<v1::ClaimFromMainnet> for ClaimFromMainnet {
 
     #[inline]
     fn try_from(value: v1::ClaimFromMainnet) -> Result<Self, Self::Error> {
+        try_fields! {
+            proof_leaf_mer = required(value.proof_leaf_mer, "proof_leaf_mer"),
+            proof_ger_l1root = required(value.proof_ger_l1root, "proof_ger_l1root"),
+            l1_leaf = required(value.l1_leaf, "l1_leaf"),
+        }
+
         Ok(ClaimFromMainnet {
-            proof_leaf_mer: required_field!(value, proof_leaf_mer),
-            proof_ger_l1root: required_field!(value, proof_ger_l1root),
-            l1_leaf: required_field!(value, l1_leaf),
+            proof_leaf_mer,
+            proof_ger_l1root,
+            l1_leaf,
         })
     }
 }
@@ -32,11 +38,18 @@ impl TryFrom<v1::ClaimFromRollup> for ClaimFromRollup {
 
     #[inline]
     fn try_from(value: v1::ClaimFromRollup) -> Result<Self, Self::Error> {
+        try_fields! {
+            proof_leaf_ler = required(value.proof_leaf_ler, "proof_leaf_ler"),
+            proof_ler_rer = required(value.proof_ler_rer, "proof_ler_rer"),
+            proof_ger_l1root = required(value.proof_ger_l1root, "proof_ger_l1root"),
+            l1_leaf = required(value.l1_leaf, "l1_leaf"),
+        }
+
         Ok(ClaimFromRollup {
-            proof_leaf_ler: required_field!(value, proof_leaf_ler),
-            proof_ler_rer: required_field!(value, proof_ler_rer),
-            proof_ger_l1root: required_field!(value, proof_ger_l1root),
-            l1_leaf: required_field!(value, l1_leaf),
+            proof_leaf_ler,
+            proof_ler_rer,
+            proof_ger_l1root,
+            l1_leaf,
         })
     }
 }
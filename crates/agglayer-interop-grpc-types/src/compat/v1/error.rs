@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{borrow::Cow, fmt};
 
 use agglayer_interop_types::primitives::SignatureError;
 use tonic_types::FieldViolation;
@@ -12,10 +12,28 @@ pub enum SourceError {
     Signature(#[from] SignatureError),
 }
 
+/// Domain of the `ErrorInfo` details attached to the gRPC statuses.
+#[cfg(feature = "service")]
+const ERROR_DOMAIN: &str = "agglayer.interop";
+
 #[derive(Clone, Copy, Debug)]
 pub enum ErrorKind {
     InvalidData,
     MissingField,
+    Unrepresentable,
+}
+
+impl ErrorKind {
+    /// Stable, machine-readable identifier of the error kind, reported as the
+    /// reason of the gRPC field violations.
+    #[inline]
+    pub fn reason(&self) -> &'static str {
+        match self {
+            ErrorKind::InvalidData => "INVALID_DATA",
+            ErrorKind::MissingField => "MISSING_FIELD",
+            ErrorKind::Unrepresentable => "UNREPRESENTABLE",
+        }
+    }
 }
 
 impl fmt::Display for ErrorKind {
@@ -28,6 +46,9 @@ impl fmt::Display for ErrorKind {
             ErrorKind::MissingField => {
                 write!(f, "Missing field")
             }
+            ErrorKind::Unrepresentable => {
+                write!(f, "Unrepresentable")
+            }
         }
     }
 }
@@ -36,9 +57,12 @@ impl fmt::Display for ErrorKind {
 pub struct Error {
     kind: ErrorKind,
     message: String,
-    field: Vec<&'static str>,
+    /// Path to the field, made of field names and `[i]` element indices.
+    field: Vec<Cow<'static, str>>,
     #[source]
     source: Option<SourceError>,
+    /// Errors found in other fields of the same message.
+    others: Vec<Error>,
 }
 
 impl fmt::Display for Error {
@@ -47,7 +71,11 @@ impl fmt::Display for Error {
         if !self.field.is_empty() {
             write!(f, "{}: ", self.field_str())?;
         }
-        write!(f, "{}", self.message)
+        write!(f, "{}", self.message)?;
+        if !self.others.is_empty() {
+            write!(f, " (and {} more errors)", self.others.len())?;
+        }
+        Ok(())
     }
 }
 
@@ -57,8 +85,9 @@ impl Error {
         Error {
             kind: ErrorKind::MissingField,
             message: "required field is missing".to_string(),
-            field: vec![f],
+            field: vec![f.into()],
             source: None,
+            others: vec![],
         }
     }
 
@@ -69,13 +98,49 @@ impl Error {
             message: m,
             field: vec![],
             source: None,
+            others: vec![],
         }
     }
 
+    /// The field is set but cannot be represented in an older version of the
+    /// messages.
+    #[inline]
+    pub fn unrepresentable(version: &'static str) -> Self {
+        Error {
+            kind: ErrorKind::Unrepresentable,
+            message: format!("cannot be represented in {version}"),
+            field: vec![],
+            source: None,
+            others: vec![],
+        }
+    }
+
+    /// Merges the errors found while converting several fields of the same
+    /// message into a single error, or returns `None` if there are none.
+    pub fn combine(errors: impl IntoIterator<Item = Error>) -> Option<Self> {
+        let mut errors = errors.into_iter();
+        let mut first = errors.next()?;
+        for mut error in errors {
+            let others = std::mem::take(&mut error.others);
+            first.others.push(error);
+            first.others.extend(others);
+        }
+        Some(first)
+    }
+
     #[inline]
     pub fn inside_field(mut self, f: &'static str) -> Self {
-        self.field.push(f);
-        self.field.rotate_right(1);
+        self.field.insert(0, f.into());
+        self.others = self.others.into_iter().map(|e| e.inside_field(f)).collect();
+        self
+    }
+
+    /// Scopes the error to the element at `index` of a repeated field, to be
+    /// followed by [`Error::inside_field`] with the name of the field.
+    #[inline]
+    pub fn at_index(mut self, index: usize) -> Self {
+        self.field.insert(0, format!("[{index}]").into());
+        self.others = self.others.into_iter().map(|e| e.at_index(index)).collect();
         self
     }
 
@@ -86,6 +151,7 @@ impl Error {
             message: "failed to serialize proof".to_string(),
             field: vec![],
             source: Some(SourceError::Bincode(e)),
+            others: vec![],
         }
     }
 
@@ -96,6 +162,7 @@ impl Error {
             message: "failed to serialize vkey".to_string(),
             field: vec![],
             source: Some(SourceError::Bincode(e)),
+            others: vec![],
         }
     }
 
@@ -106,6 +173,7 @@ impl Error {
             message: "failed to serialize context".to_string(),
             field: vec![],
             source: Some(SourceError::Bincode(e)),
+            others: vec![],
         }
     }
 
@@ -116,6 +184,7 @@ impl Error {
             message: "failed to deserialize proof".to_string(),
             field: vec![],
             source: Some(SourceError::Bincode(e)),
+            others: vec![],
         }
     }
 
@@ -126,6 +195,7 @@ impl Error {
             message: "failed to deserialize vkey".to_string(),
             field: vec![],
             source: Some(SourceError::Bincode(e)),
+            others: vec![],
         }
     }
 
@@ -136,6 +206,7 @@ impl Error {
             message: "failed to deserialize aggchain proof public values".to_string(),
             field: vec![],
             source: Some(SourceError::Bincode(e)),
+            others: vec![],
         }
     }
 
@@ -146,6 +217,7 @@ impl Error {
             message: "failed to serialize aggchain proof public values".to_string(),
             field: vec![],
             source: Some(SourceError::Bincode(e)),
+            others: vec![],
         }
     }
 
@@ -156,6 +228,7 @@ impl Error {
             message: "failed to parse signature".to_string(),
             field: vec![],
             source: Some(SourceError::Signature(e)),
+            others: vec![],
         }
     }
 
@@ -165,26 +238,61 @@ impl Error {
     }
 
     #[inline]
-    pub fn field(&self) -> &[&'static str] {
+    pub fn field(&self) -> &[Cow<'static, str>] {
         &self.field
     }
 
     #[inline]
     pub fn field_str(&self) -> String {
         if self.field.is_empty() {
-            ".".to_string()
-        } else {
-            self.field.join(".")
+            return ".".to_string();
         }
+        let mut field = String::new();
+        for segment in &self.field {
+            if !field.is_empty() && !segment.starts_with('[') {
+                field.push('.');
+            }
+            field.push_str(segment);
+        }
+        field
+    }
+
+    /// Iterates over this error and the errors found in other fields of the
+    /// same message.
+    #[inline]
+    pub fn errors(&self) -> impl Iterator<Item = &Error> {
+        std::iter::once(self).chain(&self.others)
     }
 }
 
 impl From<&Error> for Vec<FieldViolation> {
-    #[inline]
     fn from(value: &Error) -> Self {
-        vec![FieldViolation::new(
-            value.field_str(),
-            value.message.clone(),
-        )]
+        value
+            .errors()
+            .map(|e| FieldViolation::new(e.field_str(), e.message.clone()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "service")]
+impl From<Error> for tonic::Status {
+    /// Reports every field violation of the error in the `BadRequest`
+    /// details of an `INVALID_ARGUMENT` status, along with an `ErrorInfo`
+    /// carrying the reason of the first one.
+    fn from(value: Error) -> Self {
+        use std::collections::HashMap;
+
+        use tonic_types::{ErrorDetails, StatusExt as _};
+
+        let mut details = ErrorDetails::new();
+        // `tonic-types` does not encode the reason of field violations, so the
+        // reason of the first violation is also reported in `ErrorInfo`.
+        details.set_bad_request(Vec::from(&value)).set_error_info(
+            value.kind.reason(),
+            ERROR_DOMAIN,
+            HashMap::from([("field".to_owned(), value.field_str())]),
+        );
+
+        tonic::Status::with_error_details(tonic::Code::InvalidArgument, value.to_string(), details)
     }
 }
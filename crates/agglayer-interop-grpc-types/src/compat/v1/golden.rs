@@ -0,0 +1,623 @@
+//! Golden test vectors for the wire encodings of the interop types.
+//!
+//! Every public type of `unified-bridge` and `agglayer-interop-types` that
+//! goes over the wire has a vector below, pinned as an insta snapshot. A
+//! vector records, for a fixed sample value:
+//!
+//! * the `agglayer_bincode` encodings (`default()` and `sp1_compatible()`),
+//! * the human-readable serde (JSON) form,
+//! * the `v1` protobuf encoding, for types with a `v1` conversion,
+//! * the hash of the value, for hashable types.
+//!
+//! The encodings are also decoded back and compared with the sample value.
+//! Any change in the encoding of a type makes its snapshot test fail.
+//!
+//! ## Adding or updating vectors
+//!
+//! When a type is added, add a test building its vector with a deterministic
+//! sample value. When the encoding of a type changes intentionally, update the
+//! sample if needed. In both cases, regenerate the snapshots and review them:
+//!
+//! ```sh
+//! cargo insta test -p agglayer-interop-grpc-types --review -- golden
+//! ```
+//!
+//! Snapshot changes of existing vectors are consensus-relevant and should be
+//! called out explicitly in review.
+
+use std::fmt::{Debug, Write as _};
+
+use agglayer_interop_types::{
+    aggchain_proof::{
+        AggchainData, AggchainProof, AggchainProofPublicValues, MultisigPayload, Proof,
+        SP1StarkWithContext,
+    },
+    primitives::{keccak::keccak256_combine, Hashable},
+    Address, BridgeExit, Claim, ClaimFromMainnet, ClaimFromRollup, Digest, GlobalIndex,
+    GlobalIndexWithLeafHash, ImportedBridgeExit, L1InfoRoot, L1InfoTreeLeaf, L1InfoTreeLeafInner,
+    LeafType, LocalBalanceRoot, LocalExitRoot, LocalNullifierRoot, MerkleProof, NetworkId,
+    PessimisticRoot, Signature, TokenInfo, U256,
+};
+use agglayer_tries::error::SmtError;
+use prost::Message;
+use serde::{de::DeserializeOwned, Serialize};
+use unified_bridge::{LETMerkleProof, LocalExitTree, LocalExitTreeError, RollupId, RollupIndex};
+
+use crate::v1;
+
+/// Encodings of a sample value, rendered as a snapshot.
+struct GoldenVector<'a, T> {
+    value: &'a T,
+    entries: Vec<(&'static str, String)>,
+}
+
+impl<'a, T: Serialize> GoldenVector<'a, T> {
+    fn new(value: &'a T) -> Self {
+        let default = agglayer_bincode::default().serialize(value).unwrap();
+        let sp1_compatible = agglayer_bincode::sp1_compatible().serialize(value).unwrap();
+        let json = serde_json::to_string_pretty(value).unwrap();
+
+        Self {
+            value,
+            entries: vec![
+                ("bincode.default", hex_lines(&default)),
+                ("bincode.sp1_compatible", hex_lines(&sp1_compatible)),
+                ("json", json),
+            ],
+        }
+    }
+
+    /// Record the hash of the value.
+    fn hash(mut self, hash: Digest) -> Self {
+        self.entries.push(("hash", hash.to_string()));
+        self
+    }
+
+    fn assert_snapshot(self, name: &str) {
+        let mut rendered = String::new();
+        for (key, value) in self.entries {
+            writeln!(rendered, "{key}:").unwrap();
+            for line in value.lines() {
+                writeln!(rendered, "  {line}").unwrap();
+            }
+        }
+
+        insta::assert_snapshot!(name, rendered);
+    }
+}
+
+impl<T> GoldenVector<'_, T>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    /// Check that the JSON form decodes back to the value.
+    fn json_round_trip(self) -> Self {
+        let json = serde_json::to_string(self.value).unwrap();
+        assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), self.value);
+        self
+    }
+
+    /// Check that all the serde encodings decode back to the value.
+    fn round_trip(self) -> Self {
+        let default = agglayer_bincode::default().serialize(self.value).unwrap();
+        assert_eq!(
+            &agglayer_bincode::default()
+                .deserialize::<T>(&default)
+                .unwrap(),
+            self.value
+        );
+
+        let sp1_compatible = agglayer_bincode::sp1_compatible()
+            .serialize(self.value)
+            .unwrap();
+        assert_eq!(
+            &agglayer_bincode::sp1_compatible()
+                .deserialize::<T>(&sp1_compatible)
+                .unwrap(),
+            self.value
+        );
+
+        self.json_round_trip()
+    }
+}
+
+impl<T> GoldenVector<'_, T>
+where
+    T: Clone + PartialEq + Debug,
+{
+    /// Record the `v1` protobuf encoding and check it decodes back to the
+    /// value.
+    fn proto<P>(mut self) -> Self
+    where
+        P: Message + Default + TryInto<T>,
+        T: TryInto<P>,
+        <P as TryInto<T>>::Error: Debug,
+        <T as TryInto<P>>::Error: Debug,
+    {
+        let proto: P = self.value.clone().try_into().unwrap();
+        let encoded = proto.encode_to_vec();
+
+        let decoded: T = P::decode(encoded.as_slice()).unwrap().try_into().unwrap();
+        assert_eq!(&decoded, self.value);
+
+        self.entries.push(("proto.v1", hex_lines(&encoded)));
+        self
+    }
+}
+
+/// Render bytes as hex, 32 bytes per line.
+fn hex_lines(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return "(empty)".to_owned();
+    }
+
+    bytes
+        .chunks(32)
+        .map(hex::encode)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Digest with distinct bytes, so that byte order changes are visible.
+fn digest(seed: u8) -> Digest {
+    Digest(std::array::from_fn(|i| seed.wrapping_add(i as u8)))
+}
+
+fn address(seed: u8) -> Address {
+    Address::new(std::array::from_fn(|i| seed.wrapping_add(i as u8)))
+}
+
+fn signature() -> Signature {
+    Signature::new(
+        U256::from_be_bytes(*digest(0x10)),
+        U256::from_be_bytes(*digest(0x30)),
+        true,
+    )
+}
+
+fn token_info() -> TokenInfo {
+    TokenInfo {
+        origin_network: NetworkId::new(0x00112233),
+        origin_token_address: address(0xa0),
+    }
+}
+
+fn bridge_exit() -> BridgeExit {
+    BridgeExit {
+        leaf_type: LeafType::Transfer,
+        token_info: token_info(),
+        dest_network: NetworkId::new(3),
+        dest_address: address(0xc0),
+        amount: U256::from(1_000_000_000_000_000_000_u64),
+        metadata: Some(digest(0xd0)),
+    }
+}
+
+fn merkle_proof(seed: u8) -> MerkleProof {
+    MerkleProof::new(
+        digest(seed),
+        std::array::from_fn(|i| digest(seed.wrapping_add(i as u8 + 1))),
+    )
+}
+
+fn l1_info_tree_leaf_inner() -> L1InfoTreeLeafInner {
+    L1InfoTreeLeafInner {
+        global_exit_root: digest(0x60),
+        block_hash: digest(0x70),
+        timestamp: 0x0011223344556677,
+    }
+}
+
+fn l1_info_tree_leaf() -> L1InfoTreeLeaf {
+    L1InfoTreeLeaf {
+        l1_info_tree_index: 0x00c0ffee,
+        rer: digest(0x40),
+        mer: digest(0x50),
+        inner: l1_info_tree_leaf_inner(),
+    }
+}
+
+fn claim_from_mainnet() -> ClaimFromMainnet {
+    ClaimFromMainnet {
+        proof_leaf_mer: merkle_proof(0x01),
+        proof_ger_l1root: merkle_proof(0x02),
+        l1_leaf: l1_info_tree_leaf(),
+    }
+}
+
+fn claim_from_rollup() -> ClaimFromRollup {
+    ClaimFromRollup {
+        proof_leaf_ler: merkle_proof(0x03),
+        proof_ler_rer: merkle_proof(0x04),
+        proof_ger_l1root: merkle_proof(0x05),
+        l1_leaf: l1_info_tree_leaf(),
+    }
+}
+
+fn global_index() -> GlobalIndex {
+    GlobalIndex::new(NetworkId::new(3), 42)
+}
+
+fn imported_bridge_exit() -> ImportedBridgeExit {
+    ImportedBridgeExit {
+        bridge_exit: bridge_exit(),
+        claim_data: Claim::Rollup(Box::new(claim_from_rollup())),
+        global_index: global_index(),
+    }
+}
+
+fn public_values() -> AggchainProofPublicValues {
+    AggchainProofPublicValues {
+        prev_local_exit_root: digest(0x11),
+        new_local_exit_root: digest(0x22),
+        l1_info_root: digest(0x33),
+        origin_network: NetworkId::new(0x00112233),
+        commit_imported_bridge_exits: digest(0x44),
+        aggchain_params: digest(0x55),
+    }
+}
+
+fn sp1_stark() -> SP1StarkWithContext {
+    SP1StarkWithContext {
+        proof: vec![0x06, 0x00, 0x00, 0x01, 0xde, 0xad, 0xbe, 0xef],
+        vkey: vec![0xb6, 0x00, 0x02, 0xca, 0xfe],
+        version: "v6.0.0".to_owned(),
+    }
+}
+
+fn multisig() -> MultisigPayload {
+    MultisigPayload(vec![Some(signature()), None, Some(signature())])
+}
+
+fn aggchain_proof() -> AggchainProof {
+    AggchainProof {
+        proof: Proof::SP1Stark(sp1_stark()),
+        aggchain_params: digest(0x88),
+        public_values: Some(Box::new(public_values())),
+        context: Default::default(),
+    }
+}
+
+#[test]
+fn digest_vector() {
+    GoldenVector::new(&digest(0x00))
+        .round_trip()
+        .proto::<v1::FixedBytes32>()
+        .assert_snapshot("digest");
+}
+
+#[test]
+fn address_vector() {
+    GoldenVector::new(&address(0xa0))
+        .round_trip()
+        .proto::<v1::FixedBytes20>()
+        .assert_snapshot("address");
+}
+
+#[test]
+fn u256_vector() {
+    GoldenVector::new(&U256::from_be_bytes(*digest(0x80)))
+        .round_trip()
+        .proto::<v1::FixedBytes32>()
+        .assert_snapshot("u256");
+}
+
+#[test]
+fn signature_vector() {
+    GoldenVector::new(&signature())
+        .round_trip()
+        .assert_snapshot("signature");
+}
+
+#[test]
+fn root_vectors() {
+    GoldenVector::new(&LocalExitRoot::new(digest(0x01)))
+        .round_trip()
+        .proto::<v1::FixedBytes32>()
+        .assert_snapshot("local_exit_root");
+    GoldenVector::new(&LocalBalanceRoot::new(digest(0x02)))
+        .round_trip()
+        .assert_snapshot("local_balance_root");
+    GoldenVector::new(&LocalNullifierRoot::new(digest(0x03)))
+        .round_trip()
+        .assert_snapshot("local_nullifier_root");
+    GoldenVector::new(&PessimisticRoot::new(digest(0x04)))
+        .round_trip()
+        .assert_snapshot("pessimistic_root");
+    GoldenVector::new(&L1InfoRoot::new(digest(0x05)))
+        .round_trip()
+        .assert_snapshot("l1_info_root");
+}
+
+#[test]
+fn network_id_vector() {
+    GoldenVector::new(&NetworkId::new(0x00112233))
+        .round_trip()
+        .assert_snapshot("network_id");
+}
+
+#[test]
+fn rollup_id_vector() {
+    GoldenVector::new(&RollupId::new(0x00112233).unwrap())
+        .round_trip()
+        .assert_snapshot("rollup_id");
+}
+
+#[test]
+fn rollup_index_vector() {
+    GoldenVector::new(&RollupIndex::new(0x00112233).unwrap())
+        .round_trip()
+        .assert_snapshot("rollup_index");
+}
+
+#[test]
+fn leaf_type_vectors() {
+    GoldenVector::new(&LeafType::Transfer)
+        .round_trip()
+        .assert_snapshot("leaf_type_transfer");
+    GoldenVector::new(&LeafType::Message)
+        .round_trip()
+        .assert_snapshot("leaf_type_message");
+}
+
+#[test]
+fn token_info_vector() {
+    let token_info = token_info();
+
+    GoldenVector::new(&token_info)
+        .round_trip()
+        .proto::<v1::TokenInfo>()
+        .hash(token_info.hash())
+        .assert_snapshot("token_info");
+}
+
+#[test]
+fn bridge_exit_vectors() {
+    let bridge_exit = bridge_exit();
+    GoldenVector::new(&bridge_exit)
+        .round_trip()
+        .proto::<v1::BridgeExit>()
+        .hash(bridge_exit.hash())
+        .assert_snapshot("bridge_exit");
+
+    let message = BridgeExit {
+        leaf_type: LeafType::Message,
+        metadata: None,
+        ..bridge_exit
+    };
+    GoldenVector::new(&message)
+        .round_trip()
+        .proto::<v1::BridgeExit>()
+        .hash(message.hash())
+        .assert_snapshot("bridge_exit_message");
+}
+
+#[test]
+fn global_index_vectors() {
+    let global_index = global_index();
+    GoldenVector::new(&global_index)
+        .round_trip()
+        .proto::<v1::FixedBytes32>()
+        .hash(global_index.hash())
+        .assert_snapshot("global_index");
+
+    let mainnet = GlobalIndex::new(NetworkId::ETH_L1, 42);
+    GoldenVector::new(&mainnet)
+        .round_trip()
+        .proto::<v1::FixedBytes32>()
+        .hash(mainnet.hash())
+        .assert_snapshot("global_index_mainnet");
+}
+
+#[test]
+fn global_index_with_leaf_hash_vector() {
+    let value = imported_bridge_exit().to_indexed_exit_hash();
+
+    GoldenVector::new(&value)
+        .hash(value.commitment())
+        .assert_snapshot("global_index_with_leaf_hash");
+
+    let json = serde_json::to_string(&value).unwrap();
+    let decoded: GlobalIndexWithLeafHash = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.global_index, value.global_index);
+    assert_eq!(decoded.bridge_exit_hash, value.bridge_exit_hash);
+}
+
+#[test]
+fn merkle_proof_vector() {
+    let merkle_proof = merkle_proof(0x01);
+
+    GoldenVector::new(&merkle_proof)
+        .round_trip()
+        .proto::<v1::MerkleProof>()
+        .hash(merkle_proof.hash())
+        .assert_snapshot("merkle_proof");
+}
+
+#[test]
+fn let_merkle_proof_vector() {
+    let proof: LETMerkleProof = merkle_proof(0x01).proof;
+
+    GoldenVector::new(&proof)
+        .round_trip()
+        .assert_snapshot("let_merkle_proof");
+}
+
+#[test]
+fn l1_info_tree_leaf_inner_vector() {
+    let inner = l1_info_tree_leaf_inner();
+
+    GoldenVector::new(&inner)
+        .round_trip()
+        .proto::<v1::L1InfoTreeLeaf>()
+        .hash(inner.hash(inner.global_exit_root))
+        .assert_snapshot("l1_info_tree_leaf_inner");
+}
+
+#[test]
+fn l1_info_tree_leaf_vector() {
+    let leaf = l1_info_tree_leaf();
+
+    GoldenVector::new(&leaf)
+        .round_trip()
+        .proto::<v1::L1InfoTreeLeafWithContext>()
+        .hash(leaf.hash())
+        .assert_snapshot("l1_info_tree_leaf");
+}
+
+#[test]
+fn claim_from_mainnet_vector() {
+    let claim = claim_from_mainnet();
+
+    GoldenVector::new(&claim)
+        .round_trip()
+        .proto::<v1::ClaimFromMainnet>()
+        .hash(claim.hash())
+        .assert_snapshot("claim_from_mainnet");
+}
+
+#[test]
+fn claim_from_rollup_vector() {
+    let claim = claim_from_rollup();
+
+    GoldenVector::new(&claim)
+        .round_trip()
+        .proto::<v1::ClaimFromRollup>()
+        .hash(claim.hash())
+        .assert_snapshot("claim_from_rollup");
+}
+
+#[test]
+fn claim_vectors() {
+    let mainnet = Claim::Mainnet(Box::new(claim_from_mainnet()));
+    GoldenVector::new(&mainnet)
+        .round_trip()
+        .hash(mainnet.hash())
+        .assert_snapshot("claim_mainnet");
+
+    let rollup = Claim::Rollup(Box::new(claim_from_rollup()));
+    GoldenVector::new(&rollup)
+        .round_trip()
+        .hash(rollup.hash())
+        .assert_snapshot("claim_rollup");
+}
+
+#[test]
+fn imported_bridge_exit_vector() {
+    let imported_bridge_exit = imported_bridge_exit();
+    // Same as `ImportedBridgeExit::hash`, which the `zkvm` feature removes.
+    let hash = keccak256_combine([
+        imported_bridge_exit.bridge_exit.hash(),
+        imported_bridge_exit.claim_data.hash(),
+        imported_bridge_exit.global_index.hash(),
+    ]);
+
+    GoldenVector::new(&imported_bridge_exit)
+        .round_trip()
+        .proto::<v1::ImportedBridgeExit>()
+        .hash(hash)
+        .assert_snapshot("imported_bridge_exit");
+}
+
+#[test]
+fn local_exit_tree_vector() {
+    let tree = LocalExitTree::<32>::from_leaves((0..3).map(digest)).unwrap();
+
+    GoldenVector::new(&tree)
+        .hash(tree.get_root())
+        .assert_snapshot("local_exit_tree");
+
+    let json = serde_json::to_string(&tree).unwrap();
+    let decoded: LocalExitTree<32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.leaf_count, tree.leaf_count);
+    assert_eq!(decoded.frontier, tree.frontier);
+}
+
+#[test]
+fn error_vectors() {
+    GoldenVector::new(&LocalExitTreeError::FrontierIndexOutOfBounds)
+        .round_trip()
+        .assert_snapshot("local_exit_tree_error");
+    GoldenVector::new(&unified_bridge::Error::InvalidExitNetwork)
+        .round_trip()
+        .assert_snapshot("unified_bridge_error");
+    GoldenVector::new(&agglayer_interop_types::Error::InvalidSmtOperation(
+        SmtError::KeyNotPresent,
+    ))
+    .round_trip()
+    .assert_snapshot("interop_types_error");
+}
+
+#[test]
+fn aggchain_proof_public_values_vector() {
+    let public_values = public_values();
+
+    GoldenVector::new(&public_values)
+        .round_trip()
+        .hash(Digest(public_values.hash()))
+        .assert_snapshot("aggchain_proof_public_values");
+}
+
+#[test]
+fn sp1_stark_vectors() {
+    GoldenVector::new(&sp1_stark())
+        .round_trip()
+        .assert_snapshot("sp1_stark_with_context");
+    GoldenVector::new(&Proof::SP1Stark(sp1_stark()))
+        .round_trip()
+        .assert_snapshot("proof");
+}
+
+#[test]
+fn multisig_payload_vector() {
+    GoldenVector::new(&multisig())
+        .round_trip()
+        .proto::<v1::Multisig>()
+        .assert_snapshot("multisig_payload");
+}
+
+#[test]
+fn aggchain_proof_vector() {
+    GoldenVector::new(&aggchain_proof())
+        .round_trip()
+        .proto::<v1::AggchainProof>()
+        .assert_snapshot("aggchain_proof");
+}
+
+// `AggchainData` is untagged, so its bincode encodings can be produced but
+// not decoded.
+#[test]
+fn aggchain_data_vectors() {
+    GoldenVector::new(&AggchainData::ECDSA {
+        signature: signature(),
+    })
+    .json_round_trip()
+    .proto::<v1::AggchainData>()
+    .assert_snapshot("aggchain_data_ecdsa");
+
+    GoldenVector::new(&AggchainData::Generic {
+        proof: Proof::SP1Stark(sp1_stark()),
+        aggchain_params: digest(0x77),
+        signature: Some(Box::new(signature())),
+        public_values: Some(Box::new(public_values())),
+        context: Default::default(),
+    })
+    .json_round_trip()
+    .proto::<v1::AggchainData>()
+    .assert_snapshot("aggchain_data_generic");
+
+    GoldenVector::new(&AggchainData::MultisigOnly {
+        multisig: multisig(),
+    })
+    .json_round_trip()
+    .proto::<v1::AggchainData>()
+    .assert_snapshot("aggchain_data_multisig_only");
+
+    GoldenVector::new(&AggchainData::MultisigAndAggchainProof {
+        multisig: multisig(),
+        aggchain_proof: aggchain_proof(),
+    })
+    .json_round_trip()
+    .proto::<v1::AggchainData>()
+    .assert_snapshot("aggchain_data_multisig_and_aggchain_proof");
+}
@@ -1,17 +1,22 @@
 use agglayer_interop_types::ImportedBridgeExit;
 
-use super::Error;
+use super::{required, Error};
 use crate::v1;
 
 impl TryFrom<v1::ImportedBridgeExit> for ImportedBridgeExit {
     type Error = Error;
 
-    #[inline]
     fn try_from(value: v1::ImportedBridgeExit) -> Result<Self, Self::Error> {
+        try_fields! {
+            bridge_exit = required(value.bridge_exit, "bridge_exit"),
+            claim_data = required(value.claim, "claim"),
+            global_index = required(value.global_index, "global_index"),
+        }
+
         Ok(ImportedBridgeExit {
-            bridge_exit: required_field!(value, bridge_exit),
-            claim_data: required_field!(value, claim),
-            global_index: required_field!(value, global_index),
+            bridge_exit,
+            claim_data,
+            global_index,
         })
     }
 }
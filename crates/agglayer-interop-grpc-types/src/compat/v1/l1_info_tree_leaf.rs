@@ -1,6 +1,6 @@
 use agglayer_interop_types::{L1InfoTreeLeaf, L1InfoTreeLeafInner};
 
-use super::Error;
+use super::{required, Error};
 use crate::v1;
 
 impl TryFrom<v1::L1InfoTreeLeaf> for L1InfoTreeLeafInner {
@@ -8,9 +8,14 @@ impl TryFrom<v1::L1InfoTreeLeaf> for L1InfoTreeLeafInner {
 
     #[inline]
     fn try_from(value: v1::L1InfoTreeLeaf) -> Result<Self, Self::Error> {
+        try_fields! {
+            global_exit_root = required(value.global_exit_root, "global_exit_root"),
+            block_hash = required(value.block_hash, "block_hash"),
+        }
+
         Ok(L1InfoTreeLeafInner {
-            global_exit_root: required_field!(value, global_exit_root),
-            block_hash: required_field!(value, block_hash),
+            global_exit_root,
+            block_hash,
             timestamp: value.timestamp,
         })
     }
@@ -32,11 +37,17 @@ impl TryFrom<v1::L1InfoTreeLeafWithContext> for L1InfoTreeLeaf {
 
     #[inline]
     fn try_from(value: v1::L1InfoTreeLeafWithContext) -> Result<Self, Self::Error> {
+        try_fields! {
+            rer = required(value.rer, "rer"),
+            mer = required(value.mer, "mer"),
+            inner = required(value.inner, "inner"),
+        }
+
         Ok(L1InfoTreeLeaf {
             l1_info_tree_index: value.l1_info_tree_index,
-            rer: required_field!(value, rer),
-            mer: required_field!(value, mer),
-            inner: required_field!(value, inner),
+            rer,
+            mer,
+            inner,
         })
     }
 }
@@ -0,0 +1,47 @@
+use agglayer_interop_types::{Digest, LocalExitTree};
+
+use super::{repeated, Error};
+use crate::v1;
+
+impl<const TREE_DEPTH: usize> TryFrom<v1::LocalExitTree> for LocalExitTree<TREE_DEPTH> {
+    type Error = Error;
+
+    fn try_from(value: v1::LocalExitTree) -> Result<Self, Self::Error> {
+        // A full tree cannot be represented: its frontier would be ignored.
+        let max_leaf_count = 1u64
+            .checked_shl(TREE_DEPTH as u32)
+            .map_or(u64::MAX, |capacity| capacity - 1);
+        let leaf_count = if u64::from(value.leaf_count) > max_leaf_count {
+            Err(Error::invalid_data(format!(
+                "expected at most {max_leaf_count} leaves in a local exit tree of depth \
+                 {TREE_DEPTH}, got {}",
+                value.leaf_count
+            ))
+            .inside_field("leaf_count"))
+        } else {
+            Ok(value.leaf_count)
+        };
+        let frontier = if value.frontier.len() != TREE_DEPTH {
+            Err(Error::invalid_data(format!(
+                "expected {TREE_DEPTH} elements for local exit tree frontier, got {}",
+                value.frontier.len()
+            ))
+            .inside_field("frontier"))
+        } else {
+            repeated::<_, Digest>(value.frontier, "frontier")
+        };
+        try_fields!(leaf_count = leaf_count, frontier = frontier);
+        let frontier: [Digest; TREE_DEPTH] = frontier.try_into().unwrap(); // Length checked above
+        Ok(LocalExitTree::from_parts(leaf_count, frontier))
+    }
+}
+
+impl<const TREE_DEPTH: usize> From<LocalExitTree<TREE_DEPTH>> for v1::LocalExitTree {
+    #[inline]
+    fn from(value: LocalExitTree<TREE_DEPTH>) -> Self {
+        v1::LocalExitTree {
+            leaf_count: value.leaf_count,
+            frontier: value.frontier.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -1,26 +1,29 @@
 use agglayer_interop_types::{Digest, MerkleProof};
 
-use super::Error;
+use super::{repeated, required, Error};
 use crate::v1;
 
 impl TryFrom<v1::MerkleProof> for MerkleProof {
     type Error = Error;
 
     fn try_from(value: v1::MerkleProof) -> Result<Self, Self::Error> {
-        if value.siblings.len() != 32 {
-            return Err(Error::invalid_data(format!(
+        let siblings = if value.siblings.len() != 32 {
+            Err(Error::invalid_data(format!(
                 "expected 32 elements for merkle proof, got {}",
                 value.siblings.len()
-            )));
+            )))
+        } else {
+            repeated(value.siblings, "siblings")
+                // Length checked just above
+                .map(|siblings: Vec<Digest>| <[Digest; 32]>::try_from(siblings).unwrap())
+        };
+
+        try_fields! {
+            root = required(value.root, "root"),
+            siblings = siblings,
         }
-        let siblings: Vec<Digest> = value
-            .siblings
-            .into_iter()
-            .map(TryInto::try_into)
-            .collect::<Result<_, _>>()
-            .map_err(|e: Error| e.inside_field("siblings"))?;
-        let siblings: [Digest; 32] = siblings.try_into().unwrap(); // Checked just two statements above
-        Ok(MerkleProof::new(required_field!(value, root), siblings))
+
+        Ok(MerkleProof::new(root, siblings))
     }
 }
 
@@ -9,22 +9,88 @@ macro_rules! required_field {
     };
 }
 
+// Helper macro converting several fields at once, reporting the errors of all
+// of them instead of stopping at the first one
+macro_rules! try_fields {
+    ($($name:ident = $value:expr),+ $(,)?) => {
+        let ($($name,)+) = match ($($value,)+) {
+            ($(Ok($name),)+) => ($($name,)+),
+            ($($name,)+) => {
+                return Err(Error::combine([$($name.err()),+].into_iter().flatten())
+                    .expect("at least one field failed to convert"));
+            }
+        };
+    };
+}
+
 mod address;
 mod aggchain_data;
 mod bridge_exit;
 mod bytes;
+mod certificate;
 mod claim;
 mod digest;
 mod error;
 mod global_index;
 mod imported_bridge_exit;
 mod l1_info_tree_leaf;
+mod local_exit_tree;
 mod merkle_proof;
+#[cfg(feature = "service")]
+pub mod service;
+mod smt_proof;
 mod token_info;
 mod u256;
 
 pub use error::{Error, ErrorKind};
 
+/// Converts a required message field, like `required_field!` does for
+/// structs.
+pub(crate) fn required<T, U>(value: Option<T>, field: &'static str) -> Result<U, Error>
+where
+    T: TryInto<U, Error = Error>,
+{
+    value
+        .ok_or(Error::missing_field(field))?
+        .try_into()
+        .map_err(|e: Error| e.inside_field(field))
+}
+
+/// Converts an optional message field.
+pub(crate) fn optional<T, U>(value: Option<T>, field: &'static str) -> Result<Option<U>, Error>
+where
+    T: TryInto<U, Error = Error>,
+{
+    value
+        .map(TryInto::try_into)
+        .transpose()
+        .map_err(|e: Error| e.inside_field(field))
+}
+
+/// Converts a repeated message field, reporting the errors of all elements.
+pub(crate) fn repeated<T, U>(values: Vec<T>, field: &'static str) -> Result<Vec<U>, Error>
+where
+    T: TryInto<U, Error = Error>,
+{
+    let mut errors = Vec::new();
+    let values = values
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, value)| {
+            value
+                .try_into()
+                .map_err(|e: Error| errors.push(e.at_index(index).inside_field(field)))
+                .ok()
+        })
+        .collect();
+    match Error::combine(errors) {
+        Some(error) => Err(error),
+        None => Ok(values),
+    }
+}
+
+#[cfg(test)]
+mod golden;
 mod roots;
 #[cfg(test)]
 pub mod tests;
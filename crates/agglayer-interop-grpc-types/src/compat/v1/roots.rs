@@ -1,23 +1,39 @@
-use agglayer_interop_types::LocalExitRoot;
+use agglayer_interop_types::{
+    L1InfoRoot, LocalBalanceRoot, LocalExitRoot, LocalNullifierRoot, PessimisticRoot,
+};
 use prost::bytes::Bytes;
 
 use super::Error;
 use crate::v1;
 
-impl TryFrom<v1::FixedBytes32> for LocalExitRoot {
-    type Error = Error;
+macro_rules! impl_root_conversions {
+    ($($root:ty),* $(,)?) => {
+        $(
+            impl TryFrom<v1::FixedBytes32> for $root {
+                type Error = Error;
 
-    #[inline]
-    fn try_from(value: v1::FixedBytes32) -> Result<Self, Self::Error> {
-        Ok(LocalExitRoot::from(<[u8; 32]>::try_from(value)?))
-    }
-}
+                #[inline]
+                fn try_from(value: v1::FixedBytes32) -> Result<Self, Self::Error> {
+                    Ok(<$root>::from(<[u8; 32]>::try_from(value)?))
+                }
+            }
 
-impl From<LocalExitRoot> for v1::FixedBytes32 {
-    #[inline]
-    fn from(value: LocalExitRoot) -> Self {
-        v1::FixedBytes32 {
-            value: Bytes::copy_from_slice(value.as_ref()),
-        }
-    }
+            impl From<$root> for v1::FixedBytes32 {
+                #[inline]
+                fn from(value: $root) -> Self {
+                    v1::FixedBytes32 {
+                        value: Bytes::copy_from_slice(value.as_ref()),
+                    }
+                }
+            }
+        )*
+    };
 }
+
+impl_root_conversions!(
+    L1InfoRoot,
+    LocalBalanceRoot,
+    LocalExitRoot,
+    LocalNullifierRoot,
+    PessimisticRoot,
+);
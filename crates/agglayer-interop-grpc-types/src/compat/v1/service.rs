@@ -0,0 +1,490 @@
+//! gRPC server and client for the `agglayer.interop.service.v1` service,
+//! exposed in terms of the native [`agglayer_interop_types`].
+
+use agglayer_interop_types::{
+    certificate::{Certificate, CertificateHeader},
+    CertificateId, Height, L1InfoTreeLeaf, LocalExitRoot, NetworkId,
+};
+use tonic::Status;
+
+use super::Error;
+use crate::service::v1 as service;
+
+/// Handler of the `AgglayerService` RPCs operating on native types.
+///
+/// Wrap it with [`server`] to obtain a service that can be added to a
+/// [`tonic::transport::Server`]. The same handler can serve the v2 service
+/// too, by sharing it in an [`Arc`](std::sync::Arc) and wrapping it with
+/// [`v2::service::server`](crate::compat::v2::service::server).
+#[tonic::async_trait]
+pub trait AgglayerService: Send + Sync + 'static {
+    /// Submits a certificate and returns its identifier.
+    async fn submit_certificate(&self, certificate: Certificate) -> Result<CertificateId, Status>;
+
+    /// Returns the header of a previously submitted certificate.
+    async fn get_certificate_header(
+        &self,
+        certificate_id: CertificateId,
+    ) -> Result<CertificateHeader, Status>;
+
+    /// Returns the latest settled local exit root of a network along with the
+    /// height of the certificate which settled it.
+    async fn get_latest_settled_local_exit_root(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<(Height, LocalExitRoot), Status>;
+
+    /// Returns an entry of the L1 info tree.
+    async fn get_l1_info_tree_entry(
+        &self,
+        l1_info_tree_index: u32,
+    ) -> Result<L1InfoTreeLeaf, Status>;
+}
+
+#[tonic::async_trait]
+impl<S: AgglayerService + ?Sized> AgglayerService for std::sync::Arc<S> {
+    #[inline]
+    async fn submit_certificate(&self, certificate: Certificate) -> Result<CertificateId, Status> {
+        (**self).submit_certificate(certificate).await
+    }
+
+    #[inline]
+    async fn get_certificate_header(
+        &self,
+        certificate_id: CertificateId,
+    ) -> Result<CertificateHeader, Status> {
+        (**self).get_certificate_header(certificate_id).await
+    }
+
+    #[inline]
+    async fn get_latest_settled_local_exit_root(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<(Height, LocalExitRoot), Status> {
+        (**self)
+            .get_latest_settled_local_exit_root(network_id)
+            .await
+    }
+
+    #[inline]
+    async fn get_l1_info_tree_entry(
+        &self,
+        l1_info_tree_index: u32,
+    ) -> Result<L1InfoTreeLeaf, Status> {
+        (**self).get_l1_info_tree_entry(l1_info_tree_index).await
+    }
+}
+
+/// Defines the [`Adapter`], [`server`] and [`Client`] of a version of the
+/// service, from its generated code in the `$service` module.
+///
+/// All versions expose the same RPCs with the same message names, so only
+/// the generated code they wrap differs.
+macro_rules! native_service {
+    ($version:literal, $service:ident) => {
+        #[doc = concat!(
+            "Adapter converting the ", $version, " wire messages from and to the native types"
+        )]
+        /// of an [`AgglayerService`].
+        ///
+        /// [`AgglayerService`]: crate::compat::v1::service::AgglayerService
+        #[derive(Debug)]
+        pub struct Adapter<S>(S);
+
+        #[doc = concat!("Builds the ", $version, " gRPC server of an [`AgglayerService`].")]
+        ///
+        /// [`AgglayerService`]: crate::compat::v1::service::AgglayerService
+        #[inline]
+        pub fn server<S: $crate::compat::v1::service::AgglayerService>(
+            service: S,
+        ) -> $service::agglayer_service_server::AgglayerServiceServer<Adapter<S>> {
+            $service::agglayer_service_server::AgglayerServiceServer::new(Adapter(service))
+        }
+
+        #[::tonic::async_trait]
+        impl<S: $crate::compat::v1::service::AgglayerService>
+            $service::agglayer_service_server::AgglayerService for Adapter<S>
+        {
+            async fn submit_certificate(
+                &self,
+                request: ::tonic::Request<$service::SubmitCertificateRequest>,
+            ) -> Result<::tonic::Response<$service::SubmitCertificateResponse>, ::tonic::Status>
+            {
+                let request = request.into_inner();
+                let certificate = $crate::compat::v1::required(request.certificate, "certificate")
+                    .map_err(::tonic::Status::from)?;
+
+                let certificate_id = self.0.submit_certificate(certificate).await?;
+
+                Ok(::tonic::Response::new($service::SubmitCertificateResponse {
+                    certificate_id: Some(certificate_id.into()),
+                }))
+            }
+
+            async fn get_certificate_header(
+                &self,
+                request: ::tonic::Request<$service::GetCertificateHeaderRequest>,
+            ) -> Result<::tonic::Response<$service::GetCertificateHeaderResponse>, ::tonic::Status>
+            {
+                let request = request.into_inner();
+                let certificate_id =
+                    $crate::compat::v1::required(request.certificate_id, "certificate_id")
+                        .map_err(::tonic::Status::from)?;
+
+                let certificate_header = self.0.get_certificate_header(certificate_id).await?;
+
+                Ok(::tonic::Response::new($service::GetCertificateHeaderResponse {
+                    certificate_header: Some(certificate_header.into()),
+                }))
+            }
+
+            async fn get_latest_settled_local_exit_root(
+                &self,
+                request: ::tonic::Request<$service::GetLatestSettledLocalExitRootRequest>,
+            ) -> Result<
+                ::tonic::Response<$service::GetLatestSettledLocalExitRootResponse>,
+                ::tonic::Status,
+            > {
+                let network_id =
+                    ::agglayer_interop_types::NetworkId::new(request.into_inner().network_id);
+
+                let (height, local_exit_root) = self
+                    .0
+                    .get_latest_settled_local_exit_root(network_id)
+                    .await?;
+
+                Ok(::tonic::Response::new(
+                    $service::GetLatestSettledLocalExitRootResponse {
+                        height,
+                        local_exit_root: Some(local_exit_root.into()),
+                    },
+                ))
+            }
+
+            async fn get_l1_info_tree_entry(
+                &self,
+                request: ::tonic::Request<$service::GetL1InfoTreeEntryRequest>,
+            ) -> Result<::tonic::Response<$service::GetL1InfoTreeEntryResponse>, ::tonic::Status>
+            {
+                let l1_info_tree_index = request.into_inner().l1_info_tree_index;
+
+                let l1_info_tree_leaf = self.0.get_l1_info_tree_entry(l1_info_tree_index).await?;
+
+                Ok(::tonic::Response::new($service::GetL1InfoTreeEntryResponse {
+                    l1_info_tree_leaf: Some(l1_info_tree_leaf.into()),
+                }))
+            }
+        }
+
+        #[doc = concat!(
+            "gRPC client of the ", $version, " `AgglayerService` operating on native types."
+        )]
+        #[derive(Clone, Debug)]
+        pub struct Client {
+            inner: $service::agglayer_service_client::AgglayerServiceClient<
+                ::tonic::transport::Channel,
+            >,
+        }
+
+        impl Client {
+            /// Connects to the service at the given endpoint.
+            pub async fn connect<D>(dst: D) -> Result<Self, ::tonic::transport::Error>
+            where
+                D: TryInto<::tonic::transport::Endpoint>,
+                D::Error: Into<::tonic::codegen::StdError>,
+            {
+                Ok(Self::new(
+                    $service::agglayer_service_client::AgglayerServiceClient::connect(dst).await?,
+                ))
+            }
+
+            /// Wraps an existing generated client.
+            #[inline]
+            pub fn new(
+                inner: $service::agglayer_service_client::AgglayerServiceClient<
+                    ::tonic::transport::Channel,
+                >,
+            ) -> Self {
+                Self { inner }
+            }
+
+            /// Submits a certificate and returns its identifier.
+            pub async fn submit_certificate(
+                &mut self,
+                certificate: ::agglayer_interop_types::certificate::Certificate,
+            ) -> Result<::agglayer_interop_types::CertificateId, ClientError> {
+                let certificate = certificate.try_into().map_err(|e: $crate::compat::v1::Error| {
+                    ClientError::InvalidRequest(e.inside_field("certificate"))
+                })?;
+
+                let response = self
+                    .inner
+                    .submit_certificate($service::SubmitCertificateRequest {
+                        certificate: Some(certificate),
+                    })
+                    .await?
+                    .into_inner();
+
+                $crate::compat::v1::required(response.certificate_id, "certificate_id")
+                    .map_err(ClientError::InvalidResponse)
+            }
+
+            /// Returns the header of a previously submitted certificate.
+            pub async fn get_certificate_header(
+                &mut self,
+                certificate_id: ::agglayer_interop_types::CertificateId,
+            ) -> Result<::agglayer_interop_types::certificate::CertificateHeader, ClientError> {
+                let response = self
+                    .inner
+                    .get_certificate_header($service::GetCertificateHeaderRequest {
+                        certificate_id: Some(certificate_id.into()),
+                    })
+                    .await?
+                    .into_inner();
+
+                $crate::compat::v1::required(response.certificate_header, "certificate_header")
+                    .map_err(ClientError::InvalidResponse)
+            }
+
+            /// Returns the latest settled local exit root of a network along with
+            /// the height of the certificate which settled it.
+            pub async fn get_latest_settled_local_exit_root(
+                &mut self,
+                network_id: ::agglayer_interop_types::NetworkId,
+            ) -> Result<
+                (
+                    ::agglayer_interop_types::Height,
+                    ::agglayer_interop_types::LocalExitRoot,
+                ),
+                ClientError,
+            > {
+                let response = self
+                    .inner
+                    .get_latest_settled_local_exit_root(
+                        $service::GetLatestSettledLocalExitRootRequest {
+                            network_id: network_id.into(),
+                        },
+                    )
+                    .await?
+                    .into_inner();
+
+                let local_exit_root =
+                    $crate::compat::v1::required(response.local_exit_root, "local_exit_root")
+                        .map_err(ClientError::InvalidResponse)?;
+
+                Ok((response.height, local_exit_root))
+            }
+
+            /// Returns an entry of the L1 info tree.
+            pub async fn get_l1_info_tree_entry(
+                &mut self,
+                l1_info_tree_index: u32,
+            ) -> Result<::agglayer_interop_types::L1InfoTreeLeaf, ClientError> {
+                let response = self
+                    .inner
+                    .get_l1_info_tree_entry($service::GetL1InfoTreeEntryRequest {
+                        l1_info_tree_index,
+                    })
+                    .await?
+                    .into_inner();
+
+                $crate::compat::v1::required(response.l1_info_tree_leaf, "l1_info_tree_leaf")
+                    .map_err(ClientError::InvalidResponse)
+            }
+        }
+    };
+}
+
+pub(crate) use native_service;
+
+/// Error returned by the [`Client`].
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The request could not be encoded.
+    #[error("invalid request: {0}")]
+    InvalidRequest(#[source] Error),
+
+    /// The server answered with an error status.
+    #[error(transparent)]
+    Status(#[from] Status),
+
+    /// The response could not be decoded.
+    #[error("invalid response: {0}")]
+    InvalidResponse(#[source] Error),
+}
+
+native_service!("v1", service);
+
+#[cfg(test)]
+mod tests {
+    use agglayer_interop_types::{
+        certificate::{CertificateHeader, CertificateStatus},
+        Digest, NetworkId,
+    };
+    use tonic::{transport::server::TcpIncoming, Code};
+
+    use super::{Client, ClientError};
+    use crate::{
+        compat::testutils::{certificate, l1_info_tree_leaf, InMemoryAgglayer},
+        service::v1 as service,
+    };
+
+    async fn spawn_server() -> (
+        Client,
+        service::agglayer_service_client::AgglayerServiceClient<tonic::transport::Channel>,
+    ) {
+        let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let addr = incoming.local_addr().unwrap();
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(super::server(InMemoryAgglayer::default()))
+                .serve_with_incoming(incoming),
+        );
+
+        let channel = tonic::transport::Endpoint::from_shared(format!("http://{addr}"))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        let raw = service::agglayer_service_client::AgglayerServiceClient::new(channel);
+        (Client::new(raw.clone()), raw)
+    }
+
+    #[tokio::test]
+    async fn submit_and_query_certificates() {
+        let (mut client, _) = spawn_server().await;
+
+        let first = client.submit_certificate(certificate(0)).await.unwrap();
+        let second = client.submit_certificate(certificate(1)).await.unwrap();
+        assert_ne!(first, second);
+
+        let header = client.get_certificate_header(second).await.unwrap();
+        assert_eq!(
+            header,
+            CertificateHeader {
+                network_id: NetworkId::new(3),
+                height: 1,
+                epoch_number: None,
+                certificate_index: None,
+                certificate_id: second,
+                prev_local_exit_root: certificate(1).prev_local_exit_root,
+                new_local_exit_root: certificate(1).new_local_exit_root,
+                metadata: Digest([0x22; 32]),
+                status: CertificateStatus::InError {
+                    error: "not settled in tests".to_owned(),
+                },
+                settlement_tx_hash: None,
+            }
+        );
+
+        let latest = client
+            .get_latest_settled_local_exit_root(NetworkId::new(3))
+            .await
+            .unwrap();
+        assert_eq!(latest, (1, certificate(1).new_local_exit_root));
+
+        let entry = client.get_l1_info_tree_entry(42).await.unwrap();
+        assert_eq!(entry, l1_info_tree_leaf(42));
+    }
+
+    #[tokio::test]
+    async fn handler_errors_are_forwarded() {
+        let (mut client, _) = spawn_server().await;
+
+        let error = client
+            .get_certificate_header(Digest([0xff; 32]))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ClientError::Status(s) if s.code() == Code::NotFound));
+
+        let error = client
+            .get_latest_settled_local_exit_root(NetworkId::new(9))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ClientError::Status(s) if s.code() == Code::NotFound));
+    }
+
+    #[tokio::test]
+    async fn malformed_requests_are_rejected() {
+        let (_, mut raw) = spawn_server().await;
+
+        let status = raw
+            .submit_certificate(service::SubmitCertificateRequest { certificate: None })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(status.message(), "certificate: required field is missing");
+
+        let mut certificate: service::Certificate = certificate(0).try_into().unwrap();
+        certificate.bridge_exits[0].amount = None;
+        let status = raw
+            .submit_certificate(service::SubmitCertificateRequest {
+                certificate: Some(certificate),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(
+            status.message(),
+            "certificate.bridge_exits[0].amount: required field is missing"
+        );
+    }
+
+    #[tokio::test]
+    async fn all_violations_are_reported() {
+        use tonic_types::StatusExt as _;
+
+        let (_, mut raw) = spawn_server().await;
+
+        let mut certificate: service::Certificate = certificate(0).try_into().unwrap();
+        certificate.prev_local_exit_root = None;
+        certificate.bridge_exits[0].amount = None;
+        certificate.bridge_exits[0].leaf_type = 42;
+        certificate.metadata = Some(crate::v1::FixedBytes32 {
+            value: vec![0; 3].into(),
+        });
+        let status = raw
+            .submit_certificate(service::SubmitCertificateRequest {
+                certificate: Some(certificate),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(
+            status.message(),
+            "certificate.prev_local_exit_root: required field is missing (and 3 more errors)"
+        );
+
+        let info = status.get_details_error_info().unwrap();
+        assert_eq!(info.reason, "MISSING_FIELD");
+        assert_eq!(info.domain, "agglayer.interop");
+        assert_eq!(info.metadata["field"], "certificate.prev_local_exit_root");
+
+        let violations: Vec<_> = status
+            .get_details_bad_request()
+            .unwrap()
+            .field_violations
+            .into_iter()
+            .map(|v| (v.field, v.description))
+            .collect();
+        assert_eq!(
+            violations,
+            [
+                (
+                    "certificate.prev_local_exit_root",
+                    "required field is missing"
+                ),
+                (
+                    "certificate.bridge_exits[0].leaf_type",
+                    "invalid leaf type: 42"
+                ),
+                (
+                    "certificate.bridge_exits[0].amount",
+                    "required field is missing"
+                ),
+                ("certificate.metadata", "expected 32 bytes, got 3"),
+            ]
+            .map(|(field, description)| (field.to_owned(), description.to_owned()))
+        );
+    }
+}
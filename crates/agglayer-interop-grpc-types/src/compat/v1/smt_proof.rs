@@ -0,0 +1,56 @@
+use agglayer_interop_types::{Digest, SmtMerkleProof, SmtNonInclusionProof};
+
+use super::{repeated, Error};
+use crate::v1;
+
+impl<const DEPTH: usize> TryFrom<v1::SmtMerkleProof> for SmtMerkleProof<DEPTH> {
+    type Error = Error;
+
+    fn try_from(value: v1::SmtMerkleProof) -> Result<Self, Self::Error> {
+        if value.siblings.len() != DEPTH {
+            return Err(Error::invalid_data(format!(
+                "expected {DEPTH} elements for smt merkle proof, got {}",
+                value.siblings.len()
+            ))
+            .inside_field("siblings"));
+        }
+        let siblings: Vec<Digest> = repeated(value.siblings, "siblings")?;
+        let siblings: [Digest; DEPTH] = siblings.try_into().unwrap(); // Checked just two statements above
+        Ok(SmtMerkleProof { siblings })
+    }
+}
+
+impl<const DEPTH: usize> From<SmtMerkleProof<DEPTH>> for v1::SmtMerkleProof {
+    #[inline]
+    fn from(value: SmtMerkleProof<DEPTH>) -> Self {
+        v1::SmtMerkleProof {
+            siblings: value.siblings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const DEPTH: usize> TryFrom<v1::SmtNonInclusionProof> for SmtNonInclusionProof<DEPTH> {
+    type Error = Error;
+
+    fn try_from(value: v1::SmtNonInclusionProof) -> Result<Self, Self::Error> {
+        if value.siblings.len() > DEPTH {
+            return Err(Error::invalid_data(format!(
+                "expected at most {DEPTH} elements for smt non-inclusion proof, got {}",
+                value.siblings.len()
+            ))
+            .inside_field("siblings"));
+        }
+        Ok(SmtNonInclusionProof {
+            siblings: repeated(value.siblings, "siblings")?,
+        })
+    }
+}
+
+impl<const DEPTH: usize> From<SmtNonInclusionProof<DEPTH>> for v1::SmtNonInclusionProof {
+    #[inline]
+    fn from(value: SmtNonInclusionProof<DEPTH>) -> Self {
+        v1::SmtNonInclusionProof {
+            siblings: value.siblings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use agglayer_interop_types::{
     aggchain_proof::{
         AggchainData, AggchainProof, AggchainProofPublicValues, MultisigPayload, Proof,
-        SP1StarkWithContext,
+        SP1StarkWithContext, PUBLIC_VALUES_CONTEXT_KEY,
     },
     primitives::SignatureError,
     Address, BridgeExit, ClaimFromMainnet, ClaimFromRollup, Digest, GlobalIndex,
@@ -51,6 +51,33 @@ fn multisig_decode_rejects_signer_index_overflow() {
     assert_eq!(err.to_string(), "Multisig ECDSA signer index overflow");
 }
 
+#[test]
+fn claim_decode_reports_all_violations() {
+    let mut proof_leaf_mer: v1::MerkleProof =
+        MerkleProof::new(Digest([0x11; 32]), [Digest([0x22; 32]); 32]).into();
+    proof_leaf_mer.siblings[3] = v1::FixedBytes32 {
+        value: vec![0; 3].into(),
+    };
+    let claim = v1::ClaimFromMainnet {
+        proof_leaf_mer: Some(proof_leaf_mer),
+        proof_ger_l1root: None,
+        l1_leaf: Some(v1::L1InfoTreeLeafWithContext::default()),
+    };
+
+    let err = ClaimFromMainnet::try_from(claim).unwrap_err();
+    let fields: Vec<_> = err.errors().map(Error::field_str).collect();
+    assert_eq!(
+        fields,
+        [
+            "proof_leaf_mer.siblings[3]",
+            "proof_ger_l1root",
+            "l1_leaf.rer",
+            "l1_leaf.mer",
+            "l1_leaf.inner",
+        ]
+    );
+}
+
 macro_rules! make_parser_fuzzers {
     ($test:ident, $proto:ty, $type:ty) => {
         #[test]
@@ -97,6 +124,26 @@ make_parser_fuzzers!(
 make_parser_fuzzers!(fuzz_parser_merkle_proof, v1::MerkleProof, MerkleProof);
 make_parser_fuzzers!(fuzz_parser_token_info, v1::TokenInfo, TokenInfo);
 make_parser_fuzzers!(fuzz_parser_u256, v1::FixedBytes32, U256);
+make_parser_fuzzers!(
+    fuzz_parser_local_exit_tree,
+    v1::LocalExitTree,
+    agglayer_interop_types::LocalExitTree
+);
+make_parser_fuzzers!(
+    fuzz_parser_smt_merkle_proof,
+    v1::SmtMerkleProof,
+    agglayer_interop_types::SmtMerkleProof<32>
+);
+make_parser_fuzzers!(
+    fuzz_parser_smt_non_inclusion_proof,
+    v1::SmtNonInclusionProof,
+    agglayer_interop_types::SmtNonInclusionProof<32>
+);
+make_parser_fuzzers!(
+    fuzz_parser_local_exit_root,
+    v1::FixedBytes32,
+    agglayer_interop_types::LocalExitRoot
+);
 
 macro_rules! make_round_trip_fuzzers {
     ($test:ident, $proto:ty, $type:ty) => {
@@ -114,6 +161,107 @@ macro_rules! make_round_trip_fuzzers {
 }
 
 make_round_trip_fuzzers!(fuzz_round_trip_address, v1::FixedBytes20, Address);
+make_round_trip_fuzzers!(
+    fuzz_round_trip_local_exit_tree,
+    v1::LocalExitTree,
+    agglayer_interop_types::LocalExitTree
+);
+make_round_trip_fuzzers!(
+    fuzz_round_trip_smt_merkle_proof,
+    v1::SmtMerkleProof,
+    agglayer_interop_types::SmtMerkleProof<32>
+);
+make_round_trip_fuzzers!(
+    fuzz_round_trip_smt_non_inclusion_proof,
+    v1::SmtNonInclusionProof,
+    agglayer_interop_types::SmtNonInclusionProof<32>
+);
+make_round_trip_fuzzers!(
+    fuzz_round_trip_l1_info_root,
+    v1::FixedBytes32,
+    agglayer_interop_types::L1InfoRoot
+);
+make_round_trip_fuzzers!(
+    fuzz_round_trip_local_balance_root,
+    v1::FixedBytes32,
+    agglayer_interop_types::LocalBalanceRoot
+);
+make_round_trip_fuzzers!(
+    fuzz_round_trip_local_exit_root,
+    v1::FixedBytes32,
+    agglayer_interop_types::LocalExitRoot
+);
+make_round_trip_fuzzers!(
+    fuzz_round_trip_local_nullifier_root,
+    v1::FixedBytes32,
+    agglayer_interop_types::LocalNullifierRoot
+);
+make_round_trip_fuzzers!(
+    fuzz_round_trip_pessimistic_root,
+    v1::FixedBytes32,
+    agglayer_interop_types::PessimisticRoot
+);
+
+#[test]
+fn smt_proofs_reject_wrong_sibling_counts() {
+    use agglayer_interop_types::{SmtMerkleProof, SmtNonInclusionProof};
+
+    let siblings = vec![v1::FixedBytes32::from(Digest([0x11; 32])); 33];
+
+    let err = SmtMerkleProof::<32>::try_from(v1::SmtMerkleProof {
+        siblings: siblings.clone(),
+    })
+    .unwrap_err();
+    assert!(matches!(err.kind(), super::ErrorKind::InvalidData));
+    assert_eq!(err.field_str(), "siblings");
+
+    let err =
+        SmtNonInclusionProof::<32>::try_from(v1::SmtNonInclusionProof { siblings }).unwrap_err();
+    assert!(matches!(err.kind(), super::ErrorKind::InvalidData));
+    assert_eq!(err.field_str(), "siblings");
+}
+
+#[test]
+fn local_exit_tree_decode_reports_all_violations() {
+    use agglayer_interop_types::LocalExitTree;
+
+    let mut frontier = vec![v1::FixedBytes32::from(Digest([0x11; 32])); 3];
+    frontier[1] = v1::FixedBytes32 {
+        value: vec![0; 3].into(),
+    };
+    let err = LocalExitTree::<3>::try_from(v1::LocalExitTree {
+        leaf_count: 9,
+        frontier,
+    })
+    .unwrap_err();
+    let fields: Vec<_> = err.errors().map(Error::field_str).collect();
+    assert_eq!(fields, ["leaf_count", "frontier[1]"]);
+
+    let err = LocalExitTree::<3>::try_from(v1::LocalExitTree {
+        leaf_count: 7,
+        frontier: vec![v1::FixedBytes32::from(Digest([0x11; 32])); 4],
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "frontier: expected 3 elements for local exit tree frontier, got 4"
+    );
+
+    let err = LocalExitTree::<3>::try_from(v1::LocalExitTree {
+        leaf_count: 8,
+        frontier: vec![v1::FixedBytes32::from(Digest([0x11; 32])); 3],
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "leaf_count: expected at most 7 leaves in a local exit tree of depth 3, got 8"
+    );
+    assert!(LocalExitTree::<3>::try_from(v1::LocalExitTree {
+        leaf_count: 7,
+        frontier: vec![v1::FixedBytes32::from(Digest([0x11; 32])); 3],
+    })
+    .is_ok());
+}
 
 fn sample_public_values() -> AggchainProofPublicValues {
     AggchainProofPublicValues {
@@ -145,6 +293,7 @@ fn sample_generic(public_values: Option<Box<AggchainProofPublicValues>>) -> Aggc
         aggchain_params: Digest([0x77; 32]),
         signature: None,
         public_values,
+        context: Default::default(),
     }
 }
 
@@ -153,6 +302,7 @@ fn sample_aggchain_proof(public_values: Option<Box<AggchainProofPublicValues>>)
         proof: sample_proof(),
         aggchain_params: Digest([0x88; 32]),
         public_values,
+        context: Default::default(),
     }
 }
 
@@ -332,6 +482,90 @@ fn aggchain_proof_v1_decode_accepts_absent_public_values() {
     assert_eq!(decoded, sample_aggchain_proof(None));
 }
 
+#[test]
+fn aggchain_proof_v1_round_trip_preserves_unknown_context_entries() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let proto = v1::AggchainProof {
+        proof: Some(sample_proof().try_into().unwrap()),
+        aggchain_params: Some(Digest([0x88; 32]).into()),
+        signature: None,
+        context: HashMap::from([
+            (
+                "public_values".to_owned(),
+                BARE_PUBLIC_VALUES_EXPECTED_BYTES.into(),
+            ),
+            ("l2_block_range".to_owned(), vec![0x01, 0x02, 0x03].into()),
+            ("empty".to_owned(), Vec::new().into()),
+        ]),
+    };
+
+    let decoded = AggchainProof::try_from(proto.clone()).unwrap();
+
+    assert_eq!(
+        decoded.context,
+        BTreeMap::from([
+            ("empty".to_owned(), vec![]),
+            ("l2_block_range".to_owned(), vec![0x01, 0x02, 0x03]),
+        ])
+    );
+    assert_eq!(
+        decoded.public_values.as_deref(),
+        Some(&sample_public_values())
+    );
+
+    let round_trip: v1::AggchainProof = decoded.try_into().unwrap();
+
+    assert_eq!(round_trip, proto);
+}
+
+#[test]
+fn generic_v1_round_trip_preserves_unknown_context_entries() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let proto = v1::AggchainData {
+        data: Some(v1::aggchain_data::Data::Generic(v1::AggchainProof {
+            proof: Some(sample_proof().try_into().unwrap()),
+            aggchain_params: Some(Digest([0x77; 32]).into()),
+            signature: None,
+            context: HashMap::from([
+                (
+                    "public_values".to_owned(),
+                    GENERIC_NONE_PUBLIC_VALUES_EXPECTED_BYTES.into(),
+                ),
+                ("l2_block_range".to_owned(), vec![0x01, 0x02, 0x03].into()),
+            ]),
+        })),
+    };
+
+    let decoded = AggchainData::try_from(proto.clone()).unwrap();
+    let AggchainData::Generic { context, .. } = &decoded else {
+        panic!("expected Generic aggchain data");
+    };
+
+    assert_eq!(
+        context,
+        &BTreeMap::from([("l2_block_range".to_owned(), vec![0x01, 0x02, 0x03])])
+    );
+
+    let round_trip: v1::AggchainData = decoded.try_into().unwrap();
+
+    assert_eq!(round_trip, proto);
+}
+
+#[test]
+fn aggchain_proof_v1_encode_rejects_reserved_context_key() {
+    let mut input = sample_aggchain_proof(None);
+    input
+        .context
+        .insert(PUBLIC_VALUES_CONTEXT_KEY.to_owned(), vec![0x00]);
+
+    let err = v1::AggchainProof::try_from(input).unwrap_err();
+
+    assert!(matches!(err.kind(), super::ErrorKind::InvalidData));
+    assert_eq!(err.field(), &["context"]);
+}
+
 #[rstest::rstest]
 #[case("v4.0.0-rc.3", vec![0x04, 0x00, 0x03], vec![0xa4, 0x03])]
 #[case("v6.0.0", vec![0x06, 0x00, 0x00, 0x01], vec![0xb6, 0x00, 0x02])]
@@ -360,7 +594,26 @@ fn fuzz_round_trip_aggchain_data() {
     bolero::check!()
         .with_arbitrary::<AggchainData>()
         .for_each(|input| {
-            let proto: v1::AggchainData = input.clone().try_into().unwrap();
+            // The public values context key is reserved and cannot be encoded
+            let has_reserved_context_key = match &input {
+                AggchainData::Generic { context, .. }
+                | AggchainData::MultisigAndAggchainProof {
+                    aggchain_proof: AggchainProof { context, .. },
+                    ..
+                } => context.contains_key(PUBLIC_VALUES_CONTEXT_KEY),
+                _ => false,
+            };
+
+            let proto: v1::AggchainData = match input.clone().try_into() {
+                Ok(proto) => {
+                    assert!(!has_reserved_context_key);
+                    proto
+                }
+                Err(err) => {
+                    assert!(has_reserved_context_key, "Unexpected encoding error: {err}");
+                    return;
+                }
+            };
 
             // Check if input has empty multisig signatures
             let has_empty_multisig = match &input {
@@ -0,0 +1,117 @@
+use agglayer_interop_types::aggchain_proof::AggchainData;
+
+use crate::{compat::v1::Error, v1, v2};
+
+impl From<v1::AggchainProof> for v2::AggchainProof {
+    #[allow(deprecated)]
+    fn from(value: v1::AggchainProof) -> Self {
+        v2::AggchainProof {
+            aggchain_params: value.aggchain_params,
+            signature: value.signature,
+            context: value.context,
+            proof: value.proof.map(|proof| match proof {
+                v1::aggchain_proof::Proof::Sp1Stark(proof) => {
+                    v2::aggchain_proof::Proof::Sp1Stark(proof)
+                }
+            }),
+        }
+    }
+}
+
+impl TryFrom<v2::AggchainProof> for v1::AggchainProof {
+    type Error = Error;
+
+    #[allow(deprecated)]
+    fn try_from(value: v2::AggchainProof) -> Result<Self, Self::Error> {
+        let proof = match value.proof {
+            Some(v2::aggchain_proof::Proof::Sp1Stark(proof)) => {
+                Some(v1::aggchain_proof::Proof::Sp1Stark(proof))
+            }
+            Some(v2::aggchain_proof::Proof::Compressed(_)) => {
+                return Err(Error::unrepresentable("v1").inside_field("compressed"));
+            }
+            None => None,
+        };
+
+        Ok(v1::AggchainProof {
+            aggchain_params: value.aggchain_params,
+            signature: value.signature,
+            context: value.context,
+            proof,
+        })
+    }
+}
+
+impl From<v1::AggchainData> for v2::AggchainData {
+    fn from(value: v1::AggchainData) -> Self {
+        use v1::aggchain_data::Data as V1;
+        use v2::aggchain_data::Data as V2;
+
+        v2::AggchainData {
+            data: value.data.map(|data| match data {
+                V1::Signature(signature) => V2::Signature(signature),
+                V1::Generic(proof) => V2::Generic(proof.into()),
+                V1::Multisig(multisig) => V2::Multisig(multisig),
+                V1::MultisigAndAggchainProof(data) => {
+                    V2::MultisigAndAggchainProof(v2::AggchainProofWithMultisig {
+                        multisig: data.multisig,
+                        aggchain_proof: data.aggchain_proof.map(Into::into),
+                    })
+                }
+            }),
+        }
+    }
+}
+
+impl TryFrom<v2::AggchainData> for v1::AggchainData {
+    type Error = Error;
+
+    fn try_from(value: v2::AggchainData) -> Result<Self, Self::Error> {
+        use v1::aggchain_data::Data as V1;
+        use v2::aggchain_data::Data as V2;
+
+        let data = match value.data {
+            Some(V2::Signature(signature)) => Some(V1::Signature(signature)),
+            Some(V2::Generic(proof)) => Some(V1::Generic(
+                proof
+                    .try_into()
+                    .map_err(|e: Error| e.inside_field("generic"))?,
+            )),
+            Some(V2::Multisig(multisig)) => Some(V1::Multisig(multisig)),
+            Some(V2::MultisigAndAggchainProof(data)) => Some(V1::MultisigAndAggchainProof(
+                v1::AggchainProofWithMultisig {
+                    multisig: data.multisig,
+                    aggchain_proof: data
+                        .aggchain_proof
+                        .map(TryInto::try_into)
+                        .transpose()
+                        .map_err(|e: Error| {
+                            e.inside_field("aggchain_proof")
+                                .inside_field("multisig_and_aggchain_proof")
+                        })?,
+                },
+            )),
+            None => None,
+        };
+
+        Ok(v1::AggchainData { data })
+    }
+}
+
+impl TryFrom<v2::AggchainData> for AggchainData {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: v2::AggchainData) -> Result<Self, Self::Error> {
+        v1::AggchainData::try_from(value)?.try_into()
+    }
+}
+
+impl TryFrom<AggchainData> for v2::AggchainData {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: AggchainData) -> Result<Self, Self::Error> {
+        v1::AggchainData::try_from(value).map(Into::into)
+    }
+}
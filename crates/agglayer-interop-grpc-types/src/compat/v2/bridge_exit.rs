@@ -0,0 +1,76 @@
+use agglayer_interop_types::BridgeExit;
+
+use crate::{compat::v1::Error, v1, v2};
+
+impl TryFrom<v1::BridgeExit> for v2::BridgeExit {
+    type Error = Error;
+
+    fn try_from(value: v1::BridgeExit) -> Result<Self, Self::Error> {
+        let exit = match value.leaf_type() {
+            v1::LeafType::Transfer => v2::bridge_exit::Exit::Asset(v2::AssetExit {
+                token_info: value.token_info,
+                dest_network: value.dest_network,
+                dest_address: value.dest_address,
+                amount: value.amount,
+                metadata: value.metadata,
+            }),
+            v1::LeafType::Message => v2::bridge_exit::Exit::Message(v2::MessageExit {
+                token_info: value.token_info,
+                dest_network: value.dest_network,
+                dest_address: value.dest_address,
+                amount: value.amount,
+                metadata: value.metadata,
+            }),
+            v1::LeafType::Unspecified => {
+                let t = value.leaf_type;
+                return Err(Error::invalid_data(format!("invalid leaf type: {t}"))
+                    .inside_field("leaf_type"));
+            }
+        };
+
+        Ok(v2::BridgeExit { exit: Some(exit) })
+    }
+}
+
+impl TryFrom<v2::BridgeExit> for v1::BridgeExit {
+    type Error = Error;
+
+    fn try_from(value: v2::BridgeExit) -> Result<Self, Self::Error> {
+        Ok(match value.exit.ok_or(Error::missing_field("exit"))? {
+            v2::bridge_exit::Exit::Asset(exit) => v1::BridgeExit {
+                leaf_type: v1::LeafType::Transfer.into(),
+                token_info: exit.token_info,
+                dest_network: exit.dest_network,
+                dest_address: exit.dest_address,
+                amount: exit.amount,
+                metadata: exit.metadata,
+            },
+            v2::bridge_exit::Exit::Message(exit) => v1::BridgeExit {
+                leaf_type: v1::LeafType::Message.into(),
+                token_info: exit.token_info,
+                dest_network: exit.dest_network,
+                dest_address: exit.dest_address,
+                amount: exit.amount,
+                metadata: exit.metadata,
+            },
+        })
+    }
+}
+
+impl TryFrom<v2::BridgeExit> for BridgeExit {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: v2::BridgeExit) -> Result<Self, Self::Error> {
+        v1::BridgeExit::try_from(value)?.try_into()
+    }
+}
+
+impl From<BridgeExit> for v2::BridgeExit {
+    #[inline]
+    fn from(value: BridgeExit) -> Self {
+        v1::BridgeExit::from(value)
+            .try_into()
+            .expect("native bridge exits have a valid leaf type")
+    }
+}
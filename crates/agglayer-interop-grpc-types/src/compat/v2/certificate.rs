@@ -0,0 +1,71 @@
+use agglayer_interop_types::certificate::Certificate;
+
+use crate::{
+    compat::v1::{repeated, Error},
+    service::{v1 as service_v1, v2 as service_v2},
+};
+
+impl TryFrom<service_v1::Certificate> for service_v2::Certificate {
+    type Error = Error;
+
+    fn try_from(value: service_v1::Certificate) -> Result<Self, Self::Error> {
+        Ok(service_v2::Certificate {
+            network_id: value.network_id,
+            height: value.height,
+            prev_local_exit_root: value.prev_local_exit_root,
+            new_local_exit_root: value.new_local_exit_root,
+            bridge_exits: repeated(value.bridge_exits, "bridge_exits")?,
+            imported_bridge_exits: value.imported_bridge_exits,
+            metadata: value.metadata,
+            aggchain_data: value.aggchain_data.map(Into::into),
+            custom_chain_data: value.custom_chain_data,
+            l1_info_tree_leaf_count: value.l1_info_tree_leaf_count,
+        })
+    }
+}
+
+impl TryFrom<service_v2::Certificate> for service_v1::Certificate {
+    type Error = Error;
+
+    fn try_from(value: service_v2::Certificate) -> Result<Self, Self::Error> {
+        try_fields! {
+            bridge_exits = repeated(value.bridge_exits, "bridge_exits"),
+            aggchain_data = value
+                .aggchain_data
+                .map(TryInto::try_into)
+                .transpose()
+                .map_err(|e: Error| e.inside_field("aggchain_data")),
+        }
+
+        Ok(service_v1::Certificate {
+            network_id: value.network_id,
+            height: value.height,
+            prev_local_exit_root: value.prev_local_exit_root,
+            new_local_exit_root: value.new_local_exit_root,
+            bridge_exits,
+            imported_bridge_exits: value.imported_bridge_exits,
+            metadata: value.metadata,
+            aggchain_data,
+            custom_chain_data: value.custom_chain_data,
+            l1_info_tree_leaf_count: value.l1_info_tree_leaf_count,
+        })
+    }
+}
+
+impl TryFrom<service_v2::Certificate> for Certificate {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: service_v2::Certificate) -> Result<Self, Self::Error> {
+        service_v1::Certificate::try_from(value)?.try_into()
+    }
+}
+
+impl TryFrom<Certificate> for service_v2::Certificate {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: Certificate) -> Result<Self, Self::Error> {
+        service_v1::Certificate::try_from(value)?.try_into()
+    }
+}
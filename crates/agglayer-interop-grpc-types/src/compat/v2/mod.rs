@@ -0,0 +1,18 @@
+//! Conversions of the v2 messages, from and to the v1 messages and the native
+//! types.
+//!
+//! The native types mirror the v1 messages, so conversions between v2
+//! messages and native types go through v1. A v2 message holding something
+//! that v1 cannot represent, like a compressed proof, is rejected with an
+//! [`ErrorKind::Unrepresentable`] error rather than losing data.
+//!
+//! [`ErrorKind::Unrepresentable`]: super::v1::ErrorKind::Unrepresentable
+
+mod aggchain_data;
+mod bridge_exit;
+mod certificate;
+#[cfg(feature = "service")]
+pub mod service;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+//! gRPC server and client for the `agglayer.interop.service.v2` service,
+//! exposed in terms of the native [`agglayer_interop_types`].
+//!
+//! Both versions of the service are served by the same
+//! [`AgglayerService`] handler, so that v1 clients keep working against a
+//! node speaking v2.
+
+pub use crate::compat::v1::service::{AgglayerService, ClientError};
+use crate::{compat::v1::service::native_service, service::v2 as service};
+
+native_service!("v2", service);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tonic::{transport::server::TcpIncoming, Code};
+
+    use super::Client;
+    use crate::{
+        compat::{
+            testutils::{certificate, InMemoryAgglayer},
+            v1::service::Client as V1Client,
+        },
+        service::v2 as service,
+        v1, v2,
+    };
+
+    /// Spawns a node serving both versions of the service from one handler.
+    async fn spawn_server() -> (V1Client, Client, tonic::transport::Channel) {
+        let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let addr = incoming.local_addr().unwrap();
+        let handler = Arc::new(InMemoryAgglayer::default());
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(crate::compat::v1::service::server(handler.clone()))
+                .add_service(super::server(handler))
+                .serve_with_incoming(incoming),
+        );
+
+        let channel = tonic::transport::Endpoint::from_shared(format!("http://{addr}"))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        (
+            V1Client::new(
+                crate::service::v1::agglayer_service_client::AgglayerServiceClient::new(
+                    channel.clone(),
+                ),
+            ),
+            Client::new(
+                service::agglayer_service_client::AgglayerServiceClient::new(channel.clone()),
+            ),
+            channel,
+        )
+    }
+
+    #[tokio::test]
+    async fn v1_clients_keep_working_against_v2_nodes() {
+        let (mut v1_client, mut v2_client, _) = spawn_server().await;
+
+        let from_v1 = v1_client.submit_certificate(certificate(0)).await.unwrap();
+        let from_v2 = v2_client.submit_certificate(certificate(1)).await.unwrap();
+
+        // Certificates submitted through either version are visible to both.
+        assert_eq!(
+            v2_client.get_certificate_header(from_v1).await.unwrap(),
+            v1_client.get_certificate_header(from_v1).await.unwrap(),
+        );
+        assert_eq!(
+            v1_client
+                .get_certificate_header(from_v2)
+                .await
+                .unwrap()
+                .height,
+            1
+        );
+        assert_eq!(
+            v1_client
+                .get_latest_settled_local_exit_root(certificate(1).network_id)
+                .await
+                .unwrap(),
+            v2_client
+                .get_latest_settled_local_exit_root(certificate(1).network_id)
+                .await
+                .unwrap(),
+        );
+    }
+
+    #[tokio::test]
+    async fn unrepresentable_proofs_are_rejected() {
+        let (_, _, channel) = spawn_server().await;
+        let mut raw = service::agglayer_service_client::AgglayerServiceClient::new(channel);
+
+        let mut certificate: service::Certificate = certificate(0).try_into().unwrap();
+        certificate.aggchain_data = Some(v2::AggchainData {
+            data: Some(v2::aggchain_data::Data::Generic(v2::AggchainProof {
+                aggchain_params: Some(v1::FixedBytes32 {
+                    value: vec![0; 32].into(),
+                }),
+                proof: Some(v2::aggchain_proof::Proof::Compressed(v2::CompressedProof {
+                    compression: v2::Compression::Zstd.into(),
+                    data: vec![0x28, 0xb5, 0x2f, 0xfd].into(),
+                })),
+                ..Default::default()
+            })),
+        });
+
+        let status = raw
+            .submit_certificate(service::SubmitCertificateRequest {
+                certificate: Some(certificate),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(
+            status.message(),
+            "certificate.aggchain_data.generic.compressed: cannot be represented in v1"
+        );
+    }
+}
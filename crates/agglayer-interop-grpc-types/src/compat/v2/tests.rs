@@ -0,0 +1,142 @@
+use agglayer_interop_types::{aggchain_proof::AggchainData, BridgeExit};
+use prost::Message;
+
+use crate::{
+    compat::v1::{Error, ErrorKind},
+    v1, v2,
+};
+
+fn aggchain_proof(proof: v2::aggchain_proof::Proof) -> v2::AggchainProof {
+    v2::AggchainProof {
+        aggchain_params: Some(v1::FixedBytes32 {
+            value: vec![0x77; 32].into(),
+        }),
+        context: [("key".to_owned(), vec![0x01].into())].into(),
+        proof: Some(proof),
+        ..Default::default()
+    }
+}
+
+fn sp1_stark() -> v2::aggchain_proof::Proof {
+    v2::aggchain_proof::Proof::Sp1Stark(v1::Sp1StarkProof {
+        version: "v6.0.0".to_owned(),
+        proof: vec![0x06, 0x00].into(),
+        vkey: vec![0xb6].into(),
+    })
+}
+
+fn compressed() -> v2::aggchain_proof::Proof {
+    v2::aggchain_proof::Proof::Compressed(v2::CompressedProof {
+        compression: v2::Compression::Zstd.into(),
+        data: vec![0x28, 0xb5, 0x2f, 0xfd].into(),
+    })
+}
+
+#[test]
+fn aggchain_data_is_wire_compatible() {
+    let v2 = v2::AggchainData {
+        data: Some(v2::aggchain_data::Data::Generic(
+            aggchain_proof(sp1_stark()),
+        )),
+    };
+
+    // v2 messages without v2-only fields decode as v1 and vice versa.
+    let v1 = v1::AggchainData::decode(v2.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(v1::AggchainData::try_from(v2.clone()).unwrap(), v1);
+    assert_eq!(
+        v2::AggchainData::decode(v1.encode_to_vec().as_slice()).unwrap(),
+        v2
+    );
+    assert_eq!(v2::AggchainData::from(v1), v2);
+}
+
+#[test]
+fn unrepresentable_fields_are_reported() {
+    let v2 = v2::AggchainData {
+        data: Some(v2::aggchain_data::Data::MultisigAndAggchainProof(
+            v2::AggchainProofWithMultisig {
+                multisig: None,
+                aggchain_proof: Some(aggchain_proof(compressed())),
+            },
+        )),
+    };
+
+    // A v1 decoder silently drops the compressed proof...
+    let v1 = v1::AggchainData::decode(v2.encode_to_vec().as_slice()).unwrap();
+    let Some(v1::aggchain_data::Data::MultisigAndAggchainProof(data)) = v1.data else {
+        panic!("expected multisig and aggchain proof");
+    };
+    assert_eq!(data.aggchain_proof.unwrap().proof, None);
+
+    // ...whereas the conversion reports it.
+    let err: Error = v1::AggchainData::try_from(v2.clone()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Unrepresentable));
+    assert_eq!(
+        err.to_string(),
+        "multisig_and_aggchain_proof.aggchain_proof.compressed: cannot be represented in v1"
+    );
+
+    let err = AggchainData::try_from(v2).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Unrepresentable));
+}
+
+#[test]
+fn deprecated_signature_is_carried_over() {
+    let signature = v1::FixedBytes65 {
+        value: vec![0x42; 65].into(),
+    };
+    let v1 = v1::AggchainProof {
+        signature: Some(signature.clone()),
+        ..Default::default()
+    };
+
+    #[allow(deprecated)]
+    let carried = v2::AggchainProof::from(v1.clone()).signature;
+    assert_eq!(carried, Some(signature));
+    assert_eq!(
+        v1::AggchainProof::try_from(v2::AggchainProof::from(v1.clone())).unwrap(),
+        v1
+    );
+}
+
+#[test]
+fn bridge_exits_are_typed() {
+    let v1 = v1::BridgeExit {
+        leaf_type: v1::LeafType::Message.into(),
+        dest_network: 2,
+        ..Default::default()
+    };
+
+    let v2 = v2::BridgeExit::try_from(v1.clone()).unwrap();
+    assert!(matches!(
+        v2.exit,
+        Some(v2::bridge_exit::Exit::Message(v2::MessageExit {
+            dest_network: 2,
+            ..
+        }))
+    ));
+    assert_eq!(v1::BridgeExit::try_from(v2).unwrap(), v1);
+
+    let err = v2::BridgeExit::try_from(v1::BridgeExit::default()).unwrap_err();
+    assert_eq!(err.to_string(), "leaf_type: invalid leaf type: 0");
+}
+
+#[test]
+fn fuzz_round_trip_bridge_exit() {
+    bolero::check!()
+        .with_arbitrary::<BridgeExit>()
+        .for_each(|input: &BridgeExit| {
+            let proto: v2::BridgeExit = input.clone().into();
+            let output = BridgeExit::try_from(proto).unwrap();
+            assert_eq!(input, &output);
+        })
+}
+
+#[test]
+fn fuzz_parser_aggchain_data() {
+    bolero::check!().for_each(|bytes| {
+        if let Ok(proto) = v2::AggchainData::decode(bytes) {
+            let _ = AggchainData::try_from(proto);
+        };
+    })
+}
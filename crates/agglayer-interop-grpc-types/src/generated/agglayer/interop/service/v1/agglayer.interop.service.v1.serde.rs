@@ -0,0 +1,1483 @@
+// @generated
+impl serde::Serialize for Certificate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.network_id != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.prev_local_exit_root.is_some() {
+            len += 1;
+        }
+        if self.new_local_exit_root.is_some() {
+            len += 1;
+        }
+        if !self.bridge_exits.is_empty() {
+            len += 1;
+        }
+        if !self.imported_bridge_exits.is_empty() {
+            len += 1;
+        }
+        if self.metadata.is_some() {
+            len += 1;
+        }
+        if self.aggchain_data.is_some() {
+            len += 1;
+        }
+        if !self.custom_chain_data.is_empty() {
+            len += 1;
+        }
+        if self.l1_info_tree_leaf_count.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.Certificate", len)?;
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.prev_local_exit_root.as_ref() {
+            struct_ser.serialize_field("prevLocalExitRoot", v)?;
+        }
+        if let Some(v) = self.new_local_exit_root.as_ref() {
+            struct_ser.serialize_field("newLocalExitRoot", v)?;
+        }
+        if !self.bridge_exits.is_empty() {
+            struct_ser.serialize_field("bridgeExits", &self.bridge_exits)?;
+        }
+        if !self.imported_bridge_exits.is_empty() {
+            struct_ser.serialize_field("importedBridgeExits", &self.imported_bridge_exits)?;
+        }
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
+        }
+        if let Some(v) = self.aggchain_data.as_ref() {
+            struct_ser.serialize_field("aggchainData", v)?;
+        }
+        if !self.custom_chain_data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("customChainData", pbjson::private::base64::encode(&self.custom_chain_data).as_str())?;
+        }
+        if let Some(v) = self.l1_info_tree_leaf_count.as_ref() {
+            struct_ser.serialize_field("l1InfoTreeLeafCount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Certificate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_id",
+            "networkId",
+            "height",
+            "prev_local_exit_root",
+            "prevLocalExitRoot",
+            "new_local_exit_root",
+            "newLocalExitRoot",
+            "bridge_exits",
+            "bridgeExits",
+            "imported_bridge_exits",
+            "importedBridgeExits",
+            "metadata",
+            "aggchain_data",
+            "aggchainData",
+            "custom_chain_data",
+            "customChainData",
+            "l1_info_tree_leaf_count",
+            "l1InfoTreeLeafCount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkId,
+            Height,
+            PrevLocalExitRoot,
+            NewLocalExitRoot,
+            BridgeExits,
+            ImportedBridgeExits,
+            Metadata,
+            AggchainData,
+            CustomChainData,
+            L1InfoTreeLeafCount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            "height" => Ok(GeneratedField::Height),
+                            "prevLocalExitRoot" | "prev_local_exit_root" => Ok(GeneratedField::PrevLocalExitRoot),
+                            "newLocalExitRoot" | "new_local_exit_root" => Ok(GeneratedField::NewLocalExitRoot),
+                            "bridgeExits" | "bridge_exits" => Ok(GeneratedField::BridgeExits),
+                            "importedBridgeExits" | "imported_bridge_exits" => Ok(GeneratedField::ImportedBridgeExits),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            "aggchainData" | "aggchain_data" => Ok(GeneratedField::AggchainData),
+                            "customChainData" | "custom_chain_data" => Ok(GeneratedField::CustomChainData),
+                            "l1InfoTreeLeafCount" | "l1_info_tree_leaf_count" => Ok(GeneratedField::L1InfoTreeLeafCount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Certificate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.Certificate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Certificate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_id__ = None;
+                let mut height__ = None;
+                let mut prev_local_exit_root__ = None;
+                let mut new_local_exit_root__ = None;
+                let mut bridge_exits__ = None;
+                let mut imported_bridge_exits__ = None;
+                let mut metadata__ = None;
+                let mut aggchain_data__ = None;
+                let mut custom_chain_data__ = None;
+                let mut l1_info_tree_leaf_count__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PrevLocalExitRoot => {
+                            if prev_local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevLocalExitRoot"));
+                            }
+                            prev_local_exit_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewLocalExitRoot => {
+                            if new_local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newLocalExitRoot"));
+                            }
+                            new_local_exit_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::BridgeExits => {
+                            if bridge_exits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeExits"));
+                            }
+                            bridge_exits__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ImportedBridgeExits => {
+                            if imported_bridge_exits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("importedBridgeExits"));
+                            }
+                            imported_bridge_exits__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::AggchainData => {
+                            if aggchain_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggchainData"));
+                            }
+                            aggchain_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::CustomChainData => {
+                            if custom_chain_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("customChainData"));
+                            }
+                            custom_chain_data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::L1InfoTreeLeafCount => {
+                            if l1_info_tree_leaf_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l1InfoTreeLeafCount"));
+                            }
+                            l1_info_tree_leaf_count__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Certificate {
+                    network_id: network_id__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    prev_local_exit_root: prev_local_exit_root__,
+                    new_local_exit_root: new_local_exit_root__,
+                    bridge_exits: bridge_exits__.unwrap_or_default(),
+                    imported_bridge_exits: imported_bridge_exits__.unwrap_or_default(),
+                    metadata: metadata__,
+                    aggchain_data: aggchain_data__,
+                    custom_chain_data: custom_chain_data__.unwrap_or_default(),
+                    l1_info_tree_leaf_count: l1_info_tree_leaf_count__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.Certificate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CertificateHeader {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.network_id != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.epoch_number.is_some() {
+            len += 1;
+        }
+        if self.certificate_index.is_some() {
+            len += 1;
+        }
+        if self.certificate_id.is_some() {
+            len += 1;
+        }
+        if self.prev_local_exit_root.is_some() {
+            len += 1;
+        }
+        if self.new_local_exit_root.is_some() {
+            len += 1;
+        }
+        if self.metadata.is_some() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.error.is_some() {
+            len += 1;
+        }
+        if self.settlement_tx_hash.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.CertificateHeader", len)?;
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.epoch_number.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("epochNumber", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.certificate_index.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("certificateIndex", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.certificate_id.as_ref() {
+            struct_ser.serialize_field("certificateId", v)?;
+        }
+        if let Some(v) = self.prev_local_exit_root.as_ref() {
+            struct_ser.serialize_field("prevLocalExitRoot", v)?;
+        }
+        if let Some(v) = self.new_local_exit_root.as_ref() {
+            struct_ser.serialize_field("newLocalExitRoot", v)?;
+        }
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
+        }
+        if self.status != 0 {
+            let v = CertificateStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            struct_ser.serialize_field("error", v)?;
+        }
+        if let Some(v) = self.settlement_tx_hash.as_ref() {
+            struct_ser.serialize_field("settlementTxHash", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CertificateHeader {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_id",
+            "networkId",
+            "height",
+            "epoch_number",
+            "epochNumber",
+            "certificate_index",
+            "certificateIndex",
+            "certificate_id",
+            "certificateId",
+            "prev_local_exit_root",
+            "prevLocalExitRoot",
+            "new_local_exit_root",
+            "newLocalExitRoot",
+            "metadata",
+            "status",
+            "error",
+            "settlement_tx_hash",
+            "settlementTxHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkId,
+            Height,
+            EpochNumber,
+            CertificateIndex,
+            CertificateId,
+            PrevLocalExitRoot,
+            NewLocalExitRoot,
+            Metadata,
+            Status,
+            Error,
+            SettlementTxHash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            "height" => Ok(GeneratedField::Height),
+                            "epochNumber" | "epoch_number" => Ok(GeneratedField::EpochNumber),
+                            "certificateIndex" | "certificate_index" => Ok(GeneratedField::CertificateIndex),
+                            "certificateId" | "certificate_id" => Ok(GeneratedField::CertificateId),
+                            "prevLocalExitRoot" | "prev_local_exit_root" => Ok(GeneratedField::PrevLocalExitRoot),
+                            "newLocalExitRoot" | "new_local_exit_root" => Ok(GeneratedField::NewLocalExitRoot),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            "status" => Ok(GeneratedField::Status),
+                            "error" => Ok(GeneratedField::Error),
+                            "settlementTxHash" | "settlement_tx_hash" => Ok(GeneratedField::SettlementTxHash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CertificateHeader;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.CertificateHeader")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CertificateHeader, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_id__ = None;
+                let mut height__ = None;
+                let mut epoch_number__ = None;
+                let mut certificate_index__ = None;
+                let mut certificate_id__ = None;
+                let mut prev_local_exit_root__ = None;
+                let mut new_local_exit_root__ = None;
+                let mut metadata__ = None;
+                let mut status__ = None;
+                let mut error__ = None;
+                let mut settlement_tx_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EpochNumber => {
+                            if epoch_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochNumber"));
+                            }
+                            epoch_number__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::CertificateIndex => {
+                            if certificate_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateIndex"));
+                            }
+                            certificate_index__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::CertificateId => {
+                            if certificate_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateId"));
+                            }
+                            certificate_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::PrevLocalExitRoot => {
+                            if prev_local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevLocalExitRoot"));
+                            }
+                            prev_local_exit_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewLocalExitRoot => {
+                            if new_local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newLocalExitRoot"));
+                            }
+                            new_local_exit_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<CertificateStatus>()? as i32);
+                        }
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            error__ = map_.next_value()?;
+                        }
+                        GeneratedField::SettlementTxHash => {
+                            if settlement_tx_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settlementTxHash"));
+                            }
+                            settlement_tx_hash__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(CertificateHeader {
+                    network_id: network_id__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    epoch_number: epoch_number__,
+                    certificate_index: certificate_index__,
+                    certificate_id: certificate_id__,
+                    prev_local_exit_root: prev_local_exit_root__,
+                    new_local_exit_root: new_local_exit_root__,
+                    metadata: metadata__,
+                    status: status__.unwrap_or_default(),
+                    error: error__,
+                    settlement_tx_hash: settlement_tx_hash__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.CertificateHeader", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CertificateId {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.CertificateId", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CertificateId {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CertificateId;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.CertificateId")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CertificateId, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(CertificateId {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.CertificateId", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CertificateStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "CERTIFICATE_STATUS_UNSPECIFIED",
+            Self::Pending => "CERTIFICATE_STATUS_PENDING",
+            Self::Proven => "CERTIFICATE_STATUS_PROVEN",
+            Self::Candidate => "CERTIFICATE_STATUS_CANDIDATE",
+            Self::InError => "CERTIFICATE_STATUS_IN_ERROR",
+            Self::Settled => "CERTIFICATE_STATUS_SETTLED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for CertificateStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "CERTIFICATE_STATUS_UNSPECIFIED",
+            "CERTIFICATE_STATUS_PENDING",
+            "CERTIFICATE_STATUS_PROVEN",
+            "CERTIFICATE_STATUS_CANDIDATE",
+            "CERTIFICATE_STATUS_IN_ERROR",
+            "CERTIFICATE_STATUS_SETTLED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CertificateStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "CERTIFICATE_STATUS_UNSPECIFIED" => Ok(CertificateStatus::Unspecified),
+                    "CERTIFICATE_STATUS_PENDING" => Ok(CertificateStatus::Pending),
+                    "CERTIFICATE_STATUS_PROVEN" => Ok(CertificateStatus::Proven),
+                    "CERTIFICATE_STATUS_CANDIDATE" => Ok(CertificateStatus::Candidate),
+                    "CERTIFICATE_STATUS_IN_ERROR" => Ok(CertificateStatus::InError),
+                    "CERTIFICATE_STATUS_SETTLED" => Ok(CertificateStatus::Settled),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetCertificateHeaderRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.GetCertificateHeaderRequest", len)?;
+        if let Some(v) = self.certificate_id.as_ref() {
+            struct_ser.serialize_field("certificateId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetCertificateHeaderRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate_id",
+            "certificateId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CertificateId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificateId" | "certificate_id" => Ok(GeneratedField::CertificateId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetCertificateHeaderRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.GetCertificateHeaderRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetCertificateHeaderRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CertificateId => {
+                            if certificate_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateId"));
+                            }
+                            certificate_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GetCertificateHeaderRequest {
+                    certificate_id: certificate_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.GetCertificateHeaderRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetCertificateHeaderResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate_header.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.GetCertificateHeaderResponse", len)?;
+        if let Some(v) = self.certificate_header.as_ref() {
+            struct_ser.serialize_field("certificateHeader", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetCertificateHeaderResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate_header",
+            "certificateHeader",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CertificateHeader,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificateHeader" | "certificate_header" => Ok(GeneratedField::CertificateHeader),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetCertificateHeaderResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.GetCertificateHeaderResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetCertificateHeaderResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate_header__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CertificateHeader => {
+                            if certificate_header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateHeader"));
+                            }
+                            certificate_header__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GetCertificateHeaderResponse {
+                    certificate_header: certificate_header__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.GetCertificateHeaderResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetL1InfoTreeEntryRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.l1_info_tree_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.GetL1InfoTreeEntryRequest", len)?;
+        if self.l1_info_tree_index != 0 {
+            struct_ser.serialize_field("l1InfoTreeIndex", &self.l1_info_tree_index)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetL1InfoTreeEntryRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "l1_info_tree_index",
+            "l1InfoTreeIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            L1InfoTreeIndex,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "l1InfoTreeIndex" | "l1_info_tree_index" => Ok(GeneratedField::L1InfoTreeIndex),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetL1InfoTreeEntryRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.GetL1InfoTreeEntryRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetL1InfoTreeEntryRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut l1_info_tree_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::L1InfoTreeIndex => {
+                            if l1_info_tree_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l1InfoTreeIndex"));
+                            }
+                            l1_info_tree_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GetL1InfoTreeEntryRequest {
+                    l1_info_tree_index: l1_info_tree_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.GetL1InfoTreeEntryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetL1InfoTreeEntryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.l1_info_tree_leaf.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.GetL1InfoTreeEntryResponse", len)?;
+        if let Some(v) = self.l1_info_tree_leaf.as_ref() {
+            struct_ser.serialize_field("l1InfoTreeLeaf", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetL1InfoTreeEntryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "l1_info_tree_leaf",
+            "l1InfoTreeLeaf",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            L1InfoTreeLeaf,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "l1InfoTreeLeaf" | "l1_info_tree_leaf" => Ok(GeneratedField::L1InfoTreeLeaf),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetL1InfoTreeEntryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.GetL1InfoTreeEntryResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetL1InfoTreeEntryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut l1_info_tree_leaf__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::L1InfoTreeLeaf => {
+                            if l1_info_tree_leaf__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l1InfoTreeLeaf"));
+                            }
+                            l1_info_tree_leaf__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GetL1InfoTreeEntryResponse {
+                    l1_info_tree_leaf: l1_info_tree_leaf__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.GetL1InfoTreeEntryResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetLatestSettledLocalExitRootRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.network_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.GetLatestSettledLocalExitRootRequest", len)?;
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetLatestSettledLocalExitRootRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_id",
+            "networkId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetLatestSettledLocalExitRootRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.GetLatestSettledLocalExitRootRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetLatestSettledLocalExitRootRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GetLatestSettledLocalExitRootRequest {
+                    network_id: network_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.GetLatestSettledLocalExitRootRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetLatestSettledLocalExitRootResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.local_exit_root.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.GetLatestSettledLocalExitRootResponse", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.local_exit_root.as_ref() {
+            struct_ser.serialize_field("localExitRoot", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetLatestSettledLocalExitRootResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "local_exit_root",
+            "localExitRoot",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            LocalExitRoot,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "localExitRoot" | "local_exit_root" => Ok(GeneratedField::LocalExitRoot),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetLatestSettledLocalExitRootResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.GetLatestSettledLocalExitRootResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetLatestSettledLocalExitRootResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut local_exit_root__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LocalExitRoot => {
+                            if local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("localExitRoot"));
+                            }
+                            local_exit_root__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GetLatestSettledLocalExitRootResponse {
+                    height: height__.unwrap_or_default(),
+                    local_exit_root: local_exit_root__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.GetLatestSettledLocalExitRootResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitCertificateRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.SubmitCertificateRequest", len)?;
+        if let Some(v) = self.certificate.as_ref() {
+            struct_ser.serialize_field("certificate", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitCertificateRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Certificate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificate" => Ok(GeneratedField::Certificate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitCertificateRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.SubmitCertificateRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitCertificateRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Certificate => {
+                            if certificate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificate"));
+                            }
+                            certificate__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SubmitCertificateRequest {
+                    certificate: certificate__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.SubmitCertificateRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitCertificateResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.service.v1.SubmitCertificateResponse", len)?;
+        if let Some(v) = self.certificate_id.as_ref() {
+            struct_ser.serialize_field("certificateId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitCertificateResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate_id",
+            "certificateId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CertificateId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificateId" | "certificate_id" => Ok(GeneratedField::CertificateId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitCertificateResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.service.v1.SubmitCertificateResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitCertificateResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CertificateId => {
+                            if certificate_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateId"));
+                            }
+                            certificate_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SubmitCertificateResponse {
+                    certificate_id: certificate_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.service.v1.SubmitCertificateResponse", FIELDS, GeneratedVisitor)
+    }
+}
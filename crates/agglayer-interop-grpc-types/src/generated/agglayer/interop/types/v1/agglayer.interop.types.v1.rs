@@ -304,6 +304,33 @@ pub mod imported_bridge_exit {
         Rollup(super::ClaimFromRollup),
     }
 }
+/// Represents a local exit tree by its frontier.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LocalExitTree {
+    /// The number of inserted (non-empty) leaves.
+    #[prost(uint32, tag = "1")]
+    pub leaf_count: u32,
+    /// The frontier, with hashes from bottom to top. It must contain exactly
+    /// one entry per level of the tree.
+    #[prost(message, repeated, tag = "2")]
+    pub frontier: ::prost::alloc::vec::Vec<FixedBytes32>,
+}
+/// Represents an inclusion proof in a sparse Merkle tree.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SmtMerkleProof {
+    /// The siblings from the leaf to the root. It must contain exactly one
+    /// entry per level of the tree.
+    #[prost(message, repeated, tag = "1")]
+    pub siblings: ::prost::alloc::vec::Vec<FixedBytes32>,
+}
+/// Represents a non-inclusion proof in a sparse Merkle tree.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SmtNonInclusionProof {
+    /// The siblings from the root down to the first empty subtree. It must not
+    /// contain more entries than the depth of the tree.
+    #[prost(message, repeated, tag = "1")]
+    pub siblings: ::prost::alloc::vec::Vec<FixedBytes32>,
+}
 /// Encoded file descriptor set for the `agglayer.interop.types.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x0a, 0xbd, 0x06, 0x0a, 0x25, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x69, 0x6e,
@@ -1087,7 +1114,124 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x6c, 0x6c, 0x75, 0x70, 0x2e, 0x0a,
     0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x19, 0x14, 0x1a, 0x0a, 0x0c,
     0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x19, 0x1d, 0x1e, 0x62, 0x06, 0x70, 0x72,
-    0x6f, 0x74, 0x6f, 0x33,
+    0x6f, 0x74, 0x6f, 0x33, 0x0a, 0xb5, 0x06, 0x0a, 0x2f, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f,
+    0x76, 0x31, 0x2f, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x74, 0x72,
+    0x65, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x19, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73,
+    0x2e, 0x76, 0x31, 0x1a, 0x25, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x69, 0x6e,
+    0x74, 0x65, 0x72, 0x6f, 0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x62,
+    0x79, 0x74, 0x65, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x73, 0x0a, 0x0d, 0x4c, 0x6f,
+    0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72, 0x65, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x6c,
+    0x65, 0x61, 0x66, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52,
+    0x09, 0x6c, 0x65, 0x61, 0x66, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x43, 0x0a, 0x08, 0x66, 0x72,
+    0x6f, 0x6e, 0x74, 0x69, 0x65, 0x72, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e,
+    0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79,
+    0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x08, 0x66, 0x72, 0x6f, 0x6e, 0x74, 0x69, 0x65, 0x72, 0x42,
+    0xba, 0x01, 0x0a, 0x1d, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
+    0x31, 0x42, 0x12, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72, 0x65, 0x65,
+    0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x49, 0x54, 0xaa, 0x02, 0x19,
+    0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70,
+    0x2e, 0x54, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x19, 0x41, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x5c, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x5c, 0x54, 0x79, 0x70,
+    0x65, 0x73, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x25, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x5c, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x5c, 0x54, 0x79, 0x70, 0x65, 0x73, 0x5c, 0x56,
+    0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x1c,
+    0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6f,
+    0x70, 0x3a, 0x3a, 0x54, 0x79, 0x70, 0x65, 0x73, 0x3a, 0x3a, 0x56, 0x31, 0x4a, 0x85, 0x03, 0x0a,
+    0x06, 0x12, 0x04, 0x00, 0x00, 0x0d, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00,
+    0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x22, 0x0a, 0x09, 0x0a, 0x02, 0x03,
+    0x00, 0x12, 0x03, 0x04, 0x00, 0x2f, 0x0a, 0x3b, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x07, 0x00,
+    0x0d, 0x01, 0x1a, 0x2f, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73, 0x20,
+    0x61, 0x20, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x74, 0x72, 0x65,
+    0x65, 0x20, 0x62, 0x79, 0x20, 0x69, 0x74, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6e, 0x74, 0x69, 0x65,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x07, 0x08, 0x15, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x09, 0x02, 0x08, 0x0a, 0x39, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x09, 0x02, 0x18, 0x1a, 0x2c, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x6e, 0x73, 0x65, 0x72,
+    0x74, 0x65, 0x64, 0x20, 0x28, 0x6e, 0x6f, 0x6e, 0x2d, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x29, 0x20,
+    0x6c, 0x65, 0x61, 0x76, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x09, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x09, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0c,
+    0x02, 0x0a, 0x0a, 0x76, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x02, 0x25, 0x1a,
+    0x69, 0x20, 0x54, 0x68, 0x65, 0x20, 0x66, 0x72, 0x6f, 0x6e, 0x74, 0x69, 0x65, 0x72, 0x2c, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x68, 0x61, 0x73, 0x68, 0x65, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d,
+    0x20, 0x62, 0x6f, 0x74, 0x74, 0x6f, 0x6d, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x6f, 0x70, 0x2e, 0x20,
+    0x49, 0x74, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x20,
+    0x65, 0x78, 0x61, 0x63, 0x74, 0x6c, 0x79, 0x0a, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x65, 0x6e, 0x74,
+    0x72, 0x79, 0x20, 0x70, 0x65, 0x72, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x65, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x0c, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x0c, 0x18, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x0c, 0x23, 0x24, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33, 0x0a, 0x9f, 0x08, 0x0a,
+    0x29, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f,
+    0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x73, 0x6d, 0x74, 0x5f, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x19, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x76, 0x31, 0x1a, 0x25, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f,
+    0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31,
+    0x2f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x55, 0x0a, 0x0e,
+    0x53, 0x6d, 0x74, 0x4d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x43,
+    0x0a, 0x08, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78,
+    0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x08, 0x73, 0x69, 0x62, 0x6c, 0x69,
+    0x6e, 0x67, 0x73, 0x22, 0x5b, 0x0a, 0x14, 0x53, 0x6d, 0x74, 0x4e, 0x6f, 0x6e, 0x49, 0x6e, 0x63,
+    0x6c, 0x75, 0x73, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x43, 0x0a, 0x08, 0x73,
+    0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27, 0x2e,
+    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70,
+    0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42,
+    0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x08, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73,
+    0x42, 0xb5, 0x01, 0x0a, 0x1d, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x42, 0x0d, 0x53, 0x6d, 0x74, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x50, 0x72, 0x6f, 0x74,
+    0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x49, 0x54, 0xaa, 0x02, 0x19, 0x41, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x54, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x19, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x5c, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x5c, 0x54, 0x79, 0x70, 0x65, 0x73, 0x5c, 0x56,
+    0x31, 0xe2, 0x02, 0x25, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x49, 0x6e, 0x74,
+    0x65, 0x72, 0x6f, 0x70, 0x5c, 0x54, 0x79, 0x70, 0x65, 0x73, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50,
+    0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x1c, 0x41, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x3a, 0x3a, 0x54,
+    0x79, 0x70, 0x65, 0x73, 0x3a, 0x3a, 0x56, 0x31, 0x4a, 0xbb, 0x04, 0x0a, 0x06, 0x12, 0x04, 0x00,
+    0x00, 0x12, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a,
+    0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x22, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04,
+    0x00, 0x2f, 0x0a, 0x44, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x07, 0x00, 0x0b, 0x01, 0x1a, 0x38,
+    0x20, 0x52, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x69,
+    0x6e, 0x63, 0x6c, 0x75, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x69,
+    0x6e, 0x20, 0x61, 0x20, 0x73, 0x70, 0x61, 0x72, 0x73, 0x65, 0x20, 0x4d, 0x65, 0x72, 0x6b, 0x6c,
+    0x65, 0x20, 0x74, 0x72, 0x65, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x07, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0a,
+    0x02, 0x0a, 0x0a, 0x70, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0a, 0x02, 0x25, 0x1a,
+    0x63, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x20, 0x66,
+    0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x20, 0x74, 0x6f, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x6d, 0x75, 0x73,
+    0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x20, 0x65, 0x78, 0x61, 0x63, 0x74, 0x6c,
+    0x79, 0x20, 0x6f, 0x6e, 0x65, 0x0a, 0x20, 0x65, 0x6e, 0x74, 0x72, 0x79, 0x20, 0x70, 0x65, 0x72,
+    0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
+    0x65, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0a,
+    0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x18, 0x20,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0a, 0x23, 0x24, 0x0a, 0x47,
+    0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0e, 0x00, 0x12, 0x01, 0x1a, 0x3b, 0x20, 0x52, 0x65, 0x70,
+    0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x20, 0x6e, 0x6f, 0x6e, 0x2d, 0x69, 0x6e,
+    0x63, 0x6c, 0x75, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x69, 0x6e,
+    0x20, 0x61, 0x20, 0x73, 0x70, 0x61, 0x72, 0x73, 0x65, 0x20, 0x4d, 0x65, 0x72, 0x6b, 0x6c, 0x65,
+    0x20, 0x74, 0x72, 0x65, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03,
+    0x0e, 0x08, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x11, 0x02,
+    0x0a, 0x0a, 0x88, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x25, 0x1a,
+    0x7b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x20, 0x66,
+    0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x64, 0x6f, 0x77,
+    0x6e, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x65,
+    0x6d, 0x70, 0x74, 0x79, 0x20, 0x73, 0x75, 0x62, 0x74, 0x72, 0x65, 0x65, 0x2e, 0x20, 0x49, 0x74,
+    0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x6e, 0x6f, 0x74, 0x0a, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61,
+    0x69, 0x6e, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x65, 0x6e, 0x74, 0x72, 0x69, 0x65, 0x73, 0x20,
+    0x74, 0x68, 0x61, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x70, 0x74, 0x68, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x65, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x11, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x18, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x11, 0x23, 0x24, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("agglayer.interop.types.v1.serde.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
@@ -1837,6 +1837,117 @@ impl<'de> serde::Deserialize<'de> for LeafType {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for LocalExitTree {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.leaf_count != 0 {
+            len += 1;
+        }
+        if !self.frontier.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v1.LocalExitTree", len)?;
+        if self.leaf_count != 0 {
+            struct_ser.serialize_field("leafCount", &self.leaf_count)?;
+        }
+        if !self.frontier.is_empty() {
+            struct_ser.serialize_field("frontier", &self.frontier)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LocalExitTree {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "leaf_count",
+            "leafCount",
+            "frontier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LeafCount,
+            Frontier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "leafCount" | "leaf_count" => Ok(GeneratedField::LeafCount),
+                            "frontier" => Ok(GeneratedField::Frontier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LocalExitTree;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v1.LocalExitTree")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LocalExitTree, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut leaf_count__ = None;
+                let mut frontier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LeafCount => {
+                            if leaf_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leafCount"));
+                            }
+                            leaf_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Frontier => {
+                            if frontier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frontier"));
+                            }
+                            frontier__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(LocalExitTree {
+                    leaf_count: leaf_count__.unwrap_or_default(),
+                    frontier: frontier__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v1.LocalExitTree", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for MerkleProof {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2174,6 +2285,188 @@ impl<'de> serde::Deserialize<'de> for Sp1StarkProof {
         deserializer.deserialize_struct("agglayer.interop.types.v1.SP1StarkProof", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SmtMerkleProof {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.siblings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v1.SmtMerkleProof", len)?;
+        if !self.siblings.is_empty() {
+            struct_ser.serialize_field("siblings", &self.siblings)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SmtMerkleProof {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "siblings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Siblings,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "siblings" => Ok(GeneratedField::Siblings),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SmtMerkleProof;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v1.SmtMerkleProof")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SmtMerkleProof, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut siblings__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Siblings => {
+                            if siblings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("siblings"));
+                            }
+                            siblings__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SmtMerkleProof {
+                    siblings: siblings__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v1.SmtMerkleProof", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SmtNonInclusionProof {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.siblings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v1.SmtNonInclusionProof", len)?;
+        if !self.siblings.is_empty() {
+            struct_ser.serialize_field("siblings", &self.siblings)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SmtNonInclusionProof {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "siblings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Siblings,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "siblings" => Ok(GeneratedField::Siblings),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SmtNonInclusionProof;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v1.SmtNonInclusionProof")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SmtNonInclusionProof, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut siblings__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Siblings => {
+                            if siblings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("siblings"));
+                            }
+                            siblings__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SmtNonInclusionProof {
+                    siblings: siblings__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v1.SmtNonInclusionProof", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TokenInfo {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
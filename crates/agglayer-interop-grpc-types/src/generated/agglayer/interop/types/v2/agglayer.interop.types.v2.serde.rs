@@ -0,0 +1,1035 @@
+// @generated
+impl serde::Serialize for AggchainData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v2.AggchainData", len)?;
+        if let Some(v) = self.data.as_ref() {
+            match v {
+                aggchain_data::Data::Signature(v) => {
+                    struct_ser.serialize_field("signature", v)?;
+                }
+                aggchain_data::Data::Generic(v) => {
+                    struct_ser.serialize_field("generic", v)?;
+                }
+                aggchain_data::Data::Multisig(v) => {
+                    struct_ser.serialize_field("multisig", v)?;
+                }
+                aggchain_data::Data::MultisigAndAggchainProof(v) => {
+                    struct_ser.serialize_field("multisigAndAggchainProof", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signature",
+            "generic",
+            "multisig",
+            "multisig_and_aggchain_proof",
+            "multisigAndAggchainProof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signature,
+            Generic,
+            Multisig,
+            MultisigAndAggchainProof,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signature" => Ok(GeneratedField::Signature),
+                            "generic" => Ok(GeneratedField::Generic),
+                            "multisig" => Ok(GeneratedField::Multisig),
+                            "multisigAndAggchainProof" | "multisig_and_aggchain_proof" => Ok(GeneratedField::MultisigAndAggchainProof),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v2.AggchainData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AggchainData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Signature => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            data__ = map_.next_value::<::std::option::Option<_>>()?.map(aggchain_data::Data::Signature)
+;
+                        }
+                        GeneratedField::Generic => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("generic"));
+                            }
+                            data__ = map_.next_value::<::std::option::Option<_>>()?.map(aggchain_data::Data::Generic)
+;
+                        }
+                        GeneratedField::Multisig => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multisig"));
+                            }
+                            data__ = map_.next_value::<::std::option::Option<_>>()?.map(aggchain_data::Data::Multisig)
+;
+                        }
+                        GeneratedField::MultisigAndAggchainProof => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multisigAndAggchainProof"));
+                            }
+                            data__ = map_.next_value::<::std::option::Option<_>>()?.map(aggchain_data::Data::MultisigAndAggchainProof)
+;
+                        }
+                    }
+                }
+                Ok(AggchainData {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v2.AggchainData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AggchainProof {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.aggchain_params.is_some() {
+            len += 1;
+        }
+        if self.signature.is_some() {
+            len += 1;
+        }
+        if !self.context.is_empty() {
+            len += 1;
+        }
+        if self.proof.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v2.AggchainProof", len)?;
+        if let Some(v) = self.aggchain_params.as_ref() {
+            struct_ser.serialize_field("aggchainParams", v)?;
+        }
+        if let Some(v) = self.signature.as_ref() {
+            struct_ser.serialize_field("signature", v)?;
+        }
+        if !self.context.is_empty() {
+            let v: std::collections::HashMap<_, _> = self.context.iter()
+                .map(|(k, v)| (k, pbjson::private::base64::encode(v))).collect();
+            struct_ser.serialize_field("context", &v)?;
+        }
+        if let Some(v) = self.proof.as_ref() {
+            match v {
+                aggchain_proof::Proof::Sp1Stark(v) => {
+                    struct_ser.serialize_field("sp1Stark", v)?;
+                }
+                aggchain_proof::Proof::Compressed(v) => {
+                    struct_ser.serialize_field("compressed", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainProof {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "aggchain_params",
+            "aggchainParams",
+            "signature",
+            "context",
+            "sp1_stark",
+            "sp1Stark",
+            "compressed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AggchainParams,
+            Signature,
+            Context,
+            Sp1Stark,
+            Compressed,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "aggchainParams" | "aggchain_params" => Ok(GeneratedField::AggchainParams),
+                            "signature" => Ok(GeneratedField::Signature),
+                            "context" => Ok(GeneratedField::Context),
+                            "sp1Stark" | "sp1_stark" => Ok(GeneratedField::Sp1Stark),
+                            "compressed" => Ok(GeneratedField::Compressed),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainProof;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v2.AggchainProof")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AggchainProof, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut aggchain_params__ = None;
+                let mut signature__ = None;
+                let mut context__ = None;
+                let mut proof__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AggchainParams => {
+                            if aggchain_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggchainParams"));
+                            }
+                            aggchain_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::Context => {
+                            if context__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("context"));
+                            }
+                            context__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, ::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|(k,v)| (k, v.0)).collect()
+                            );
+                        }
+                        GeneratedField::Sp1Stark => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sp1Stark"));
+                            }
+                            proof__ = map_.next_value::<::std::option::Option<_>>()?.map(aggchain_proof::Proof::Sp1Stark)
+;
+                        }
+                        GeneratedField::Compressed => {
+                            if proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("compressed"));
+                            }
+                            proof__ = map_.next_value::<::std::option::Option<_>>()?.map(aggchain_proof::Proof::Compressed)
+;
+                        }
+                    }
+                }
+                Ok(AggchainProof {
+                    aggchain_params: aggchain_params__,
+                    signature: signature__,
+                    context: context__.unwrap_or_default(),
+                    proof: proof__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v2.AggchainProof", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AggchainProofWithMultisig {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.multisig.is_some() {
+            len += 1;
+        }
+        if self.aggchain_proof.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v2.AggchainProofWithMultisig", len)?;
+        if let Some(v) = self.multisig.as_ref() {
+            struct_ser.serialize_field("multisig", v)?;
+        }
+        if let Some(v) = self.aggchain_proof.as_ref() {
+            struct_ser.serialize_field("aggchainProof", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainProofWithMultisig {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "multisig",
+            "aggchain_proof",
+            "aggchainProof",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Multisig,
+            AggchainProof,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "multisig" => Ok(GeneratedField::Multisig),
+                            "aggchainProof" | "aggchain_proof" => Ok(GeneratedField::AggchainProof),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainProofWithMultisig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v2.AggchainProofWithMultisig")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AggchainProofWithMultisig, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut multisig__ = None;
+                let mut aggchain_proof__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Multisig => {
+                            if multisig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multisig"));
+                            }
+                            multisig__ = map_.next_value()?;
+                        }
+                        GeneratedField::AggchainProof => {
+                            if aggchain_proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggchainProof"));
+                            }
+                            aggchain_proof__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(AggchainProofWithMultisig {
+                    multisig: multisig__,
+                    aggchain_proof: aggchain_proof__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v2.AggchainProofWithMultisig", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AssetExit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.token_info.is_some() {
+            len += 1;
+        }
+        if self.dest_network != 0 {
+            len += 1;
+        }
+        if self.dest_address.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v2.AssetExit", len)?;
+        if let Some(v) = self.token_info.as_ref() {
+            struct_ser.serialize_field("tokenInfo", v)?;
+        }
+        if self.dest_network != 0 {
+            struct_ser.serialize_field("destNetwork", &self.dest_network)?;
+        }
+        if let Some(v) = self.dest_address.as_ref() {
+            struct_ser.serialize_field("destAddress", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetExit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "token_info",
+            "tokenInfo",
+            "dest_network",
+            "destNetwork",
+            "dest_address",
+            "destAddress",
+            "amount",
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TokenInfo,
+            DestNetwork,
+            DestAddress,
+            Amount,
+            Metadata,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tokenInfo" | "token_info" => Ok(GeneratedField::TokenInfo),
+                            "destNetwork" | "dest_network" => Ok(GeneratedField::DestNetwork),
+                            "destAddress" | "dest_address" => Ok(GeneratedField::DestAddress),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetExit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v2.AssetExit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AssetExit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut token_info__ = None;
+                let mut dest_network__ = None;
+                let mut dest_address__ = None;
+                let mut amount__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TokenInfo => {
+                            if token_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tokenInfo"));
+                            }
+                            token_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::DestNetwork => {
+                            if dest_network__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destNetwork"));
+                            }
+                            dest_network__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DestAddress => {
+                            if dest_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destAddress"));
+                            }
+                            dest_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(AssetExit {
+                    token_info: token_info__,
+                    dest_network: dest_network__.unwrap_or_default(),
+                    dest_address: dest_address__,
+                    amount: amount__,
+                    metadata: metadata__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v2.AssetExit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BridgeExit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.exit.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v2.BridgeExit", len)?;
+        if let Some(v) = self.exit.as_ref() {
+            match v {
+                bridge_exit::Exit::Asset(v) => {
+                    struct_ser.serialize_field("asset", v)?;
+                }
+                bridge_exit::Exit::Message(v) => {
+                    struct_ser.serialize_field("message", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BridgeExit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset",
+            "message",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Asset,
+            Message,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "asset" => Ok(GeneratedField::Asset),
+                            "message" => Ok(GeneratedField::Message),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BridgeExit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v2.BridgeExit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BridgeExit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut exit__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Asset => {
+                            if exit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            exit__ = map_.next_value::<::std::option::Option<_>>()?.map(bridge_exit::Exit::Asset)
+;
+                        }
+                        GeneratedField::Message => {
+                            if exit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            exit__ = map_.next_value::<::std::option::Option<_>>()?.map(bridge_exit::Exit::Message)
+;
+                        }
+                    }
+                }
+                Ok(BridgeExit {
+                    exit: exit__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v2.BridgeExit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CompressedProof {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.compression != 0 {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v2.CompressedProof", len)?;
+        if self.compression != 0 {
+            let v = Compression::try_from(self.compression)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.compression)))?;
+            struct_ser.serialize_field("compression", &v)?;
+        }
+        if !self.data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("data", pbjson::private::base64::encode(&self.data).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CompressedProof {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "compression",
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Compression,
+            Data,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "compression" => Ok(GeneratedField::Compression),
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CompressedProof;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v2.CompressedProof")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CompressedProof, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut compression__ = None;
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Compression => {
+                            if compression__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("compression"));
+                            }
+                            compression__ = Some(map_.next_value::<Compression>()? as i32);
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(CompressedProof {
+                    compression: compression__.unwrap_or_default(),
+                    data: data__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v2.CompressedProof", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Compression {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "COMPRESSION_UNSPECIFIED",
+            Self::Zstd => "COMPRESSION_ZSTD",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for Compression {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "COMPRESSION_UNSPECIFIED",
+            "COMPRESSION_ZSTD",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Compression;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "COMPRESSION_UNSPECIFIED" => Ok(Compression::Unspecified),
+                    "COMPRESSION_ZSTD" => Ok(Compression::Zstd),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MessageExit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.token_info.is_some() {
+            len += 1;
+        }
+        if self.dest_network != 0 {
+            len += 1;
+        }
+        if self.dest_address.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.interop.types.v2.MessageExit", len)?;
+        if let Some(v) = self.token_info.as_ref() {
+            struct_ser.serialize_field("tokenInfo", v)?;
+        }
+        if self.dest_network != 0 {
+            struct_ser.serialize_field("destNetwork", &self.dest_network)?;
+        }
+        if let Some(v) = self.dest_address.as_ref() {
+            struct_ser.serialize_field("destAddress", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if let Some(v) = self.metadata.as_ref() {
+            struct_ser.serialize_field("metadata", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MessageExit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "token_info",
+            "tokenInfo",
+            "dest_network",
+            "destNetwork",
+            "dest_address",
+            "destAddress",
+            "amount",
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TokenInfo,
+            DestNetwork,
+            DestAddress,
+            Amount,
+            Metadata,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tokenInfo" | "token_info" => Ok(GeneratedField::TokenInfo),
+                            "destNetwork" | "dest_network" => Ok(GeneratedField::DestNetwork),
+                            "destAddress" | "dest_address" => Ok(GeneratedField::DestAddress),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MessageExit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.interop.types.v2.MessageExit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MessageExit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut token_info__ = None;
+                let mut dest_network__ = None;
+                let mut dest_address__ = None;
+                let mut amount__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TokenInfo => {
+                            if token_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tokenInfo"));
+                            }
+                            token_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::DestNetwork => {
+                            if dest_network__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destNetwork"));
+                            }
+                            dest_network__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DestAddress => {
+                            if dest_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destAddress"));
+                            }
+                            dest_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(MessageExit {
+                    token_info: token_info__,
+                    dest_network: dest_network__.unwrap_or_default(),
+                    dest_address: dest_address__,
+                    amount: amount__,
+                    metadata: metadata__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.interop.types.v2.MessageExit", FIELDS, GeneratedVisitor)
+    }
+}
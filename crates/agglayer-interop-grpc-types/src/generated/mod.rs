@@ -1,12 +1,29 @@
 // @generated
 pub mod agglayer {
     pub mod interop {
+        pub mod service {
+            // @@protoc_insertion_point(attribute:agglayer.interop.service.v1)
+            pub mod v1 {
+                include!("agglayer/interop/service/v1/agglayer.interop.service.v1.rs");
+                // @@protoc_insertion_point(agglayer.interop.service.v1)
+            }
+            // @@protoc_insertion_point(attribute:agglayer.interop.service.v2)
+            pub mod v2 {
+                include!("agglayer/interop/service/v2/agglayer.interop.service.v2.rs");
+                // @@protoc_insertion_point(agglayer.interop.service.v2)
+            }
+        }
         pub mod types {
             // @@protoc_insertion_point(attribute:agglayer.interop.types.v1)
             pub mod v1 {
                 include!("agglayer/interop/types/v1/agglayer.interop.types.v1.rs");
                 // @@protoc_insertion_point(agglayer.interop.types.v1)
             }
+            // @@protoc_insertion_point(attribute:agglayer.interop.types.v2)
+            pub mod v2 {
+                include!("agglayer/interop/types/v2/agglayer.interop.types.v2.rs");
+                // @@protoc_insertion_point(agglayer.interop.types.v2)
+            }
         }
     }
 }
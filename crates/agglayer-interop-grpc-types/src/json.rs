@@ -0,0 +1,510 @@
+//! Canonical JSON representation of the `agglayer.interop` messages.
+//!
+//! The proto-JSON mapping generated with `pbjson` encodes `bytes` fields as
+//! base64, whereas the native types render `Digest`, `Address` and friends as
+//! `0x`-prefixed hex strings. The canonical form used by the JSON gateways
+//! follows the native types instead:
+//!
+//! - `FixedBytes20`, `FixedBytes32` and `FixedBytes65` messages are flattened
+//!   to a hex string,
+//! - every other `bytes` field is a hex string as well,
+//! - `U256` values, which are carried as `FixedBytes32` on the wire, are
+//!   decimal strings.
+//!
+//! Everything else is left as in proto-JSON. [`JsonMapping`] converts between
+//! both forms, using the descriptors of the generated messages.
+
+use std::collections::HashMap;
+
+use agglayer_interop_types::U256;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use prost::Message as _;
+use prost_types::{
+    field_descriptor_proto::{Label, Type},
+    DescriptorProto, FieldDescriptorProto, FileDescriptorSet,
+};
+use serde_json::{Map, Value};
+
+/// Messages flattened to a single hex string in the canonical form.
+const FIXED_BYTES: &[&str] = &[
+    ".agglayer.interop.types.v1.FixedBytes20",
+    ".agglayer.interop.types.v1.FixedBytes32",
+    ".agglayer.interop.types.v1.FixedBytes65",
+];
+
+/// `FixedBytes32` fields holding a big-endian `U256`.
+///
+/// The schema does not tell them apart from digests, so every `FixedBytes32`
+/// field named after an amount has to be listed here, which is checked by
+/// the `amount_fields_are_u256_fields` test.
+const U256_FIELDS: &[&str] = &[
+    ".agglayer.interop.types.v1.BridgeExit.amount",
+    ".agglayer.interop.types.v2.AssetExit.amount",
+    ".agglayer.interop.types.v2.MessageExit.amount",
+];
+
+/// Error returned when converting between the JSON forms.
+#[derive(Debug, thiserror::Error)]
+pub enum JsonError {
+    /// The message type is not part of the `agglayer.interop` packages.
+    #[error("unknown message type: {0}")]
+    UnknownMessage(String),
+
+    /// The JSON object has a field which the message does not define.
+    #[error("unknown field `{field}` in {message}")]
+    UnknownField { message: String, field: String },
+
+    /// The JSON value does not have the expected shape.
+    #[error("invalid value for `{field}`: {reason}")]
+    InvalidValue { field: String, reason: String },
+}
+
+impl JsonError {
+    fn invalid(field: &str, reason: impl Into<String>) -> Self {
+        JsonError::InvalidValue {
+            field: field.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Direction of a conversion.
+#[derive(Clone, Copy)]
+enum Direction {
+    ToCanonical,
+    ToProto,
+}
+
+/// Converter between the proto-JSON and canonical JSON forms of the
+/// `agglayer.interop` messages.
+#[derive(Clone, Debug)]
+pub struct JsonMapping {
+    /// Message descriptors, by fully-qualified name with a leading dot.
+    messages: HashMap<String, DescriptorProto>,
+}
+
+impl Default for JsonMapping {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonMapping {
+    /// Builds the mapping from the descriptors embedded in this crate.
+    pub fn new() -> Self {
+        let mut messages = HashMap::new();
+        for set in [
+            crate::v1::FILE_DESCRIPTOR_SET,
+            crate::service::v1::FILE_DESCRIPTOR_SET,
+            crate::v2::FILE_DESCRIPTOR_SET,
+            crate::service::v2::FILE_DESCRIPTOR_SET,
+        ] {
+            // Checked in along with the generated code, so it always decodes.
+            let set = FileDescriptorSet::decode(set).expect("valid file descriptor set");
+            for file in set.file {
+                let prefix = format!(".{}", file.package());
+                for message in file.message_type {
+                    index(&mut messages, &prefix, message);
+                }
+            }
+        }
+        Self { messages }
+    }
+
+    /// Converts the proto-JSON form of a message to its canonical form.
+    ///
+    /// `message` is the fully-qualified name of the message type, e.g.
+    /// `agglayer.interop.types.v1.BridgeExit`.
+    pub fn to_canonical(&self, message: &str, value: Value) -> Result<Value, JsonError> {
+        self.convert_message(
+            &format!(".{message}"),
+            value,
+            message,
+            Direction::ToCanonical,
+        )
+    }
+
+    /// Converts the canonical form of a message to its proto-JSON form.
+    ///
+    /// `message` is the fully-qualified name of the message type, e.g.
+    /// `agglayer.interop.types.v1.BridgeExit`.
+    pub fn to_proto_json(&self, message: &str, value: Value) -> Result<Value, JsonError> {
+        self.convert_message(&format!(".{message}"), value, message, Direction::ToProto)
+    }
+
+    fn convert_message(
+        &self,
+        type_name: &str,
+        value: Value,
+        path: &str,
+        direction: Direction,
+    ) -> Result<Value, JsonError> {
+        if FIXED_BYTES.contains(&type_name) {
+            return convert_fixed_bytes(value, path, direction);
+        }
+
+        let descriptor = self
+            .messages
+            .get(type_name)
+            .ok_or_else(|| JsonError::UnknownMessage(type_name[1..].to_owned()))?;
+        let Value::Object(object) = value else {
+            return Err(JsonError::invalid(path, "expected an object"));
+        };
+
+        let mut out = Map::with_capacity(object.len());
+        for (key, value) in object {
+            let field = descriptor
+                .field
+                .iter()
+                .find(|f| f.name() == key || json_name(f) == key)
+                .ok_or_else(|| JsonError::UnknownField {
+                    message: type_name[1..].to_owned(),
+                    field: key.clone(),
+                })?;
+            let path = format!("{path}.{key}");
+            let value = self.convert_field(type_name, field, value, &path, direction)?;
+            out.insert(key, value);
+        }
+        Ok(Value::Object(out))
+    }
+
+    fn convert_field(
+        &self,
+        type_name: &str,
+        field: &FieldDescriptorProto,
+        value: Value,
+        path: &str,
+        direction: Direction,
+    ) -> Result<Value, JsonError> {
+        if value.is_null() {
+            return Ok(value);
+        }
+
+        if let Some(entry) = self.map_entry(field) {
+            let Value::Object(object) = value else {
+                return Err(JsonError::invalid(path, "expected an object"));
+            };
+            let value_field = entry
+                .field
+                .iter()
+                .find(|f| f.number() == 2)
+                .expect("map entries have a value field");
+            return object
+                .into_iter()
+                .map(|(key, value)| {
+                    let path = format!("{path}.{key}");
+                    let value =
+                        self.convert_single(type_name, value_field, value, &path, direction)?;
+                    Ok((key, value))
+                })
+                .collect::<Result<Map<_, _>, _>>()
+                .map(Value::Object);
+        }
+
+        if field.label() == Label::Repeated {
+            let Value::Array(values) = value else {
+                return Err(JsonError::invalid(path, "expected an array"));
+            };
+            return values
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let path = format!("{path}[{i}]");
+                    self.convert_single(type_name, field, value, &path, direction)
+                })
+                .collect::<Result<_, _>>()
+                .map(Value::Array);
+        }
+
+        self.convert_single(type_name, field, value, path, direction)
+    }
+
+    fn convert_single(
+        &self,
+        type_name: &str,
+        field: &FieldDescriptorProto,
+        value: Value,
+        path: &str,
+        direction: Direction,
+    ) -> Result<Value, JsonError> {
+        match field.r#type() {
+            Type::Bytes => convert_bytes(value, path, direction),
+            Type::Message
+                if U256_FIELDS.contains(&format!("{type_name}.{}", field.name()).as_str()) =>
+            {
+                convert_u256(value, path, direction)
+            }
+            Type::Message => self.convert_message(field.type_name(), value, path, direction),
+            _ => Ok(value),
+        }
+    }
+
+    fn map_entry(&self, field: &FieldDescriptorProto) -> Option<&DescriptorProto> {
+        if field.label() != Label::Repeated || field.r#type() != Type::Message {
+            return None;
+        }
+        self.messages
+            .get(field.type_name())
+            .filter(|m| m.options.as_ref().is_some_and(|o| o.map_entry()))
+    }
+}
+
+/// Indexes a message and its nested messages by fully-qualified name.
+fn index(messages: &mut HashMap<String, DescriptorProto>, prefix: &str, message: DescriptorProto) {
+    let name = format!("{prefix}.{}", message.name());
+    for nested in &message.nested_type {
+        index(messages, &name, nested.clone());
+    }
+    messages.insert(name, message);
+}
+
+/// Name of a field in proto-JSON, which is its lower camel case name unless
+/// specified otherwise.
+fn json_name(field: &FieldDescriptorProto) -> String {
+    if let Some(name) = &field.json_name {
+        return name.clone();
+    }
+    let mut out = String::with_capacity(field.name().len());
+    let mut upper = false;
+    for c in field.name().chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn decode_base64(value: &Value, path: &str) -> Result<Vec<u8>, JsonError> {
+    let Value::String(s) = value else {
+        return Err(JsonError::invalid(path, "expected a base64 string"));
+    };
+    STANDARD
+        .decode(s)
+        .map_err(|e| JsonError::invalid(path, e.to_string()))
+}
+
+fn decode_hex(value: &Value, path: &str) -> Result<Vec<u8>, JsonError> {
+    let Value::String(s) = value else {
+        return Err(JsonError::invalid(path, "expected a hex string"));
+    };
+    let s = s
+        .strip_prefix("0x")
+        .ok_or_else(|| JsonError::invalid(path, "expected a 0x prefix"))?;
+    hex::decode(s).map_err(|e| JsonError::invalid(path, e.to_string()))
+}
+
+fn convert_bytes(value: Value, path: &str, direction: Direction) -> Result<Value, JsonError> {
+    Ok(match direction {
+        Direction::ToCanonical => {
+            Value::String(format!("0x{}", hex::encode(decode_base64(&value, path)?)))
+        }
+        Direction::ToProto => Value::String(STANDARD.encode(decode_hex(&value, path)?)),
+    })
+}
+
+fn convert_fixed_bytes(value: Value, path: &str, direction: Direction) -> Result<Value, JsonError> {
+    match direction {
+        Direction::ToCanonical => {
+            let Value::Object(mut object) = value else {
+                return Err(JsonError::invalid(path, "expected an object"));
+            };
+            let bytes = object
+                .remove("value")
+                .unwrap_or_else(|| Value::String(String::new()));
+            if let Some(key) = object.keys().next() {
+                return Err(JsonError::invalid(path, format!("unexpected key `{key}`")));
+            }
+            convert_bytes(bytes, path, direction)
+        }
+        Direction::ToProto => Ok(Value::Object(Map::from_iter([(
+            "value".to_owned(),
+            convert_bytes(value, path, direction)?,
+        )]))),
+    }
+}
+
+fn convert_u256(value: Value, path: &str, direction: Direction) -> Result<Value, JsonError> {
+    match direction {
+        Direction::ToCanonical => {
+            let Value::String(hex) = convert_fixed_bytes(value, path, direction)? else {
+                unreachable!("fixed bytes are converted to a string");
+            };
+            let bytes = hex::decode(&hex[2..]).expect("freshly encoded hex");
+            let bytes = <[u8; 32]>::try_from(bytes).map_err(|b| {
+                JsonError::invalid(path, format!("expected 32 bytes, got {}", b.len()))
+            })?;
+            Ok(Value::String(U256::from_be_bytes(bytes).to_string()))
+        }
+        Direction::ToProto => {
+            let Value::String(s) = value else {
+                return Err(JsonError::invalid(path, "expected a decimal string"));
+            };
+            let amount = U256::from_str_radix(&s, 10)
+                .map_err(|e| JsonError::invalid(path, e.to_string()))?;
+            let bytes = Value::String(format!("0x{}", hex::encode(amount.to_be_bytes::<32>())));
+            convert_fixed_bytes(bytes, path, direction)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_interop_types::{BridgeExit, Digest, LeafType, NetworkId, TokenInfo};
+    use serde_json::json;
+
+    use super::*;
+    use crate::v1;
+
+    fn bridge_exit() -> v1::BridgeExit {
+        BridgeExit {
+            leaf_type: LeafType::Transfer,
+            token_info: TokenInfo {
+                origin_network: NetworkId::new(1),
+                origin_token_address: [0x11; 20].into(),
+            },
+            dest_network: NetworkId::new(2),
+            dest_address: [0x22; 20].into(),
+            amount: U256::from(1_000_000_000_000_000_000_u128),
+            metadata: Some(Digest([0x33; 32])),
+        }
+        .into()
+    }
+
+    #[test]
+    fn amount_fields_are_u256_fields() {
+        let mapping = JsonMapping::new();
+        let mut amounts: Vec<_> = mapping
+            .messages
+            .iter()
+            .flat_map(|(message, descriptor)| {
+                descriptor
+                    .field
+                    .iter()
+                    .filter(|field| {
+                        field.type_name() == ".agglayer.interop.types.v1.FixedBytes32"
+                            && (field.name() == "amount" || field.name().ends_with("_amount"))
+                    })
+                    .map(move |field| format!("{message}.{}", field.name()))
+            })
+            .collect();
+        amounts.sort();
+
+        assert_eq!(amounts, U256_FIELDS);
+    }
+
+    #[test]
+    fn bridge_exit_canonical_form() {
+        let mapping = JsonMapping::new();
+        let proto_json = serde_json::to_value(bridge_exit()).unwrap();
+
+        let canonical = mapping
+            .to_canonical("agglayer.interop.types.v1.BridgeExit", proto_json.clone())
+            .unwrap();
+
+        assert_eq!(
+            canonical,
+            json!({
+                "leafType": "LEAF_TYPE_TRANSFER",
+                "tokenInfo": {
+                    "originNetwork": 1,
+                    "originTokenAddress": format!("0x{}", "11".repeat(20)),
+                },
+                "destNetwork": 2,
+                "destAddress": format!("0x{}", "22".repeat(20)),
+                "amount": "1000000000000000000",
+                "metadata": format!("0x{}", "33".repeat(32)),
+            })
+        );
+
+        // The fixed bytes use the same representation as the native types.
+        assert_eq!(
+            canonical["metadata"],
+            serde_json::to_value(Digest([0x33; 32])).unwrap()
+        );
+
+        let back = mapping
+            .to_proto_json("agglayer.interop.types.v1.BridgeExit", canonical)
+            .unwrap();
+        assert_eq!(back, proto_json);
+        assert_eq!(
+            serde_json::from_value::<v1::BridgeExit>(back).unwrap(),
+            bridge_exit()
+        );
+    }
+
+    #[test]
+    fn v2_amounts_are_decimal() {
+        let mapping = JsonMapping::new();
+        let proto = crate::v2::BridgeExit::try_from(bridge_exit()).unwrap();
+        let proto_json = serde_json::to_value(&proto).unwrap();
+
+        let canonical = mapping
+            .to_canonical("agglayer.interop.types.v2.BridgeExit", proto_json.clone())
+            .unwrap();
+        assert_eq!(canonical["asset"]["amount"], json!("1000000000000000000"));
+
+        let back = mapping
+            .to_proto_json("agglayer.interop.types.v2.BridgeExit", canonical)
+            .unwrap();
+        assert_eq!(back, proto_json);
+    }
+
+    #[test]
+    fn nested_and_repeated_bytes_are_converted() {
+        let mapping = JsonMapping::new();
+        let proto = v1::AggchainProof {
+            aggchain_params: Some(Digest([0x44; 32]).into()),
+            context: [("key".to_owned(), vec![0xab, 0xcd].into())].into(),
+            ..Default::default()
+        };
+        let proto_json = serde_json::to_value(&proto).unwrap();
+
+        let canonical = mapping
+            .to_canonical(
+                "agglayer.interop.types.v1.AggchainProof",
+                proto_json.clone(),
+            )
+            .unwrap();
+        assert_eq!(
+            canonical["aggchainParams"],
+            json!(format!("0x{}", "44".repeat(32)))
+        );
+        assert_eq!(canonical["context"], json!({ "key": "0xabcd" }));
+
+        let back = mapping
+            .to_proto_json("agglayer.interop.types.v1.AggchainProof", canonical)
+            .unwrap();
+        assert_eq!(back, proto_json);
+    }
+
+    #[test]
+    fn invalid_inputs_are_rejected() {
+        let mapping = JsonMapping::new();
+
+        assert!(matches!(
+            mapping.to_canonical("agglayer.interop.types.v1.Unknown", json!({})),
+            Err(JsonError::UnknownMessage(_))
+        ));
+        assert!(matches!(
+            mapping.to_canonical("agglayer.interop.types.v1.BridgeExit", json!({ "foo": 1 })),
+            Err(JsonError::UnknownField { .. })
+        ));
+
+        let err = mapping
+            .to_proto_json(
+                "agglayer.interop.types.v1.BridgeExit",
+                json!({ "amount": "0x10" }),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value for `agglayer.interop.types.v1.BridgeExit.amount`: invalid digit: x"
+        );
+    }
+}
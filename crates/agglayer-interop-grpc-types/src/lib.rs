@@ -1,7 +1,9 @@
 #[allow(clippy::needless_lifetimes)]
 mod generated;
 
-pub use crate::generated::agglayer::interop::types::*;
+pub use crate::generated::agglayer::interop::{service, types::*};
 
 #[cfg(feature = "compat")]
 pub mod compat;
+#[cfg(feature = "json")]
+pub mod json;
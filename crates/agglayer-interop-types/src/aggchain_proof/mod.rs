@@ -1,17 +1,21 @@
+use std::collections::BTreeMap;
+
 use agglayer_primitives::Signature;
 use serde::{Deserialize, Serialize};
 pub use unified_bridge::AggchainProofPublicValues;
 
 use crate::Digest;
 
+/// Context key under which the aggchain proof public values are transported.
+pub const PUBLIC_VALUES_CONTEXT_KEY: &str = "public_values";
+
 // Aggchain data submitted via the [`Certificate`].
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "testutils", derive(arbitrary::Arbitrary, Eq, PartialEq))]
+// Untagged variants are tried in declaration order, so the variants whose
+// fields are a superset of another's must come first.
 #[serde(untagged)]
 pub enum AggchainData {
-    ECDSA {
-        signature: Signature,
-    },
     Generic {
         /// proof of the aggchain proof.
         proof: Proof,
@@ -21,14 +25,24 @@ pub enum AggchainData {
         signature: Option<Box<Signature>>,
         /// Optional aggchain proof public values.
         public_values: Option<Box<AggchainProofPublicValues>>,
+        /// Additional opaque context entries, excluding the public values.
+        ///
+        /// Defaults to empty when missing from self-describing formats such
+        /// as JSON. Bincode has no such fallback, so the bincode encodings
+        /// written before this field was added no longer decode.
+        #[serde(default)]
+        context: BTreeMap<String, Vec<u8>>,
     },
-    MultisigOnly {
-        multisig: MultisigPayload,
+    ECDSA {
+        signature: Signature,
     },
     MultisigAndAggchainProof {
         multisig: MultisigPayload,
         aggchain_proof: AggchainProof,
     },
+    MultisigOnly {
+        multisig: MultisigPayload,
+    },
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -45,6 +59,13 @@ pub struct AggchainProof {
     pub aggchain_params: Digest,
     /// Optional aggchain proof public values.
     pub public_values: Option<Box<AggchainProofPublicValues>>,
+    /// Additional opaque context entries, excluding the public values.
+    ///
+    /// Defaults to empty when missing from self-describing formats such as
+    /// JSON. Bincode has no such fallback, so the bincode encodings written
+    /// before this field was added no longer decode.
+    #[serde(default)]
+    pub context: BTreeMap<String, Vec<u8>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
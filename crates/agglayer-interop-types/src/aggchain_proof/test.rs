@@ -72,3 +72,125 @@ fn proof_serde_round_trip_preserves_raw_envelope() {
         }
     }
 }
+
+#[test]
+fn aggchain_proof_serde_round_trip_preserves_context() {
+    let aggchain_proof = AggchainProof {
+        proof: Proof::SP1Stark(SP1StarkWithContext {
+            version: "v6.0.0".to_owned(),
+            proof: vec![0xde, 0xad, 0xbe, 0xef],
+            vkey: vec![0xca, 0xfe, 0xba, 0xbe],
+        }),
+        aggchain_params: Digest([0x11; 32]),
+        public_values: None,
+        context: BTreeMap::from([("l2_block_range".to_owned(), vec![0x01, 0x02])]),
+    };
+
+    let encoded = serde_json::to_string(&aggchain_proof).unwrap();
+    let decoded: AggchainProof = serde_json::from_str(&encoded).unwrap();
+
+    assert_eq!(decoded, aggchain_proof);
+}
+
+#[test]
+fn aggchain_proof_serde_accepts_missing_context() {
+    let aggchain_proof = AggchainProof {
+        proof: Proof::SP1Stark(SP1StarkWithContext {
+            version: "v6.0.0".to_owned(),
+            proof: vec![0xde, 0xad, 0xbe, 0xef],
+            vkey: vec![0xca, 0xfe, 0xba, 0xbe],
+        }),
+        aggchain_params: Digest([0x11; 32]),
+        public_values: None,
+        context: BTreeMap::new(),
+    };
+
+    let mut encoded = serde_json::to_value(&aggchain_proof).unwrap();
+    encoded.as_object_mut().unwrap().remove("context").unwrap();
+
+    let decoded: AggchainProof = serde_json::from_value(encoded).unwrap();
+
+    assert_eq!(decoded, aggchain_proof);
+}
+
+#[test]
+fn aggchain_proof_bincode_round_trip_preserves_context() {
+    let aggchain_proof = AggchainProof {
+        proof: Proof::SP1Stark(SP1StarkWithContext {
+            version: "v6.0.0".to_owned(),
+            proof: vec![0xde, 0xad, 0xbe, 0xef],
+            vkey: vec![0xca, 0xfe, 0xba, 0xbe],
+        }),
+        aggchain_params: Digest([0x11; 32]),
+        public_values: None,
+        context: BTreeMap::new(),
+    };
+
+    let encoded = crate::bincode::default()
+        .serialize(&aggchain_proof)
+        .unwrap();
+    let decoded: AggchainProof = crate::bincode::default().deserialize(&encoded).unwrap();
+
+    assert_eq!(decoded, aggchain_proof);
+}
+
+#[test]
+fn aggchain_proof_bincode_without_context_is_rejected() {
+    /// Layout of [`AggchainProof`] before the context entries were added.
+    #[derive(Serialize)]
+    struct LegacyAggchainProof {
+        proof: Proof,
+        aggchain_params: Digest,
+        public_values: Option<Box<AggchainProofPublicValues>>,
+    }
+
+    let encoded = crate::bincode::default()
+        .serialize(&LegacyAggchainProof {
+            proof: Proof::SP1Stark(SP1StarkWithContext {
+                version: "v6.0.0".to_owned(),
+                proof: vec![0xde, 0xad, 0xbe, 0xef],
+                vkey: vec![0xca, 0xfe, 0xba, 0xbe],
+            }),
+            aggchain_params: Digest([0x11; 32]),
+            public_values: None,
+        })
+        .unwrap();
+
+    assert!(crate::bincode::default()
+        .deserialize::<AggchainProof>(&encoded)
+        .is_err());
+}
+
+#[test]
+fn aggchain_data_serde_round_trip_keeps_the_most_specific_variant() {
+    let signature = Signature::new(U256::from(1), U256::from(2), false);
+    let proof = Proof::SP1Stark(SP1StarkWithContext {
+        version: "v6.0.0".to_owned(),
+        proof: vec![0xde, 0xad, 0xbe, 0xef],
+        vkey: vec![0xca, 0xfe, 0xba, 0xbe],
+    });
+
+    for aggchain_data in [
+        AggchainData::Generic {
+            proof: proof.clone(),
+            aggchain_params: Digest([0x11; 32]),
+            signature: Some(Box::new(signature)),
+            public_values: None,
+            context: BTreeMap::new(),
+        },
+        AggchainData::MultisigAndAggchainProof {
+            multisig: MultisigPayload(vec![Some(signature)]),
+            aggchain_proof: AggchainProof {
+                proof,
+                aggchain_params: Digest([0x11; 32]),
+                public_values: None,
+                context: BTreeMap::new(),
+            },
+        },
+    ] {
+        let encoded = serde_json::to_string(&aggchain_data).unwrap();
+        let decoded: AggchainData = serde_json::from_str(&encoded).unwrap();
+
+        assert_eq!(decoded, aggchain_data);
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    aggchain_proof::AggchainData, BridgeExit, CertificateId, CertificateIndex, Digest, EpochNumber,
+    Height, ImportedBridgeExit, LocalExitRoot, NetworkId,
+};
+
+/// Certificate submitted by a network to the agglayer.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "testutils", derive(Eq, PartialEq))]
+pub struct Certificate {
+    /// Network which produced the certificate.
+    pub network_id: NetworkId,
+    /// Height of the certificate for the network.
+    pub height: Height,
+    /// Local exit root before applying the certificate.
+    pub prev_local_exit_root: LocalExitRoot,
+    /// Local exit root after applying the certificate.
+    pub new_local_exit_root: LocalExitRoot,
+    /// Bridge exits initiated on the network.
+    pub bridge_exits: Vec<BridgeExit>,
+    /// Bridge exits claimed on the network.
+    pub imported_bridge_exits: Vec<ImportedBridgeExit>,
+    /// Fixed size field of arbitrary data for the chain needs.
+    pub metadata: Digest,
+    /// Aggchain data attesting the state transition.
+    pub aggchain_data: AggchainData,
+    /// Opaque data forwarded to the chain-specific verification.
+    #[serde(default)]
+    pub custom_chain_data: Vec<u8>,
+    /// Number of L1 info tree leaves the certificate was built against.
+    #[serde(default)]
+    pub l1_info_tree_leaf_count: Option<u32>,
+}
+
+/// Processing status of a certificate.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum CertificateStatus {
+    /// The certificate has been received and awaits proving.
+    Pending,
+    /// The pessimistic proof of the certificate has been generated.
+    Proven,
+    /// The certificate is part of an epoch awaiting settlement.
+    Candidate,
+    /// The certificate has been rejected.
+    InError { error: String },
+    /// The certificate has been settled on L1.
+    Settled,
+}
+
+/// Header summarizing a certificate and its processing state.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CertificateHeader {
+    /// Network which produced the certificate.
+    pub network_id: NetworkId,
+    /// Height of the certificate for the network.
+    pub height: Height,
+    /// Epoch in which the certificate was settled, if any.
+    pub epoch_number: Option<EpochNumber>,
+    /// Index of the certificate within its epoch, if any.
+    pub certificate_index: Option<CertificateIndex>,
+    /// Identifier of the certificate.
+    pub certificate_id: CertificateId,
+    /// Local exit root before applying the certificate.
+    pub prev_local_exit_root: LocalExitRoot,
+    /// Local exit root after applying the certificate.
+    pub new_local_exit_root: LocalExitRoot,
+    /// Metadata carried by the certificate.
+    pub metadata: Digest,
+    /// Processing status of the certificate.
+    pub status: CertificateStatus,
+    /// Hash of the settlement transaction, if any.
+    pub settlement_tx_hash: Option<Digest>,
+}
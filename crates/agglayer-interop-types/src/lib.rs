@@ -1,14 +1,18 @@
 pub use agglayer_bincode as bincode;
 pub use agglayer_primitives::Digest;
 use agglayer_tries::error::SmtError;
-pub use agglayer_tries::roots::{
-    L1InfoRoot, LocalBalanceRoot, LocalExitRoot, LocalNullifierRoot, PessimisticRoot,
+pub use agglayer_tries::{
+    proof::{SmtMerkleProof, SmtNonInclusionProof},
+    roots::{L1InfoRoot, LocalBalanceRoot, LocalExitRoot, LocalNullifierRoot, PessimisticRoot},
 };
 use serde::{Deserialize, Serialize};
 
 pub mod aggchain_proof;
+pub mod certificate;
 
 pub type EpochNumber = u64;
+pub type CertificateIndex = u64;
+pub type Height = u64;
 pub type CertificateId = Digest;
 
 pub use agglayer_primitives as primitives;
@@ -17,7 +21,7 @@ pub use agglayer_primitives::{Address, Signature, B256, U256, U512};
 pub use unified_bridge::{
     BridgeExit, Claim, ClaimFromMainnet, ClaimFromRollup, GlobalIndex, GlobalIndexWithLeafHash,
     ImportedBridgeExit, ImportedBridgeExitCommitmentValues, L1InfoTreeLeaf, L1InfoTreeLeafInner,
-    LeafType, MerkleProof, NetworkId, TokenInfo,
+    LeafType, LocalExitTree, MerkleProof, NetworkId, TokenInfo,
 };
 
 #[derive(Debug, thiserror::Error, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1,3 +1,5 @@
 #[cfg(feature = "grpc")]
 pub use agglayer_interop_grpc_types as grpc;
 pub use agglayer_interop_types as types;
+#[cfg(feature = "rpc-client")]
+pub use agglayer_rpc_client as rpc;
@@ -0,0 +1,480 @@
+use std::time::Duration;
+
+use agglayer_interop_types::{
+    certificate::{Certificate, CertificateHeader},
+    CertificateId, NetworkId,
+};
+use jsonrpsee::{
+    core::{client::ClientT as _, params::ArrayParams, ClientError},
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{methods, retry, EpochConfiguration, Error, NetworkStatus, RetryPolicy};
+
+/// Builder of a [`Client`].
+#[derive(Clone, Debug)]
+pub struct ClientBuilder {
+    url: String,
+    request_timeout: Duration,
+    retry_policy: RetryPolicy,
+    send_certificate_retry_policy: RetryPolicy,
+}
+
+impl ClientBuilder {
+    /// Sets the timeout of each attempt of a request.
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Sets the policy used to retry the requests failing with a transient
+    /// error.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the policy used to retry the certificates which did not reach
+    /// the agglayer.
+    ///
+    /// Unlike the other requests, certificates are not sent again after a
+    /// timeout or any other error which may happen once the agglayer received
+    /// them.
+    pub fn send_certificate_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.send_certificate_retry_policy = retry_policy;
+        self
+    }
+
+    pub fn build(self) -> Result<Client, Error> {
+        let inner = HttpClientBuilder::default()
+            .request_timeout(self.request_timeout)
+            .build(&self.url)
+            .map_err(|source| Error::Building {
+                url: self.url,
+                source,
+            })?;
+
+        Ok(Client {
+            inner,
+            retry_policy: self.retry_policy,
+            send_certificate_retry_policy: self.send_certificate_retry_policy,
+        })
+    }
+}
+
+/// Client of the agglayer JSON-RPC API over HTTP.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: HttpClient,
+    retry_policy: RetryPolicy,
+    send_certificate_retry_policy: RetryPolicy,
+}
+
+impl Client {
+    pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+    pub fn builder(url: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            url: url.into(),
+            request_timeout: Self::DEFAULT_REQUEST_TIMEOUT,
+            retry_policy: RetryPolicy::default(),
+            send_certificate_retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Creates a client with the default timeout and retry policy.
+    pub fn new(url: impl Into<String>) -> Result<Self, Error> {
+        Self::builder(url).build()
+    }
+
+    pub async fn send_certificate(
+        &self,
+        certificate: &Certificate,
+    ) -> Result<CertificateId, Error> {
+        self.request_with(
+            methods::SEND_CERTIFICATE,
+            rpc_params![certificate],
+            &self.send_certificate_retry_policy,
+            retry::is_unsent,
+        )
+        .await
+    }
+
+    pub async fn get_certificate_header(
+        &self,
+        certificate_id: CertificateId,
+    ) -> Result<CertificateHeader, Error> {
+        self.request(methods::GET_CERTIFICATE_HEADER, rpc_params![certificate_id])
+            .await
+    }
+
+    pub async fn get_epoch_configuration(&self) -> Result<EpochConfiguration, Error> {
+        self.request(methods::GET_EPOCH_CONFIGURATION, rpc_params![])
+            .await
+    }
+
+    pub async fn get_network_status(&self, network_id: NetworkId) -> Result<NetworkStatus, Error> {
+        self.request(methods::GET_NETWORK_STATUS, rpc_params![network_id])
+            .await
+    }
+
+    async fn request<R: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        params: ArrayParams,
+    ) -> Result<R, Error> {
+        self.request_with(method, params, &self.retry_policy, retry::is_transient)
+            .await
+    }
+
+    /// Sends a request, retrying the errors for which `retryable` holds.
+    async fn request_with<R: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        params: ArrayParams,
+        retry_policy: &RetryPolicy,
+        retryable: fn(&ClientError) -> bool,
+    ) -> Result<R, Error> {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let error = match self.inner.request(method, params.clone()).await {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+
+            let transient = retryable(&error);
+            if !transient || attempts > retry_policy.max_retries {
+                return Err(match error {
+                    ClientError::Call(source) if !transient => Error::Rejected { method, source },
+                    source => Error::Request {
+                        method,
+                        attempts,
+                        source,
+                    },
+                });
+            }
+
+            let backoff = retry_policy.backoff(attempts);
+            tracing::debug!(%error, ?backoff, attempts, "Retrying {method}");
+            tokio::time::sleep(backoff).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc, Mutex,
+        },
+    };
+
+    use agglayer_interop_types::{
+        aggchain_proof::AggchainData, certificate::CertificateStatus, Digest, Height,
+        LocalExitRoot, Signature, U256,
+    };
+    use jsonrpsee::{
+        server::{RpcModule, Server, ServerHandle},
+        types::{
+            error::{INVALID_PARAMS_CODE, SERVER_IS_BUSY_CODE},
+            ErrorObject, ErrorObjectOwned,
+        },
+    };
+
+    use super::*;
+
+    const EPOCH_CONFIGURATION: EpochConfiguration = EpochConfiguration {
+        genesis_block: 100,
+        epoch_duration: 10,
+    };
+
+    #[derive(Default)]
+    struct MockAgglayer {
+        certificates: Mutex<BTreeMap<CertificateId, Certificate>>,
+        /// Number of upcoming calls answered with a "server is busy" error.
+        busy: AtomicU32,
+        calls: AtomicU32,
+    }
+
+    impl MockAgglayer {
+        fn call(&self) -> Result<(), ErrorObjectOwned> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            match self
+                .busy
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |busy| {
+                    busy.checked_sub(1)
+                }) {
+                Ok(_) => Err(ErrorObject::owned(
+                    SERVER_IS_BUSY_CODE,
+                    "server is busy",
+                    None::<()>,
+                )),
+                Err(_) => Ok(()),
+            }
+        }
+
+        fn header(&self, certificate_id: CertificateId) -> Option<CertificateHeader> {
+            let certificates = self.certificates.lock().unwrap();
+            let certificate = certificates.get(&certificate_id)?;
+            Some(CertificateHeader {
+                network_id: certificate.network_id,
+                height: certificate.height,
+                epoch_number: None,
+                certificate_index: None,
+                certificate_id,
+                prev_local_exit_root: certificate.prev_local_exit_root,
+                new_local_exit_root: certificate.new_local_exit_root,
+                metadata: certificate.metadata,
+                status: CertificateStatus::Pending,
+                settlement_tx_hash: None,
+            })
+        }
+    }
+
+    fn not_found() -> ErrorObjectOwned {
+        ErrorObject::owned(INVALID_PARAMS_CODE, "not found", None::<()>)
+    }
+
+    fn certificate(height: Height) -> Certificate {
+        Certificate {
+            network_id: NetworkId::new(3),
+            height,
+            prev_local_exit_root: LocalExitRoot::new(Digest([0x11; 32])),
+            new_local_exit_root: LocalExitRoot::new(Digest([0x12; 32])),
+            bridge_exits: vec![],
+            imported_bridge_exits: vec![],
+            metadata: Digest([0x22; 32]),
+            aggchain_data: AggchainData::ECDSA {
+                signature: Signature::new(U256::from(1u64), U256::from(2u64), false),
+            },
+            custom_chain_data: vec![],
+            l1_info_tree_leaf_count: None,
+        }
+    }
+
+    fn module(agglayer: Arc<MockAgglayer>) -> RpcModule<Arc<MockAgglayer>> {
+        let mut module = RpcModule::new(agglayer);
+        module
+            .register_method(methods::SEND_CERTIFICATE, |params, agglayer, _| {
+                agglayer.call()?;
+                let certificate: Certificate = params.one()?;
+                let mut certificates = agglayer.certificates.lock().unwrap();
+                let certificate_id = Digest([certificates.len() as u8 + 1; 32]);
+                certificates.insert(certificate_id, certificate);
+                Ok::<_, ErrorObjectOwned>(certificate_id)
+            })
+            .unwrap();
+        module
+            .register_method(methods::GET_CERTIFICATE_HEADER, |params, agglayer, _| {
+                agglayer.call()?;
+                agglayer.header(params.one()?).ok_or_else(not_found)
+            })
+            .unwrap();
+        module
+            .register_method(methods::GET_EPOCH_CONFIGURATION, |_, agglayer, _| {
+                agglayer.call()?;
+                Ok::<_, ErrorObjectOwned>(EPOCH_CONFIGURATION)
+            })
+            .unwrap();
+        module
+            .register_method(methods::GET_NETWORK_STATUS, |params, agglayer, _| {
+                agglayer.call()?;
+                let network_id: NetworkId = params.one()?;
+                let certificates = agglayer.certificates.lock().unwrap();
+                let latest = certificates
+                    .values()
+                    .filter(|certificate| certificate.network_id == network_id)
+                    .max_by_key(|certificate| certificate.height);
+                Ok::<_, ErrorObjectOwned>(NetworkStatus {
+                    network_id,
+                    latest_pending_height: latest.map(|certificate| certificate.height),
+                    latest_settled_height: None,
+                    latest_settled_epoch: None,
+                    latest_settled_local_exit_root: None,
+                })
+            })
+            .unwrap();
+        module
+    }
+
+    /// Starts a server until the returned handle is dropped.
+    async fn spawn_server(agglayer: Arc<MockAgglayer>) -> (SocketAddr, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        (addr, server.start(module(agglayer)))
+    }
+
+    /// Starts a server accepting connections but never answering them.
+    async fn spawn_unresponsive_server() -> SocketAddr {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = vec![];
+            while let Ok((stream, _)) = listener.accept().await {
+                connections.push(stream);
+            }
+        });
+        addr
+    }
+
+    const RETRY_POLICY: RetryPolicy = RetryPolicy {
+        max_retries: 2,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(10),
+    };
+
+    fn client(addr: SocketAddr) -> Client {
+        Client::builder(format!("http://{addr}"))
+            .retry_policy(RETRY_POLICY)
+            .send_certificate_retry_policy(RETRY_POLICY)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn send_and_query_certificates() {
+        let (addr, _server) = spawn_server(Default::default()).await;
+        let client = client(addr);
+
+        let certificate_id = client.send_certificate(&certificate(5)).await.unwrap();
+        let header = client.get_certificate_header(certificate_id).await.unwrap();
+        assert_eq!(header.certificate_id, certificate_id);
+        assert_eq!(header.height, 5);
+        assert_eq!(header.status, CertificateStatus::Pending);
+
+        let status = client.get_network_status(NetworkId::new(3)).await.unwrap();
+        assert_eq!(status.latest_pending_height, Some(5));
+
+        assert_eq!(
+            client.get_epoch_configuration().await.unwrap(),
+            EPOCH_CONFIGURATION
+        );
+    }
+
+    #[tokio::test]
+    async fn rejections_are_not_retried() {
+        let agglayer = Arc::new(MockAgglayer::default());
+        let (addr, _server) = spawn_server(agglayer.clone()).await;
+
+        let err = client(addr)
+            .get_certificate_header(Digest([0xff; 32]))
+            .await
+            .unwrap_err();
+        assert_eq!(err.rejection().unwrap().message(), "not found");
+        assert_eq!(
+            err.to_string(),
+            "interop_getCertificateHeader was rejected by the agglayer"
+        );
+        assert_eq!(agglayer.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn busy_servers_are_retried() {
+        let agglayer = Arc::new(MockAgglayer {
+            busy: AtomicU32::new(2),
+            ..Default::default()
+        });
+        let (addr, _server) = spawn_server(agglayer.clone()).await;
+        let client = client(addr);
+
+        assert_eq!(
+            client.get_epoch_configuration().await.unwrap(),
+            EPOCH_CONFIGURATION
+        );
+        assert_eq!(agglayer.calls.load(Ordering::SeqCst), 3);
+
+        // Give up once the retries are exhausted.
+        agglayer.busy.store(3, Ordering::SeqCst);
+        let err = client.get_epoch_configuration().await.unwrap_err();
+        assert!(matches!(err, Error::Request { attempts: 3, .. }));
+        assert_eq!(agglayer.calls.load(Ordering::SeqCst), 6);
+    }
+
+    #[tokio::test]
+    async fn unreachable_servers_time_out() {
+        let addr = spawn_unresponsive_server().await;
+
+        let err = Client::builder(format!("http://{addr}"))
+            .request_timeout(Duration::from_millis(50))
+            .retry_policy(RetryPolicy::NONE)
+            .build()
+            .unwrap()
+            .get_epoch_configuration()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Request {
+                attempts: 1,
+                source: ClientError::RequestTimeout,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn certificates_are_not_sent_again_after_a_timeout() {
+        let addr = spawn_unresponsive_server().await;
+
+        let err = Client::builder(format!("http://{addr}"))
+            .request_timeout(Duration::from_millis(50))
+            .send_certificate_retry_policy(RETRY_POLICY)
+            .build()
+            .unwrap()
+            .send_certificate(&certificate(5))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Request {
+                attempts: 1,
+                source: ClientError::RequestTimeout,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn certificates_are_sent_again_when_the_connection_fails() {
+        // Nothing listens on the port once the listener is dropped.
+        let addr = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        let err = client(addr)
+            .send_certificate(&certificate(5))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Request {
+                attempts: 3,
+                source: ClientError::Transport(_),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn busy_servers_are_sent_certificates_again() {
+        let agglayer = Arc::new(MockAgglayer {
+            busy: AtomicU32::new(2),
+            ..Default::default()
+        });
+        let (addr, _server) = spawn_server(agglayer.clone()).await;
+
+        client(addr)
+            .send_certificate(&certificate(5))
+            .await
+            .unwrap();
+        assert_eq!(agglayer.calls.load(Ordering::SeqCst), 3);
+    }
+}
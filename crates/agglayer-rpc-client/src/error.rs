@@ -0,0 +1,31 @@
+use jsonrpsee::{core::ClientError, types::ErrorObjectOwned};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Building the client for {url}")]
+    Building { url: String, source: ClientError },
+
+    #[error("{method} was rejected by the agglayer")]
+    Rejected {
+        method: &'static str,
+        source: ErrorObjectOwned,
+    },
+
+    #[error("{method} failed after {attempts} attempts")]
+    Request {
+        method: &'static str,
+        attempts: u32,
+        source: ClientError,
+    },
+}
+
+impl Error {
+    /// Returns the JSON-RPC error object if the agglayer rejected the call.
+    #[inline]
+    pub fn rejection(&self) -> Option<&ErrorObjectOwned> {
+        match self {
+            Error::Rejected { source, .. } => Some(source),
+            Error::Building { .. } | Error::Request { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Client for the agglayer JSON-RPC API.
+
+mod client;
+mod error;
+pub mod methods;
+mod retry;
+mod types;
+
+pub use client::{Client, ClientBuilder};
+pub use error::Error;
+pub use retry::RetryPolicy;
+pub use types::{EpochConfiguration, NetworkStatus};
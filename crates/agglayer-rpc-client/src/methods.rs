@@ -0,0 +1,13 @@
+//! Names of the agglayer JSON-RPC methods.
+
+/// Submits a certificate, returning its identifier.
+pub const SEND_CERTIFICATE: &str = "interop_sendCertificate";
+
+/// Returns the header of a certificate given its identifier.
+pub const GET_CERTIFICATE_HEADER: &str = "interop_getCertificateHeader";
+
+/// Returns the epoch configuration of the agglayer.
+pub const GET_EPOCH_CONFIGURATION: &str = "interop_getEpochConfiguration";
+
+/// Returns the status of a network given its identifier.
+pub const GET_NETWORK_STATUS: &str = "interop_getNetworkStatus";
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use jsonrpsee::{
+    core::{http_helpers::HttpError, ClientError},
+    types::error::SERVER_IS_BUSY_CODE,
+};
+
+/// Exponential backoff applied to the requests failing with a transient
+/// error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between two attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy making a single attempt.
+    pub const NONE: Self = Self {
+        max_retries: 0,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    };
+
+    /// Delay to wait for before the given retry, starting at 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Whether the request may succeed if sent again.
+pub(crate) fn is_transient(error: &ClientError) -> bool {
+    match error {
+        ClientError::Transport(_) | ClientError::RequestTimeout | ClientError::RestartNeeded(_) => {
+            true
+        }
+        ClientError::Call(error) => error.code() == SERVER_IS_BUSY_CODE,
+        _ => false,
+    }
+}
+
+/// Whether the request failed before reaching the agglayer, so that sending
+/// it again cannot process it twice.
+///
+/// Timeouts are not included, as the agglayer may have processed the request
+/// without the response coming back in time.
+pub(crate) fn is_unsent(error: &ClientError) -> bool {
+    match error {
+        ClientError::Transport(error) => matches!(
+            error.downcast_ref::<jsonrpsee::http_client::transport::Error>(),
+            Some(jsonrpsee::http_client::transport::Error::Http(HttpError::Stream(error)))
+                if error
+                    .downcast_ref::<hyper_util::client::legacy::Error>()
+                    .is_some_and(hyper_util::client::legacy::Error::is_connect)
+        ),
+        ClientError::Call(error) => error.code() == SERVER_IS_BUSY_CODE,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        };
+
+        let backoffs: Vec<_> = (1..=6).map(|retry| policy.backoff(retry)).collect();
+        assert_eq!(
+            backoffs,
+            [100, 200, 400, 800, 1000, 1000].map(Duration::from_millis)
+        );
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,26 @@
+use agglayer_interop_types::{EpochNumber, Height, LocalExitRoot, NetworkId};
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the epochs in which certificates are settled.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EpochConfiguration {
+    /// L1 block at which the first epoch starts.
+    pub genesis_block: u64,
+    /// Number of L1 blocks in an epoch.
+    pub epoch_duration: u64,
+}
+
+/// Settlement status of a network as seen by the agglayer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NetworkStatus {
+    /// Identifier of the network.
+    pub network_id: NetworkId,
+    /// Height of the latest certificate received but not yet settled, if any.
+    pub latest_pending_height: Option<Height>,
+    /// Height of the latest settled certificate, if any.
+    pub latest_settled_height: Option<Height>,
+    /// Epoch in which the latest certificate was settled, if any.
+    pub latest_settled_epoch: Option<EpochNumber>,
+    /// Local exit root of the latest settled certificate, if any.
+    pub latest_settled_local_exit_root: Option<LocalExitRoot>,
+}
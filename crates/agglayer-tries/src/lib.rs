@@ -4,6 +4,7 @@ pub mod proof;
 pub mod roots;
 pub mod smt;
 pub mod utils;
+pub mod versioned_smt;
 
 #[cfg(test)]
 mod tests;
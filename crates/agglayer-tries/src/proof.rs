@@ -23,19 +23,31 @@ impl ToBits<32> for u32 {
 }
 
 #[serde_as]
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "testutils", derive(arbitrary::Arbitrary))]
 pub struct SmtMerkleProof<const DEPTH: usize> {
     #[serde_as(as = "[_; DEPTH]")]
     pub siblings: [Digest; DEPTH],
 }
 
 #[serde_as]
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SmtNonInclusionProof<const DEPTH: usize> {
     #[serde_as(as = "Vec<_>")]
     pub siblings: Vec<Digest>,
 }
 
+#[cfg(feature = "testutils")]
+impl<const DEPTH: usize> arbitrary::Arbitrary<'_> for SmtNonInclusionProof<DEPTH> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=DEPTH)?;
+        let siblings = (0..len)
+            .map(|_| u.arbitrary())
+            .collect::<arbitrary::Result<_>>()?;
+        Ok(Self { siblings })
+    }
+}
+
 impl<const DEPTH: usize> SmtMerkleProof<DEPTH> {
     pub fn verify<K>(&self, key: K, value: Digest, root: Digest) -> bool
     where
@@ -82,6 +94,20 @@ impl<const DEPTH: usize> SmtMerkleProof<DEPTH> {
 
         Some(hash)
     }
+
+    /// Verify the inclusion proof (i.e. that `(key, old_value)` is in the SMT)
+    /// and return the updated root of the SMT with `key` removed, or `None`
+    /// if the inclusion proof is invalid or `old_value` is empty.
+    pub fn verify_and_remove<K>(&self, key: K, old_value: Digest, root: Digest) -> Option<Digest>
+    where
+        K: ToBits<DEPTH> + Copy,
+    {
+        let empty_leaf = EMPTY_HASH_ARRAY_AT_193[0];
+        if old_value == empty_leaf {
+            return None;
+        }
+        self.verify_and_update(key, old_value, empty_leaf, root)
+    }
 }
 
 impl<const DEPTH: usize> SmtNonInclusionProof<DEPTH> {
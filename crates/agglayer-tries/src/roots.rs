@@ -8,6 +8,7 @@ macro_rules! define_digest_type {
         #[derive(
             Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default,
         )]
+        #[cfg_attr(feature = "testutils", derive(arbitrary::Arbitrary))]
         #[serde(transparent)]
         pub struct $name(Digest);
 
@@ -81,7 +81,16 @@ impl<const DEPTH: usize> Smt<DEPTH> {
     where
         K: ToBits<DEPTH>,
     {
-        let mut hash = self.root;
+        self.get_at(self.root, key)
+    }
+
+    /// Returns the value at the key in the SMT with the given root, whose
+    /// nodes must still be stored.
+    pub(crate) fn get_at<K>(&self, root: Digest, key: K) -> Option<Digest>
+    where
+        K: ToBits<DEPTH>,
+    {
+        let mut hash = root;
         for b in key.to_bits() {
             hash = if b {
                 self.tree.get(&hash)?.right
@@ -199,18 +208,62 @@ impl<const DEPTH: usize> Smt<DEPTH> {
         Ok(())
     }
 
+    /// Removes the value at the key and returns it.
+    ///
+    /// The subtrees left empty collapse back to their empty hash instead of
+    /// being stored. The nodes of the previous path may be shared with other
+    /// subtrees, so they are only dropped by [`Smt::traverse_and_prune`].
+    pub fn remove<K>(&mut self, key: K) -> Result<Digest, SmtError>
+    where
+        K: ToBits<DEPTH>,
+    {
+        let bits = key.to_bits();
+        let mut path = Vec::with_capacity(DEPTH);
+        let mut hash = self.root;
+        for bit in bits {
+            let node = *self.tree.get(&hash).ok_or(SmtError::KeyNotPresent)?;
+            hash = if bit { node.right } else { node.left };
+            path.push(node);
+        }
+        let old_value = hash;
+        if old_value == Self::EMPTY_HASH_ARRAY_AT_HEIGHT[0] {
+            return Err(SmtError::KeyNotPresent);
+        }
+
+        let mut hash = Self::EMPTY_HASH_ARRAY_AT_HEIGHT[0];
+        for (depth, mut node) in path.into_iter().enumerate().rev() {
+            if bits[depth] {
+                node.right = hash;
+            } else {
+                node.left = hash;
+            }
+            hash = node.hash();
+
+            // The root is always stored, as in an empty SMT.
+            let empty_child = Self::empty_hash_at_depth_from_root(depth)?;
+            if depth == 0 || node.left != empty_child || node.right != empty_child {
+                self.tree.insert(hash, node);
+            }
+        }
+        self.root = hash;
+
+        Ok(old_value)
+    }
+
     fn traverse_helper(
         &self,
         hash: Digest,
         depth: usize,
         nodes: &mut HashSet<Digest>,
     ) -> Result<(), SmtError> {
-        nodes.insert(hash);
-
         if depth == DEPTH {
             // We've reached a leaf.
             return Ok(());
         }
+        if !nodes.insert(hash) {
+            // Subtree already traversed, e.g. shared with another root.
+            return Ok(());
+        }
 
         let node = self.tree.get(&hash).ok_or(SmtError::KeyNotPresent)?;
         if node.left != Self::empty_hash_at_depth_from_root(depth)? {
@@ -239,8 +292,18 @@ impl<const DEPTH: usize> Smt<DEPTH> {
     where
         Digest: Eq + Hash,
     {
+        self.prune_unreachable_from([self.root])
+    }
+
+    /// Prunes all the nodes which are not reachable from any of the roots.
+    pub(crate) fn prune_unreachable_from(
+        &mut self,
+        roots: impl IntoIterator<Item = Digest>,
+    ) -> Result<(), SmtError> {
         let mut seen_nodes = HashSet::new();
-        self.traverse_helper(self.root, 0, &mut seen_nodes)?;
+        for root in roots {
+            self.traverse_helper(root, 0, &mut seen_nodes)?;
+        }
         self.tree.retain(|k, _v| seen_nodes.contains(k));
 
         Ok(())
@@ -248,6 +311,7 @@ impl<const DEPTH: usize> Smt<DEPTH> {
 
     fn get_inclusion_proof_helper<K>(
         &self,
+        root: Digest,
         key: K,
         zero_allowed: bool,
     ) -> Result<SmtMerkleProof<DEPTH>, SmtError>
@@ -255,7 +319,7 @@ impl<const DEPTH: usize> Smt<DEPTH> {
         K: ToBits<DEPTH>,
     {
         let mut siblings = [Self::EMPTY_HASH_ARRAY_AT_HEIGHT[0]; DEPTH];
-        let mut hash = self.root;
+        let mut hash = root;
         let bits = key.to_bits();
         for i in 0..DEPTH {
             let node = self.tree.get(&hash).ok_or(SmtError::KeyNotPresent)?;
@@ -274,7 +338,20 @@ impl<const DEPTH: usize> Smt<DEPTH> {
     where
         K: ToBits<DEPTH>,
     {
-        self.get_inclusion_proof_helper(key, false)
+        self.get_inclusion_proof_at(self.root, key)
+    }
+
+    /// Returns an inclusion proof of the key in the SMT with the given root,
+    /// whose nodes must still be stored.
+    pub(crate) fn get_inclusion_proof_at<K>(
+        &self,
+        root: Digest,
+        key: K,
+    ) -> Result<SmtMerkleProof<DEPTH>, SmtError>
+    where
+        K: ToBits<DEPTH>,
+    {
+        self.get_inclusion_proof_helper(root, key, false)
     }
 
     /// Returns an inclusion proof that the key is not in the SMT.
@@ -291,18 +368,31 @@ impl<const DEPTH: usize> Smt<DEPTH> {
         // Hack: We use `insert` to insert all the necessary nodes in the SMT.
         // This will return an error if the key is in the SMT.
         self.insert(key, Self::EMPTY_HASH_ARRAY_AT_HEIGHT[0])?;
-        self.get_inclusion_proof_helper(key, true)
+        self.get_inclusion_proof_helper(self.root, key, true)
     }
 
     pub fn get_non_inclusion_proof<K>(
         &self,
         key: K,
     ) -> Result<SmtNonInclusionProof<DEPTH>, SmtError>
+    where
+        K: ToBits<DEPTH>,
+    {
+        self.get_non_inclusion_proof_at(self.root, key)
+    }
+
+    /// Returns a non-inclusion proof of the key in the SMT with the given
+    /// root, whose nodes must still be stored.
+    pub(crate) fn get_non_inclusion_proof_at<K>(
+        &self,
+        root: Digest,
+        key: K,
+    ) -> Result<SmtNonInclusionProof<DEPTH>, SmtError>
     where
         K: ToBits<DEPTH>,
     {
         let mut siblings = vec![];
-        let mut hash = self.root;
+        let mut hash = root;
         let bits = key.to_bits();
 
         for (depth, bit) in bits.iter().take(DEPTH).enumerate() {
@@ -1 +1,3 @@
 mod smt;
+mod smt_properties;
+mod versioned_smt;
@@ -223,6 +223,80 @@ fn test_inclusion_proof_zero_doesnt_update() {
     assert_eq!(root, smt.root, "The SMT should not be updated");
 }
 
+#[test]
+fn test_remove() {
+    let mut rng = rng();
+    let num_keys = rng.random_range(1..100);
+    let kvs: Vec<(u32, _)> = (0..num_keys).map(|_| (random(), random())).collect();
+    check_no_duplicates(&kvs);
+
+    let mut smt = Smt::<DEPTH>::new();
+    for (key, value) in kvs.iter() {
+        smt.insert(*key, *value).unwrap();
+    }
+    let (key, value) = *kvs.choose(&mut rng).unwrap();
+    assert_eq!(smt.remove(key), Ok(value));
+    assert_eq!(smt.remove(key), Err(SmtError::KeyNotPresent));
+    assert!(smt
+        .get_non_inclusion_proof(key)
+        .unwrap()
+        .verify(key, smt.root));
+
+    let mut expected = Smt::<DEPTH>::new();
+    for (other_key, other_value) in kvs.iter().filter(|(k, _)| *k != key) {
+        expected.insert(*other_key, *other_value).unwrap();
+    }
+    assert_eq!(smt.root, expected.root);
+
+    // The key can be inserted again.
+    smt.insert(key, value).unwrap();
+    assert_eq!(smt.get(key), Some(value));
+}
+
+#[test]
+fn test_remove_collapses_empty_subtrees() {
+    let mut smt = Smt::<DEPTH>::new();
+    smt.insert(42u32, Digest([0xa; 32])).unwrap();
+    assert_eq!(smt.remove(42u32), Ok(Digest([0xa; 32])));
+    assert!(smt.is_empty());
+    assert!(!smt
+        .tree
+        .keys()
+        .any(|hash| Smt::<DEPTH>::EMPTY_HASH_ARRAY_AT_HEIGHT.contains(hash)));
+
+    smt.traverse_and_prune().unwrap();
+    assert_eq!(smt.tree, Smt::<DEPTH>::new().tree);
+}
+
+#[test]
+fn test_remove_absent_key() {
+    let mut smt = Smt::<DEPTH>::new();
+    assert_eq!(smt.remove(42u32), Err(SmtError::KeyNotPresent));
+
+    smt.insert(42u32, Digest([0xa; 32])).unwrap();
+    let root = smt.root;
+    assert_eq!(smt.remove(43u32), Err(SmtError::KeyNotPresent));
+    assert_eq!(smt.root, root);
+}
+
+#[test]
+fn test_inclusion_proof_and_remove() {
+    let num_keys = rng().random_range(1..100);
+    let mut smt = Smt::<DEPTH>::new();
+    let kvs: Vec<(u32, _)> = (0..num_keys).map(|_| (random(), random())).collect();
+    check_no_duplicates(&kvs);
+    for (key, value) in kvs.iter() {
+        smt.insert(*key, *value).unwrap();
+    }
+    let (key, value) = kvs[rng().random_range(0..num_keys)];
+    let proof = smt.get_inclusion_proof(key).unwrap();
+    assert_eq!(proof.verify_and_remove(key, random(), smt.root), None);
+    assert_eq!(proof.verify_and_remove(key, Digest::ZERO, smt.root), None);
+    let new_root = proof.verify_and_remove(key, value, smt.root).unwrap();
+    smt.remove(key).unwrap();
+    assert_eq!(smt.root, new_root);
+}
+
 #[test]
 fn test_traverse_and_prune() {
     let mut rng = rng();
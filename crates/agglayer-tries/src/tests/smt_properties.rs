@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+use agglayer_primitives::{keccak::keccak256_combine, Digest};
+
+use crate::{error::SmtError, smt::Smt};
+
+const DEPTH: usize = 8;
+
+/// Operation applied to both the SMT and its reference model.
+#[derive(Clone, Debug, arbitrary::Arbitrary)]
+enum Op {
+    Insert(u8, Digest),
+    Update(u8, Digest),
+    Remove(u8),
+    InclusionProofZero(u8),
+    Prune,
+}
+
+/// Reference model of the SMT: the non-zero leaves of a full Merkle tree.
+#[derive(Default)]
+struct Model {
+    leaves: BTreeMap<u8, Digest>,
+}
+
+impl Model {
+    fn get(&self, key: u8) -> Digest {
+        self.leaves.get(&key).copied().unwrap_or(Digest::ZERO)
+    }
+
+    fn set(&mut self, key: u8, value: Digest) {
+        if value == Digest::ZERO {
+            self.leaves.remove(&key);
+        } else {
+            self.leaves.insert(key, value);
+        }
+    }
+
+    fn root(&self) -> Digest {
+        // Keys are read from their least significant bit down the tree.
+        let mut layer = vec![Digest::ZERO; 1 << DEPTH];
+        for (key, value) in &self.leaves {
+            layer[key.reverse_bits() as usize] = *value;
+        }
+        while layer.len() > 1 {
+            layer = layer
+                .chunks(2)
+                .map(|pair| keccak256_combine([pair[0], pair[1]]))
+                .collect();
+        }
+        layer[0]
+    }
+}
+
+fn apply(smt: &mut Smt<DEPTH>, model: &mut Model, op: &Op) {
+    match *op {
+        Op::Insert(key, value) => {
+            let root = smt.root;
+            if model.leaves.contains_key(&key) {
+                assert_eq!(smt.insert(key, value), Err(SmtError::KeyAlreadyPresent));
+                assert_eq!(smt.root, root);
+                return;
+            }
+            let proof = smt.get_non_inclusion_proof(key).unwrap();
+            assert!(proof.verify(key, root));
+            let new_root = proof.verify_and_update(key, value, root);
+
+            smt.insert(key, value).unwrap();
+            model.set(key, value);
+            assert_eq!(new_root, Some(smt.root));
+        }
+        Op::Update(key, value) => {
+            let root = smt.root;
+            let old_value = model.get(key);
+            let proof = if old_value == Digest::ZERO {
+                smt.get_inclusion_proof_zero(key).unwrap()
+            } else {
+                smt.get_inclusion_proof(key).unwrap()
+            };
+            assert_eq!(smt.root, root);
+            let new_root = proof.verify_and_update(key, old_value, value, root);
+
+            smt.update(key, value).unwrap();
+            model.set(key, value);
+            assert_eq!(new_root, Some(smt.root));
+        }
+        Op::Remove(key) => {
+            let root = smt.root;
+            let old_value = model.get(key);
+            if old_value == Digest::ZERO {
+                assert_eq!(smt.remove(key), Err(SmtError::KeyNotPresent));
+                assert_eq!(smt.root, root);
+                return;
+            }
+            let proof = smt.get_inclusion_proof(key).unwrap();
+            let new_root = proof.verify_and_remove(key, old_value, root);
+
+            assert_eq!(smt.remove(key), Ok(old_value));
+            model.set(key, Digest::ZERO);
+            assert_eq!(new_root, Some(smt.root));
+        }
+        Op::InclusionProofZero(key) => {
+            let root = smt.root;
+            if model.leaves.contains_key(&key) {
+                assert_eq!(
+                    smt.get_inclusion_proof_zero(key),
+                    Err(SmtError::KeyAlreadyPresent)
+                );
+            } else {
+                let proof = smt.get_inclusion_proof_zero(key).unwrap();
+                assert!(proof.verify(key, Digest::ZERO, root));
+            }
+            assert_eq!(smt.root, root);
+        }
+        Op::Prune => {
+            let root = smt.root;
+            smt.traverse_and_prune().unwrap();
+            assert_eq!(smt.root, root);
+        }
+    }
+}
+
+/// Checks the SMT against its model, and the proofs of every key.
+fn check_invariants(smt: &Smt<DEPTH>, model: &Model) {
+    assert_eq!(smt.root, model.root());
+    assert_eq!(smt.is_empty(), model.leaves.is_empty());
+
+    let entries: BTreeMap<_, _> = smt
+        .entries()
+        .unwrap()
+        .into_iter()
+        .map(|(path, value)| {
+            let key = path
+                .as_bits()
+                .iter()
+                .rev()
+                .fold(0u8, |key, bit| (key << 1) | u8::from(*bit));
+            (key, value)
+        })
+        .collect();
+    assert_eq!(entries, model.leaves);
+
+    for key in 0..=u8::MAX {
+        let value = model.get(key);
+        if value != Digest::ZERO {
+            assert_eq!(smt.get(key), Some(value));
+            let proof = smt.get_inclusion_proof(key).unwrap();
+            assert!(proof.verify(key, value, smt.root));
+            assert!(!proof.verify(key, Digest::ZERO, smt.root));
+            assert_eq!(smt.get_non_inclusion_proof(key), Err(SmtError::KeyPresent));
+        } else {
+            let proof = smt.get_non_inclusion_proof(key).unwrap();
+            assert!(proof.verify(key, smt.root));
+            // The proof that a key is absent cannot be used for a present one.
+            for present in model.leaves.keys() {
+                assert!(!proof.verify(*present, smt.root));
+            }
+        }
+    }
+}
+
+fn check_operations(ops: &[Op]) {
+    let mut smt = Smt::<DEPTH>::new();
+    let mut model = Model::default();
+    check_invariants(&smt, &model);
+    for op in ops {
+        apply(&mut smt, &mut model, op);
+        check_invariants(&smt, &model);
+    }
+
+    // Pruning keeps every node reachable from the root.
+    smt.traverse_and_prune().unwrap();
+    check_invariants(&smt, &model);
+}
+
+#[test]
+fn fuzz_smt_operations() {
+    bolero::check!()
+        .with_arbitrary::<Vec<Op>>()
+        .for_each(|ops| check_operations(ops))
+}
+
+#[test]
+fn zero_proofs_are_pruned() {
+    let mut smt = Smt::<DEPTH>::new();
+    smt.insert(3, Digest([1; 32])).unwrap();
+    smt.traverse_and_prune().unwrap();
+    let nodes = smt.tree.len();
+
+    smt.get_inclusion_proof_zero(4).unwrap();
+    assert!(smt.tree.len() > nodes);
+    smt.traverse_and_prune().unwrap();
+    assert_eq!(smt.tree.len(), nodes);
+}
+
+#[test]
+fn mixed_operations() {
+    let ops = [
+        Op::Insert(1, Digest([1; 32])),
+        Op::InclusionProofZero(2),
+        Op::Update(2, Digest([2; 32])),
+        Op::Prune,
+        Op::Insert(2, Digest([3; 32])),
+        Op::Update(1, Digest::ZERO),
+        Op::Insert(1, Digest([4; 32])),
+        Op::Update(129, Digest([5; 32])),
+        Op::Prune,
+        Op::Update(129, Digest::ZERO),
+        Op::Remove(2),
+        Op::Remove(2),
+        Op::Prune,
+        Op::Insert(2, Digest([6; 32])),
+        Op::Remove(1),
+    ];
+    check_operations(&ops);
+}
@@ -0,0 +1,129 @@
+use agglayer_primitives::Digest;
+use rand::{random, rng, RngExt as _};
+
+use crate::{error::SmtError, smt::Smt, versioned_smt::VersionedSmt};
+
+const DEPTH: usize = 32;
+
+fn random_kvs(num_keys: usize) -> Vec<(u32, Digest)> {
+    let kvs: Vec<(u32, Digest)> = (0..num_keys).map(|_| (random(), random())).collect();
+    let mut keys: Vec<_> = kvs.iter().map(|(key, _)| *key).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    assert_eq!(keys.len(), num_keys, "Duplicate key. Check your rng.");
+    kvs
+}
+
+#[test]
+fn test_proofs_against_snapshots() {
+    let num_keys = rng().random_range(2..100);
+    let kvs = random_kvs(2 * num_keys);
+    let (old_kvs, new_kvs) = kvs.split_at(num_keys);
+
+    let mut smt = VersionedSmt::<DEPTH>::new();
+    for (key, value) in old_kvs {
+        smt.insert(*key, *value).unwrap();
+    }
+    let old_root = smt.snapshot(1);
+
+    // Apply the next certificate: new keys, one update and one removal.
+    for (key, value) in new_kvs {
+        smt.insert(*key, *value).unwrap();
+    }
+    let (updated_key, old_value) = old_kvs[0];
+    smt.update(updated_key, random()).unwrap();
+    let (removed_key, removed_value) = old_kvs[1];
+    smt.remove(removed_key).unwrap();
+    smt.snapshot(2);
+    smt.collect_garbage().unwrap();
+
+    let snapshot = smt.get_snapshot(&1).unwrap();
+    assert_eq!(snapshot.root(), old_root);
+    let proof = snapshot.get_inclusion_proof(updated_key).unwrap();
+    assert!(proof.verify(updated_key, old_value, old_root));
+    let proof = snapshot.get_inclusion_proof(removed_key).unwrap();
+    assert!(proof.verify(removed_key, removed_value, old_root));
+    assert_eq!(snapshot.get(removed_key), Some(removed_value));
+    for (key, _) in new_kvs {
+        let proof = snapshot.get_non_inclusion_proof(*key).unwrap();
+        assert!(proof.verify(*key, old_root));
+    }
+
+    let snapshot = smt.get_snapshot(&2).unwrap();
+    assert_eq!(snapshot.root(), smt.root());
+    assert_eq!(
+        snapshot.get_non_inclusion_proof(updated_key).unwrap_err(),
+        SmtError::KeyPresent
+    );
+    assert!(snapshot
+        .get_non_inclusion_proof(removed_key)
+        .unwrap()
+        .verify(removed_key, smt.root()));
+}
+
+#[test]
+fn test_garbage_collection_keeps_pinned_nodes_only() {
+    let kvs = random_kvs(20);
+    let mut smt = VersionedSmt::<DEPTH>::new();
+    let mut expected = Smt::<DEPTH>::new();
+    for (key, value) in &kvs {
+        smt.insert(*key, *value).unwrap();
+        expected.insert(*key, *value).unwrap();
+        smt.snapshot(*key as u64);
+    }
+
+    // The nodes of the older snapshots are kept.
+    smt.collect_garbage().unwrap();
+    expected.traverse_and_prune().unwrap();
+    assert!(smt.current().tree.len() > expected.tree.len());
+
+    for (key, _) in &kvs[..kvs.len() - 1] {
+        smt.release(&(*key as u64)).unwrap();
+    }
+    smt.collect_garbage().unwrap();
+    assert_eq!(smt.current().tree, expected.tree);
+    assert_eq!(smt.snapshots().count(), 1);
+}
+
+#[test]
+fn test_snapshots_sharing_a_root() {
+    let mut smt = VersionedSmt::<DEPTH, &str>::new();
+    smt.insert(1u32, Digest([1; 32])).unwrap();
+    let root = smt.snapshot("a");
+    assert_eq!(smt.snapshot("b"), root);
+
+    smt.update(1u32, Digest([2; 32])).unwrap();
+    assert_eq!(smt.release(&"a"), Some(root));
+    assert!(smt.is_pinned(root));
+    smt.collect_garbage().unwrap();
+    assert_eq!(
+        smt.get_snapshot(&"b").unwrap().get(1u32),
+        Some(Digest([1; 32]))
+    );
+
+    // Taking a snapshot under the same name unpins the previous root.
+    let new_root = smt.snapshot("b");
+    assert!(!smt.is_pinned(root));
+    assert!(smt.is_pinned(new_root));
+    assert_eq!(smt.release(&"a"), None);
+    smt.collect_garbage().unwrap();
+    assert!(!smt.current().tree.contains_key(&root));
+}
+
+#[test]
+fn test_garbage_collection_without_snapshots() {
+    let kvs = random_kvs(20);
+    let mut smt = VersionedSmt::<DEPTH>::new();
+    let mut expected = Smt::<DEPTH>::new();
+    for (key, value) in &kvs {
+        smt.insert(*key, *value).unwrap();
+        expected.insert(*key, *value).unwrap();
+    }
+    smt.remove(kvs[0].0).unwrap();
+    expected.remove(kvs[0].0).unwrap();
+
+    smt.collect_garbage().unwrap();
+    expected.traverse_and_prune().unwrap();
+    assert_eq!(smt.root(), expected.root);
+    assert_eq!(smt.current().tree, expected.tree);
+}
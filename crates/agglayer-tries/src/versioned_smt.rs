@@ -0,0 +1,197 @@
+use std::collections::{BTreeMap, HashMap};
+
+use agglayer_primitives::Digest;
+
+use crate::{
+    error::SmtError,
+    proof::{SmtMerkleProof, SmtNonInclusionProof, ToBits},
+    smt::Smt,
+};
+
+/// An SMT keeping the nodes of named snapshots of its root, so that proofs
+/// can still be generated against them after the SMT is updated.
+///
+/// The snapshots are typically named after the height of the certificate
+/// which produced them. Nodes are shared between the snapshots, and
+/// [`VersionedSmt::collect_garbage`] only removes the nodes which are not
+/// reachable from the current root nor from any snapshot.
+#[derive(Clone, Debug)]
+pub struct VersionedSmt<const DEPTH: usize, S = u64> {
+    smt: Smt<DEPTH>,
+
+    /// The pinned root of each snapshot.
+    snapshots: BTreeMap<S, Digest>,
+
+    /// The number of snapshots pinning each root.
+    pinned_roots: HashMap<Digest, usize>,
+}
+
+impl<const DEPTH: usize, S: Ord> Default for VersionedSmt<DEPTH, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const DEPTH: usize, S: Ord> From<Smt<DEPTH>> for VersionedSmt<DEPTH, S> {
+    #[inline]
+    fn from(smt: Smt<DEPTH>) -> Self {
+        Self {
+            smt,
+            snapshots: BTreeMap::new(),
+            pinned_roots: HashMap::new(),
+        }
+    }
+}
+
+impl<const DEPTH: usize, S: Ord> VersionedSmt<DEPTH, S> {
+    #[inline]
+    pub fn new() -> Self {
+        Smt::new().into()
+    }
+
+    /// Returns the SMT at its current root.
+    #[inline]
+    pub fn current(&self) -> &Smt<DEPTH> {
+        &self.smt
+    }
+
+    #[inline]
+    pub fn root(&self) -> Digest {
+        self.smt.root
+    }
+
+    #[inline]
+    pub fn insert<K>(&mut self, key: K, value: Digest) -> Result<(), SmtError>
+    where
+        K: ToBits<DEPTH>,
+    {
+        self.smt.insert(key, value)
+    }
+
+    #[inline]
+    pub fn update<K>(&mut self, key: K, value: Digest) -> Result<(), SmtError>
+    where
+        K: ToBits<DEPTH>,
+    {
+        self.smt.update(key, value)
+    }
+
+    #[inline]
+    pub fn remove<K>(&mut self, key: K) -> Result<Digest, SmtError>
+    where
+        K: ToBits<DEPTH>,
+    {
+        self.smt.remove(key)
+    }
+
+    /// See [`Smt::get_inclusion_proof_zero`].
+    #[inline]
+    pub fn get_inclusion_proof_zero<K>(&mut self, key: K) -> Result<SmtMerkleProof<DEPTH>, SmtError>
+    where
+        K: Copy + ToBits<DEPTH>,
+    {
+        self.smt.get_inclusion_proof_zero(key)
+    }
+
+    /// Pins the current root under the given name, replacing the snapshot
+    /// previously taken under that name, and returns the root.
+    pub fn snapshot(&mut self, name: S) -> Digest {
+        let root = self.smt.root;
+        *self.pinned_roots.entry(root).or_default() += 1;
+        if let Some(previous) = self.snapshots.insert(name, root) {
+            self.unpin(previous);
+        }
+
+        root
+    }
+
+    /// Releases the snapshot, its nodes are removed by the next garbage
+    /// collection unless they are still reachable. Returns its root, or
+    /// `None` if there is no snapshot with that name.
+    pub fn release(&mut self, name: &S) -> Option<Digest> {
+        let root = self.snapshots.remove(name)?;
+        self.unpin(root);
+
+        Some(root)
+    }
+
+    fn unpin(&mut self, root: Digest) {
+        if let Some(count) = self.pinned_roots.get_mut(&root) {
+            *count -= 1;
+            if *count == 0 {
+                self.pinned_roots.remove(&root);
+            }
+        }
+    }
+
+    /// Returns whether a snapshot pins the given root.
+    #[inline]
+    pub fn is_pinned(&self, root: Digest) -> bool {
+        self.pinned_roots.contains_key(&root)
+    }
+
+    /// Returns the snapshot with the given name.
+    pub fn get_snapshot(&self, name: &S) -> Option<SmtSnapshot<'_, DEPTH>> {
+        let root = *self.snapshots.get(name)?;
+        Some(SmtSnapshot {
+            smt: &self.smt,
+            root,
+        })
+    }
+
+    /// Returns the names and roots of the snapshots, in order.
+    pub fn snapshots(&self) -> impl Iterator<Item = (&S, Digest)> + '_ {
+        self.snapshots.iter().map(|(name, root)| (name, *root))
+    }
+
+    /// Removes the nodes which are reachable neither from the current root
+    /// nor from any snapshot.
+    pub fn collect_garbage(&mut self) -> Result<(), SmtError> {
+        let roots = std::iter::once(self.smt.root)
+            .chain(self.pinned_roots.keys().copied())
+            .collect::<Vec<_>>();
+        self.smt.prune_unreachable_from(roots)
+    }
+}
+
+/// Read-only view of a [`VersionedSmt`] at the root of one of its snapshots.
+#[derive(Clone, Copy, Debug)]
+pub struct SmtSnapshot<'a, const DEPTH: usize> {
+    smt: &'a Smt<DEPTH>,
+    root: Digest,
+}
+
+impl<const DEPTH: usize> SmtSnapshot<'_, DEPTH> {
+    #[inline]
+    pub fn root(&self) -> Digest {
+        self.root
+    }
+
+    #[inline]
+    pub fn get<K>(&self, key: K) -> Option<Digest>
+    where
+        K: ToBits<DEPTH>,
+    {
+        self.smt.get_at(self.root, key)
+    }
+
+    #[inline]
+    pub fn get_inclusion_proof<K>(&self, key: K) -> Result<SmtMerkleProof<DEPTH>, SmtError>
+    where
+        K: ToBits<DEPTH>,
+    {
+        self.smt.get_inclusion_proof_at(self.root, key)
+    }
+
+    #[inline]
+    pub fn get_non_inclusion_proof<K>(
+        &self,
+        key: K,
+    ) -> Result<SmtNonInclusionProof<DEPTH>, SmtError>
+    where
+        K: ToBits<DEPTH>,
+    {
+        self.smt.get_non_inclusion_proof_at(self.root, key)
+    }
+}
@@ -8,7 +8,8 @@ pub mod proof;
 
 /// Represents a local exit tree as defined by the LxLy bridge.
 #[serde_as]
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "testutils", derive(arbitrary::Arbitrary))]
 pub struct LocalExitTree<const TREE_DEPTH: usize = 32> {
     /// The number of inserted (non-empty) leaves.
     pub leaf_count: u32,
@@ -148,9 +149,104 @@ fn get_bit_at(target: u32, bit_idx: usize) -> u32 {
 
 #[cfg(test)]
 mod tests {
-    use agglayer_primitives::{Address, Hashable, U256};
+    use agglayer_primitives::{keccak::keccak256_combine, Address, Digest, Hashable, U256};
 
-    use crate::{bridge_exit::BridgeExit, local_exit_tree::LocalExitTree, token_info::LeafType};
+    use crate::{
+        bridge_exit::BridgeExit,
+        local_exit_tree::{LocalExitTree, LocalExitTreeError},
+        token_info::LeafType,
+    };
+
+    /// Naive Merkle tree keeping all its leaves, used as a reference for the
+    /// frontier-based [`LocalExitTree`].
+    struct FullTree<const TREE_DEPTH: usize> {
+        leaves: Vec<Digest>,
+    }
+
+    impl<const TREE_DEPTH: usize> FullTree<TREE_DEPTH> {
+        fn root(&self) -> Digest {
+            let mut layer = self.leaves.clone();
+            layer.resize(1 << TREE_DEPTH, Digest::ZERO);
+            for _ in 0..TREE_DEPTH {
+                layer = layer
+                    .chunks(2)
+                    .map(|pair| keccak256_combine([pair[0], pair[1]]))
+                    .collect();
+            }
+            layer[0]
+        }
+    }
+
+    /// Appends `num_leaves` leaves, cycling through `leaves`, to both trees
+    /// and checks that they stay in sync until the tree is full.
+    fn check_against_full_tree<const TREE_DEPTH: usize>(num_leaves: usize, leaves: &[Digest]) {
+        // The last leaf index is reserved, as the frontier cannot hold a full
+        // tree.
+        let capacity = (1 << TREE_DEPTH) - 1;
+        let mut tree = LocalExitTree::<TREE_DEPTH>::new();
+        let mut full_tree = FullTree::<TREE_DEPTH> { leaves: Vec::new() };
+        assert_eq!(tree.get_root(), full_tree.root());
+
+        for leaf in leaves.iter().cycle().take(num_leaves) {
+            if full_tree.leaves.len() < capacity {
+                full_tree.leaves.push(*leaf);
+                assert_eq!(tree.add_leaf(*leaf), Ok(full_tree.leaves.len() as u32));
+            } else {
+                let before = tree.clone();
+                assert_eq!(
+                    tree.add_leaf(*leaf),
+                    Err(LocalExitTreeError::LeafIndexOverflow)
+                );
+                assert_eq!(tree, before);
+            }
+            assert_eq!(tree.leaf_count() as usize, full_tree.leaves.len());
+            assert_eq!(tree.get_root(), full_tree.root());
+            assert_eq!(
+                LocalExitTree::<TREE_DEPTH>::from_parts(tree.leaf_count(), tree.frontier())
+                    .get_root(),
+                full_tree.root()
+            );
+        }
+    }
+
+    macro_rules! make_full_tree_fuzzers {
+        ($test:ident, $depth:literal) => {
+            #[test]
+            fn $test() {
+                bolero::check!()
+                    .with_arbitrary::<(u16, Vec<Digest>)>()
+                    .for_each(|(num_leaves, leaves)| {
+                        // Go a few leaves past the capacity to hit the overflow.
+                        let num_leaves = *num_leaves as usize % ((1 << $depth) + 3);
+                        check_against_full_tree::<$depth>(num_leaves, leaves);
+                    })
+            }
+        };
+    }
+
+    make_full_tree_fuzzers!(fuzz_local_exit_tree_depth_1, 1);
+    make_full_tree_fuzzers!(fuzz_local_exit_tree_depth_2, 2);
+    make_full_tree_fuzzers!(fuzz_local_exit_tree_depth_3, 3);
+    make_full_tree_fuzzers!(fuzz_local_exit_tree_depth_4, 4);
+    make_full_tree_fuzzers!(fuzz_local_exit_tree_depth_7, 7);
+
+    #[test]
+    fn leaves_past_the_capacity_are_rejected() {
+        let leaves: Vec<_> = (0..16u8).map(|i| Digest([i; 32])).collect();
+        check_against_full_tree::<1>(4, &leaves);
+        check_against_full_tree::<2>(6, &leaves);
+        check_against_full_tree::<4>(18, &leaves);
+    }
+
+    #[test]
+    fn last_leaf_of_the_full_depth_tree_is_rejected() {
+        let mut tree = LocalExitTree::<32>::from_parts(u32::MAX - 1, [Digest::ZERO; 32]);
+        assert_eq!(tree.add_leaf(Digest([1; 32])), Ok(u32::MAX));
+        assert_eq!(
+            tree.add_leaf(Digest([2; 32])),
+            Err(LocalExitTreeError::LeafIndexOverflow)
+        );
+    }
 
     #[test]
     fn test_deposit_hash() {
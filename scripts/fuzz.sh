@@ -13,10 +13,14 @@ fuzzers=(
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_imported_bridge_exit"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_l1_info_tree_leaf_with_context"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_l1_info_tree_leaf_inner"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_local_exit_root"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_local_exit_tree"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_merkle_proof"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_smt_merkle_proof"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_smt_non_inclusion_proof"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_token_info"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_u256"
-    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_parser_address"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_address"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_aggchain_data"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_bridge_exit"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_claim_from_mainnet"
@@ -24,11 +28,27 @@ fuzzers=(
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_digest"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_global_index"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_imported_bridge_exit"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_l1_info_root"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_l1_info_tree_leaf_with_context"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_l1_info_tree_leaf_inner"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_local_balance_root"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_local_exit_root"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_local_exit_tree"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_local_nullifier_root"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_merkle_proof"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_pessimistic_root"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_smt_merkle_proof"
+    "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_smt_non_inclusion_proof"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_token_info"
     "agglayer-interop-grpc-types/compat::v1::tests::fuzz_round_trip_u256"
+    "agglayer-interop-grpc-types/compat::v2::tests::fuzz_parser_aggchain_data"
+    "agglayer-interop-grpc-types/compat::v2::tests::fuzz_round_trip_bridge_exit"
+    "agglayer-tries/tests::smt_properties::fuzz_smt_operations"
+    "unified-bridge/local_exit_tree::tests::fuzz_local_exit_tree_depth_1"
+    "unified-bridge/local_exit_tree::tests::fuzz_local_exit_tree_depth_2"
+    "unified-bridge/local_exit_tree::tests::fuzz_local_exit_tree_depth_3"
+    "unified-bridge/local_exit_tree::tests::fuzz_local_exit_tree_depth_4"
+    "unified-bridge/local_exit_tree::tests::fuzz_local_exit_tree_depth_7"
 )
 
 printf '%s\0' "${fuzzers[@]}" | parallel --null --bar --joblog fuzz.log bash -c '